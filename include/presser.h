@@ -0,0 +1,59 @@
+/* C API of presser, available when it's built with the `capi` feature.
+ *
+ * See the documentation of the Rust functions of the same names for details:
+ * https://docs.rs/presser
+ */
+
+#ifndef PRESSER_H
+#define PRESSER_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum PresserStatus {
+    PRESSER_STATUS_OK = 0,
+    PRESSER_STATUS_OUT_OF_MEMORY = 1,
+    PRESSER_STATUS_OFFSET_OUT_OF_BOUNDS = 2,
+    PRESSER_STATUS_INVALID_LAYOUT = 3,
+    PRESSER_STATUS_REQUESTED_OFFSET_UNALIGNED = 4,
+    PRESSER_STATUS_ZERO_SIZED_TYPE = 5,
+    PRESSER_STATUS_INVALID_ALIGNMENT = 6,
+    PRESSER_STATUS_ALLOCATION_FAILED = 7,
+} PresserStatus;
+
+typedef struct PresserCopyRecord {
+    size_t start_offset;
+    size_t end_offset;
+    size_t end_offset_padded;
+} PresserCopyRecord;
+
+/* Copies `src_size` bytes from `src` into `dst` at the first offset at or after `start_offset`
+ * which is aligned to `min_alignment`. `out_record` may be NULL. */
+PresserStatus presser_copy_bytes(
+    void *dst,
+    size_t dst_size,
+    size_t start_offset,
+    const void *src,
+    size_t src_size,
+    size_t min_alignment,
+    PresserCopyRecord *out_record);
+
+/* Computes where a copy of `size` bytes with alignment `align` into `dst` would be placed,
+ * without copying anything. `dst` is never accessed. `out_record` may be NULL. */
+PresserStatus presser_plan_offset(
+    const void *dst,
+    size_t dst_size,
+    size_t start_offset,
+    size_t size,
+    size_t align,
+    size_t min_alignment,
+    PresserCopyRecord *out_record);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* PRESSER_H */
@@ -0,0 +1,108 @@
+use super::*;
+
+/// A `SIZE`-byte slab on the stack (or wherever it's placed), aligned to `ALIGN` bytes.
+///
+/// Unlike [`make_stack_slab`], which is only aligned to the alignment of its element type, this
+/// allows asking for any power-of-two alignment from 1 up to 4096 bytes, so for example copying a
+/// 256-byte aligned value to offset 0 with [`copy_to_offset_exact`] always succeeds.
+///
+/// ```
+/// # use presser::*;
+/// let mut slab = AlignedStackSlab::<512, 256>::new();
+/// copy_to_offset_exact(&[0u8; 256], &mut slab, 0).unwrap();
+/// assert_eq!(slab.base_ptr().align_offset(256), 0);
+/// ```
+#[repr(C)]
+pub struct AlignedStackSlab<const SIZE: usize, const ALIGN: usize>
+where
+    ConstAlign<ALIGN>: SupportedAlign,
+{
+    _align: [<ConstAlign<ALIGN> as SupportedAlign>::Aligner; 0],
+    bytes: [MaybeUninit<u8>; SIZE],
+}
+
+impl<const SIZE: usize, const ALIGN: usize> AlignedStackSlab<SIZE, ALIGN>
+where
+    ConstAlign<ALIGN>: SupportedAlign,
+{
+    /// Make a new slab. Begins as uninitialized.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            _align: [],
+            bytes: make_stack_slab(),
+        }
+    }
+}
+
+impl<const SIZE: usize, const ALIGN: usize> Default for AlignedStackSlab<SIZE, ALIGN>
+where
+    ConstAlign<ALIGN>: SupportedAlign,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// SAFETY: We point to our own inline array of `SIZE` bytes, which is valid for as long as we are
+// borrowed, so our `base_ptr` access is as required.
+unsafe impl<const SIZE: usize, const ALIGN: usize> Slab for AlignedStackSlab<SIZE, ALIGN>
+where
+    ConstAlign<ALIGN>: SupportedAlign,
+{
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.bytes.as_ptr().cast()
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.bytes.as_mut_ptr().cast()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        SIZE
+    }
+}
+
+/// Marker type carrying an alignment as a const generic, used to select the alignment of an
+/// [`AlignedStackSlab`].
+pub struct ConstAlign<const ALIGN: usize>;
+
+/// Implemented for [`ConstAlign`]s with an alignment supported by [`AlignedStackSlab`], i.e.
+/// powers of two from 1 up to 4096.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait SupportedAlign: sealed::Sealed {
+    /// A zero-sized type with the alignment in question.
+    type Aligner;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! supported_aligns {
+    ($($align:literal => $aligner:ident),*) => {
+        $(
+            #[doc(hidden)]
+            #[derive(Clone, Copy)]
+            #[repr(align($align))]
+            pub struct $aligner;
+
+            impl sealed::Sealed for ConstAlign<$align> {}
+
+            impl SupportedAlign for ConstAlign<$align> {
+                type Aligner = $aligner;
+            }
+        )*
+    };
+}
+
+supported_aligns!(
+    1 => Align1, 2 => Align2, 4 => Align4, 8 => Align8, 16 => Align16, 32 => Align32,
+    64 => Align64, 128 => Align128, 256 => Align256, 512 => Align512, 1024 => Align1024,
+    2048 => Align2048, 4096 => Align4096
+);
@@ -0,0 +1,189 @@
+use super::*;
+
+use std::collections::VecDeque;
+
+/// A ring allocator for streaming data to a device through a [`Slab`], such as a per-frame upload
+/// heap, where each allocation is tagged with a fence value and freed once that fence has been
+/// [retired][StreamingArena::retire].
+///
+/// Allocations are placed one after the other, wrapping around to the start of the slab when they
+/// reach its end, and never overlap any allocation which hasn't been retired yet.
+///
+/// ```
+/// # use presser::*;
+/// let mut arena = StreamingArena::new(make_stack_slab::<u8, 64>());
+///
+/// let first = arena.copy_slice(&[0u8; 24], 1, 1)?;
+/// let second = arena.copy_slice(&[1u8; 24], 1, 2)?;
+/// // doesn't fit until the device is done with the first frame's data
+/// assert!(arena.copy_slice(&[2u8; 24], 1, 3).is_err());
+///
+/// arena.retire(1);
+/// let third = arena.copy_slice(&[2u8; 24], 1, 3)?;
+/// assert_eq!(third.start_offset, first.start_offset);
+/// # Ok::<(), presser::Error>(())
+/// ```
+///
+/// Fence values must be passed in non-decreasing order, as is the case for the usual
+/// frame counters or device timeline values.
+pub struct StreamingArena<S: Slab> {
+    inner: S,
+    head: usize,
+    /// Whether `head` has wrapped around to before the oldest allocation, in which case the free
+    /// space is between the two rather than after `head` and before the oldest allocation.
+    wrapped: bool,
+    in_flight: VecDeque<InFlight>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct InFlight {
+    fence: u64,
+    start: usize,
+    /// Whether this is the first allocation placed after wrapping around to the start.
+    wraps: bool,
+}
+
+impl<S: Slab> StreamingArena<S> {
+    /// Wrap `inner`, starting with no allocations.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            head: 0,
+            wrapped: false,
+            in_flight: VecDeque::new(),
+        }
+    }
+
+    /// Allocate space for a resource with layout `layout`, aligned to at least `min_alignment`,
+    /// which stays allocated until `fence` is [retired][StreamingArena::retire].
+    ///
+    /// Returns a [`CopyRecord`] describing where the resource will live. The function will return
+    /// an error of kind [`ErrorKind::OutOfMemory`] if there isn't enough free space for it, in
+    /// which case nothing is allocated.
+    pub fn allocate(
+        &mut self,
+        layout: Layout,
+        min_alignment: usize,
+        fence: u64,
+    ) -> Result<CopyRecord, Error> {
+        let fits_before = |arena: &Self, start: usize, limit: usize| {
+            compute_and_validate_offsets(&arena.inner, start, layout, min_alignment, false)
+                .ok()
+                .filter(|offsets| offsets.end <= limit)
+        };
+
+        let mut wraps = false;
+        let offsets = match self.in_flight.front() {
+            Some(oldest) if self.wrapped => {
+                // already wrapped around, the free space is between the head and oldest
+                fits_before(self, self.head, oldest.start)
+            }
+            Some(oldest) => fits_before(self, self.head, self.inner.size()).or_else(|| {
+                // wrap around to the start, unless the oldest allocation is already there
+                wraps = oldest.start > 0;
+                fits_before(self, 0, oldest.start).filter(|_| wraps)
+            }),
+            None => fits_before(self, 0, self.inner.size()),
+        }
+        .ok_or(Error::new(ErrorKind::OutOfMemory))?;
+
+        self.head = offsets.end;
+        self.wrapped |= wraps;
+        self.in_flight.push_back(InFlight {
+            fence,
+            start: offsets.start,
+            wraps,
+        });
+        Ok(offsets.into())
+    }
+
+    /// Copy `src` into a new allocation, aligned to at least `min_alignment`, which stays
+    /// allocated until `fence` is [retired][StreamingArena::retire].
+    ///
+    /// See [`allocate`][StreamingArena::allocate] for more.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe on its own, however it is very possible to do unsafe
+    /// things if you read the copied data in the wrong way. See the
+    /// [crate-level Safety documentation][`crate#safety`] for more.
+    pub fn copy<T: Copy>(
+        &mut self,
+        src: &T,
+        min_alignment: usize,
+        fence: u64,
+    ) -> Result<CopyRecord, Error> {
+        let record = self.allocate(Layout::new::<T>(), min_alignment, fence)?;
+        copy_to_offset_with_align_exact(src, &mut self.inner, record.start_offset, min_alignment)
+    }
+
+    /// Copy all elements of `src` into a new allocation, laid out as a `[T]` aligned to at least
+    /// `min_alignment`, which stays allocated until `fence` is [retired][StreamingArena::retire].
+    ///
+    /// See [`allocate`][StreamingArena::allocate] for more.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe on its own, however it is very possible to do unsafe
+    /// things if you read the copied data in the wrong way. See the
+    /// [crate-level Safety documentation][`crate#safety`] for more.
+    pub fn copy_slice<T: Copy>(
+        &mut self,
+        src: &[T],
+        min_alignment: usize,
+        fence: u64,
+    ) -> Result<CopyRecord, Error> {
+        let record = self.allocate(Layout::for_value(src), min_alignment, fence)?;
+        copy_from_slice_to_offset_with_align_exact(
+            src,
+            &mut self.inner,
+            record.start_offset,
+            min_alignment,
+        )
+    }
+
+    /// Free all allocations tagged with a fence value up to and including `completed_fence`,
+    /// i.e. once the device is done with them.
+    pub fn retire(&mut self, completed_fence: u64) {
+        while let Some(oldest) = self.in_flight.front() {
+            if oldest.fence > completed_fence {
+                break;
+            }
+            self.in_flight.pop_front();
+            // once everything allocated before wrapping around is retired, the allocations are
+            // contiguous again
+            if self.in_flight.front().map_or(true, |next| next.wraps) {
+                self.wrapped = false;
+            }
+        }
+        if self.in_flight.is_empty() {
+            self.head = 0;
+        }
+    }
+
+    /// The number of allocations which haven't been retired yet.
+    #[inline]
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    /// The wrapped slab.
+    #[inline]
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// The wrapped slab, for example to write into an allocation in some other way than through
+    /// [`copy`][StreamingArena::copy] and [`copy_slice`][StreamingArena::copy_slice].
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+
+    /// Unwrap the wrapped slab, discarding all allocations.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
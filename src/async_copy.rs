@@ -0,0 +1,136 @@
+use super::*;
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+#[cfg(feature = "async-io")]
+use std::io;
+
+#[cfg(feature = "async-io")]
+use tokio::io::AsyncRead;
+#[cfg(feature = "async-io")]
+use tokio::io::ReadBuf;
+
+/// The number of bytes [`copy_from_slice_to_offset_async`] copies between yields to the executor.
+pub const ASYNC_COPY_CHUNK_SIZE: usize = 256 * 1024;
+
+/// Copies from `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, yielding to the executor after every
+/// [`ASYNC_COPY_CHUNK_SIZE`] bytes.
+///
+/// This lets very large copies run on a cooperative (async) executor without starving the
+/// other tasks on the same thread. It doesn't depend on any particular executor.
+///
+/// The placement of the copy and the returned [`CopyRecord`] are the same as for
+/// [`copy_from_slice_to_offset`]. If the returned future is dropped before completion, only a
+/// prefix of `src` will have been copied.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+pub async fn copy_from_slice_to_offset_async<T: Copy, S: Slab + ?Sized>(
+    src: &[T],
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    let layout = Layout::for_value(src);
+    let offsets = compute_and_validate_offsets(&*dst, start_offset, layout, 1, false)?;
+
+    let src_ptr = src.as_ptr().cast::<u8>();
+    let len = layout.size();
+    let mut copied = 0;
+    loop {
+        let chunk = ASYNC_COPY_CHUNK_SIZE.min(len - copied);
+
+        // SAFETY:
+        // - src is valid as we have a reference to it
+        // - dst is valid so long as requirements for `slab` were met, i.e.
+        // we have unique access to the region described and that it is valid for the duration
+        // of 'a.
+        // - checked that the whole copy stays within bounds of our allocation, and
+        // `copied + chunk <= len`
+        // - the two regions can't overlap as we have unique access to `dst`
+        unsafe {
+            let dst_ptr = dst.base_non_null().as_ptr().add(offsets.start + copied);
+            core::ptr::copy_nonoverlapping(src_ptr.add(copied), dst_ptr, chunk);
+        }
+        copied += chunk;
+
+        if copied == len {
+            break;
+        }
+        YieldNow(false).await;
+    }
+
+    Ok(offsets.into())
+}
+
+/// Reads from `reader` into the memory represented by `dst`, starting at *exactly*
+/// `start_offset` bytes past the start of `dst`, until either `reader` reaches end-of-file or
+/// the end of `dst` is reached.
+///
+/// The data is read directly into the slab, without an intermediate buffer. The returned
+/// [`CopyRecord`] covers exactly the bytes that were read, so all bytes in
+/// [`record.range()`][CopyRecord::range] are initialized.
+///
+/// If `start_offset` is out of bounds of `dst`, an error of kind
+/// [`InvalidInput`][io::ErrorKind::InvalidInput] wrapping [`ErrorKind::OffsetOutOfBounds`] is
+/// returned. If reading fails partway through, the error is returned and some prefix of the
+/// range may have been written to.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[cfg(feature = "async-io")]
+pub async fn copy_from_async_reader_to_offset<R, S>(
+    reader: &mut R,
+    dst: &mut S,
+    start_offset: usize,
+) -> io::Result<CopyRecord>
+where
+    R: AsyncRead + Unpin + ?Sized,
+    S: Slab + ?Sized,
+{
+    if start_offset > dst.size() {
+        return Err(Error::offset_out_of_bounds(start_offset, start_offset, dst.size()).into());
+    }
+
+    let mut buf = ReadBuf::uninit(&mut dst.as_maybe_uninit_bytes_mut()[start_offset..]);
+    while buf.remaining() > 0 {
+        let filled = buf.filled().len();
+        core::future::poll_fn(|cx| Pin::new(&mut *reader).poll_read(cx, &mut buf)).await?;
+        if buf.filled().len() == filled {
+            break;
+        }
+    }
+
+    let end_offset = start_offset + buf.filled().len();
+    Ok(CopyRecord {
+        start_offset,
+        end_offset,
+        end_offset_padded: end_offset,
+    })
+}
+
+/// A future which returns [`Poll::Pending`] once, after immediately waking its task.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use super::*;
+
+/// An invariant lifetime used to tie [`BrandedRecord`]s to the [`BrandedSlab`] they were made for.
+///
+/// Each call to [`with_branded_slab`] picks a brand which is different from every other one, so
+/// the compiler refuses to mix records and slabs with different brands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Brand<'id>(PhantomData<fn(&'id ()) -> &'id ()>);
+
+/// Call `f` with a [`BrandedSlab`] borrowing `slab`, whose [`BrandedRecord`]s can only be used
+/// with that same slab.
+///
+/// This statically rules out reading back a record from the wrong buffer:
+///
+/// ```compile_fail
+/// # use presser::*;
+/// let mut a = make_stack_slab::<u32, 4>();
+/// let mut b = make_stack_slab::<u32, 4>();
+/// with_branded_slab(&mut a, |mut a| {
+///     with_branded_slab(&mut b, |b| {
+///         let record = a.copy(&1u32, 0).unwrap();
+///         // error: `record` is branded with `a`'s brand
+///         let _ = unsafe { b.read_record::<u32>(record) };
+///     })
+/// });
+/// ```
+///
+/// ```
+/// # use presser::*;
+/// let mut slab = make_stack_slab::<u32, 4>();
+/// let value = with_branded_slab(&mut slab, |mut slab| {
+///     let record = slab.copy(&42u32, 0)?;
+///     // SAFETY: `record` was made by copying a `u32`.
+///     unsafe { slab.read_record::<u32>(record) }.copied()
+/// })?;
+/// assert_eq!(value, 42);
+/// # Ok::<(), presser::Error>(())
+/// ```
+#[inline]
+pub fn with_branded_slab<S, R, F>(slab: &mut S, f: F) -> R
+where
+    S: Slab + ?Sized,
+    F: for<'id> FnOnce(BrandedSlab<'id, '_, S>) -> R,
+{
+    f(BrandedSlab {
+        slab,
+        brand: Brand(PhantomData),
+    })
+}
+
+/// A borrow of a [`Slab`] carrying a unique brand, made by [`with_branded_slab`]. Copies made
+/// through it return [`BrandedRecord`]s, which can only be read back through the same slab.
+///
+/// [`BrandedSlab`] is itself a [`Slab`] which passes through to the borrowed one, but copies made
+/// into it directly return plain [`CopyRecord`]s.
+pub struct BrandedSlab<'id, 's, S: Slab + ?Sized> {
+    slab: &'s mut S,
+    brand: Brand<'id>,
+}
+
+/// A [`CopyRecord`] which can only be used with the [`BrandedSlab`] it was made by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BrandedRecord<'id> {
+    record: CopyRecord,
+    brand: Brand<'id>,
+}
+
+impl<'id> BrandedRecord<'id> {
+    /// The record itself, without its brand.
+    #[inline]
+    pub fn record(&self) -> CopyRecord {
+        self.record
+    }
+}
+
+impl<'id, 's, S: Slab + ?Sized> BrandedSlab<'id, 's, S> {
+    #[inline(always)]
+    fn brand(&self, record: CopyRecord) -> BrandedRecord<'id> {
+        BrandedRecord {
+            record,
+            brand: self.brand,
+        }
+    }
+
+    /// Copies `src` into the slab at a minimum offset of `start_offset` bytes.
+    ///
+    /// See [`copy_to_offset`] for more.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe on its own, however it is very possible to do unsafe
+    /// things if you read the copied data in the wrong way. See the
+    /// [crate-level Safety documentation][`crate#safety`] for more.
+    #[inline]
+    pub fn copy<T: Copy>(
+        &mut self,
+        src: &T,
+        start_offset: usize,
+    ) -> Result<BrandedRecord<'id>, Error> {
+        let record = copy_to_offset(src, self.slab, start_offset)?;
+        Ok(self.brand(record))
+    }
+
+    /// Copies from `src` into the slab at a minimum offset of `start_offset` bytes.
+    ///
+    /// See [`copy_from_slice_to_offset`] for more.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe on its own, however it is very possible to do unsafe
+    /// things if you read the copied data in the wrong way. See the
+    /// [crate-level Safety documentation][`crate#safety`] for more.
+    #[inline]
+    pub fn copy_slice<T: Copy>(
+        &mut self,
+        src: &[T],
+        start_offset: usize,
+    ) -> Result<BrandedRecord<'id>, Error> {
+        let record = copy_from_slice_to_offset(src, self.slab, start_offset)?;
+        Ok(self.brand(record))
+    }
+
+    /// Gets a shared reference to the `T` described by `record`.
+    ///
+    /// The function will return an error if the size of `T` isn't the size of the copy described
+    /// by `record`, or in the same cases as [`read_at_offset`].
+    ///
+    /// # Safety
+    ///
+    /// `record` must describe a copy of a `T`, and the `T` must not have been overwritten with
+    /// something which isn't a **valid**\* `T` since.
+    ///
+    /// \* Validity is a complex topic not to be taken lightly.
+    /// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+    #[inline]
+    pub unsafe fn read_record<T>(&self, record: BrandedRecord<'id>) -> Result<&T, Error> {
+        if record.record.len() != core::mem::size_of::<T>() {
+            return Err(Error::invalid_layout());
+        }
+        // SAFETY: same safety requirements
+        unsafe { read_at_offset(&*self.slab, record.record.start_offset) }
+    }
+
+    /// Gets a shared reference to the `[T]` described by `record`.
+    ///
+    /// The function will return an error if the size of the copy described by `record` isn't a
+    /// multiple of the size of `T`, of kind [`ErrorKind::ZeroSizedType`] if `T` is zero-sized, or
+    /// in the same cases as [`read_slice_at_offset`].
+    ///
+    /// # Safety
+    ///
+    /// `record` must describe a copy of a `[T]`, and its elements must not have been overwritten
+    /// with something which isn't a **valid**\* `T` since.
+    ///
+    /// \* Validity is a complex topic not to be taken lightly.
+    /// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+    #[inline]
+    pub unsafe fn read_slice_record<T>(&self, record: BrandedRecord<'id>) -> Result<&[T], Error> {
+        let size = core::mem::size_of::<T>();
+        if size == 0 {
+            return Err(Error::zero_sized_type());
+        }
+        if record.record.len() % size != 0 {
+            return Err(Error::invalid_layout());
+        }
+        // SAFETY: same safety requirements
+        unsafe {
+            read_slice_at_offset(
+                &*self.slab,
+                record.record.start_offset,
+                record.record.len() / size,
+            )
+        }
+    }
+}
+
+// SAFETY: This is just a basic pass-thru to `slab`, which is a `Slab` itself.
+unsafe impl<'id, 's, S: Slab + ?Sized> Slab for BrandedSlab<'id, 's, S> {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.slab.base_ptr()
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.slab.base_ptr_mut()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.slab.size()
+    }
+}
@@ -0,0 +1,203 @@
+use super::*;
+
+/// The byte that the canaries of a [`CanarySlab`] are filled with.
+pub const CANARY_BYTE: u8 = 0xFD;
+
+/// Wraps a [`Slab`] and places small canary regions after each resource suballocated in it, to
+/// detect copies (or device writes) which strayed outside of the [`CopyRecord`] they were meant
+/// to stay within.
+///
+/// Resources are placed one after the other, like with a [`LayoutPlanner`], through
+/// [`reserve`][CanarySlab::reserve], [`copy`][CanarySlab::copy] and
+/// [`copy_slice`][CanarySlab::copy_slice]. Directly after the end of each resource, a canary of
+/// [`canary_len`][CanarySlab::canary_len] bytes is filled with [`CANARY_BYTE`]. Calling
+/// [`check`][CanarySlab::check] later reports the first canary which was overwritten.
+///
+/// ```
+/// # use presser::*;
+/// let mut slab = CanarySlab::new(make_stack_slab::<u32, 32>());
+/// let record = slab.copy_slice(&[1u32, 2, 3], 1)?;
+/// slab.copy(&4u32, 1)?;
+///
+/// // a buggy copy which writes one element too many
+/// copy_from_slice_to_offset(&[1u32, 2, 3, 4], &mut slab, record.start_offset)?;
+///
+/// // SAFETY: only `u32`s, which have no padding bytes, were written into the slab
+/// assert_eq!(unsafe { slab.check() }, Err(record.end_offset..record.end_offset + 16));
+/// # Ok::<(), presser::Error>(())
+/// ```
+///
+/// Unlike `GuardedSlab` (with the '`os`' feature), this works on any slab, including memory
+/// mapped from a device, and has no overhead beyond the bytes used by the canaries. It only detects
+/// overruns after the fact, though, and only those which wrote a byte other than [`CANARY_BYTE`].
+///
+/// [`CanarySlab`] is itself a [`Slab`] which passes through to the wrapped one, but copies made
+/// into it directly don't get canaries.
+pub struct CanarySlab<S: Slab> {
+    inner: S,
+    cursor: usize,
+    canary_len: usize,
+    canaries: Vec<Range<usize>>,
+}
+
+impl<S: Slab> CanarySlab<S> {
+    /// The number of bytes in each canary for a slab made with [`new`][CanarySlab::new].
+    pub const DEFAULT_CANARY_LEN: usize = 16;
+
+    /// Wrap `inner`, placing canaries of [`DEFAULT_CANARY_LEN`][CanarySlab::DEFAULT_CANARY_LEN]
+    /// bytes.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self::with_canary_len(inner, Self::DEFAULT_CANARY_LEN)
+    }
+
+    /// Wrap `inner`, placing canaries of `canary_len` bytes.
+    #[inline]
+    pub fn with_canary_len(inner: S, canary_len: usize) -> Self {
+        Self {
+            inner,
+            cursor: 0,
+            canary_len,
+            canaries: Vec::new(),
+        }
+    }
+
+    /// Reserve space for a resource with layout `layout` after all previously reserved
+    /// resources and their canaries, aligned to at least `min_alignment`, and place a canary
+    /// directly after it.
+    ///
+    /// Returns a [`CopyRecord`] describing where the resource will live. If the resource and its
+    /// canary don't fit in the slab, an error is returned and nothing is reserved.
+    pub fn reserve(&mut self, layout: Layout, min_alignment: usize) -> Result<CopyRecord, Error> {
+        let offsets =
+            compute_and_validate_offsets(&self.inner, self.cursor, layout, min_alignment, false)?;
+        let canary_end = offsets
+            .end
+            .checked_add(self.canary_len)
+            .ok_or(Error::invalid_layout())?;
+        let canary = validate_range(&self.inner, offsets.end..canary_end)?;
+
+        // SAFETY: `canary` was checked to be within the bounds of `inner`.
+        unsafe {
+            core::ptr::write_bytes(
+                self.inner.base_non_null().as_ptr().add(canary.start),
+                CANARY_BYTE,
+                canary.len(),
+            );
+        }
+
+        self.cursor = canary.end;
+        self.canaries.push(canary);
+        Ok(offsets.into())
+    }
+
+    /// Copy `src` into the slab after all previously reserved resources and their canaries,
+    /// aligned to at least `min_alignment`, and place a canary directly after it.
+    ///
+    /// See [`reserve`][CanarySlab::reserve] for more.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe on its own, however it is very possible to do unsafe
+    /// things if you read the copied data in the wrong way. See the
+    /// [crate-level Safety documentation][`crate#safety`] for more.
+    pub fn copy<T: Copy>(&mut self, src: &T, min_alignment: usize) -> Result<CopyRecord, Error> {
+        let record = self.reserve(Layout::new::<T>(), min_alignment)?;
+        copy_to_offset_with_align_exact(src, &mut self.inner, record.start_offset, min_alignment)
+    }
+
+    /// Copy all elements of `src` into the slab, laid out as a `[T]`, after all previously
+    /// reserved resources and their canaries, aligned to at least `min_alignment`, and place a
+    /// canary directly after it.
+    ///
+    /// See [`reserve`][CanarySlab::reserve] for more.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe on its own, however it is very possible to do unsafe
+    /// things if you read the copied data in the wrong way. See the
+    /// [crate-level Safety documentation][`crate#safety`] for more.
+    pub fn copy_slice<T: Copy>(
+        &mut self,
+        src: &[T],
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error> {
+        let record = self.reserve(Layout::for_value(src), min_alignment)?;
+        copy_from_slice_to_offset_with_align_exact(
+            src,
+            &mut self.inner,
+            record.start_offset,
+            min_alignment,
+        )
+    }
+
+    /// Check whether all canaries are still intact.
+    ///
+    /// Returns the range of the first canary which was overwritten as an error.
+    ///
+    /// # Safety
+    ///
+    /// All bytes written over the canaries since they were placed must be initialized. This is
+    /// always the case for bytes written by a device, or by copies of types without padding bytes.
+    pub unsafe fn check(&self) -> Result<(), Range<usize>> {
+        for canary in &self.canaries {
+            // SAFETY:
+            // - `canary` was checked to be within bounds of `inner` when it was placed
+            // - `u8` has no alignment requirements
+            // - the canary was initialized when placed, and if the function-level safety
+            // requirements are met, it still is.
+            let bytes = unsafe {
+                read_slice_at_offset_unchecked::<u8, S>(&self.inner, canary.start, canary.len())
+            };
+            if bytes.iter().any(|&b| b != CANARY_BYTE) {
+                return Err(canary.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// The number of bytes in each canary.
+    #[inline]
+    pub fn canary_len(&self) -> usize {
+        self.canary_len
+    }
+
+    /// The offset directly after the last placed canary, i.e. the number of bytes at the
+    /// beginning of the slab which are used by resources and canaries (including alignment
+    /// padding).
+    #[inline]
+    pub fn used(&self) -> usize {
+        self.cursor
+    }
+
+    /// The wrapped slab.
+    #[inline]
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Unwrap the wrapped slab, discarding the canaries. The bytes they were filled with stay in
+    /// the slab.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+// SAFETY: This is just a basic pass-thru to `inner`, which is a `Slab` itself.
+unsafe impl<S: Slab> Slab for CanarySlab<S> {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.inner.base_ptr()
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.inner.base_ptr_mut()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+}
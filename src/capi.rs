@@ -0,0 +1,164 @@
+use super::*;
+
+/// Status codes returned by the functions of the C API. Apart from [`PresserStatus::Ok`], these
+/// mirror the variants of [`ErrorKind`].
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PresserStatus {
+    /// The operation succeeded.
+    Ok = 0,
+    /// See [`ErrorKind::OutOfMemory`].
+    OutOfMemory = 1,
+    /// See [`ErrorKind::OffsetOutOfBounds`].
+    OffsetOutOfBounds = 2,
+    /// See [`ErrorKind::InvalidLayout`]. This is also returned for null pointer arguments which may
+    /// not be null.
+    InvalidLayout = 3,
+    /// See [`ErrorKind::RequestedOffsetUnaligned`].
+    RequestedOffsetUnaligned = 4,
+    /// See [`ErrorKind::ZeroSizedType`].
+    ZeroSizedType = 5,
+    /// See [`ErrorKind::InvalidAlignment`].
+    InvalidAlignment = 6,
+    /// See [`ErrorKind::AllocationFailed`].
+    AllocationFailed = 7,
+}
+
+impl From<Error> for PresserStatus {
+    fn from(err: Error) -> Self {
+        match err.kind() {
+            ErrorKind::OutOfMemory => Self::OutOfMemory,
+            ErrorKind::OffsetOutOfBounds => Self::OffsetOutOfBounds,
+            ErrorKind::InvalidLayout => Self::InvalidLayout,
+            ErrorKind::RequestedOffsetUnaligned => Self::RequestedOffsetUnaligned,
+            ErrorKind::ZeroSizedType => Self::ZeroSizedType,
+            ErrorKind::InvalidAlignment => Self::InvalidAlignment,
+            ErrorKind::AllocationFailed => Self::AllocationFailed,
+        }
+    }
+}
+
+/// The C representation of a [`CopyRecord`].
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PresserCopyRecord {
+    /// See [`CopyRecord::start_offset`].
+    pub start_offset: usize,
+    /// See [`CopyRecord::end_offset`].
+    pub end_offset: usize,
+    /// See [`CopyRecord::end_offset_padded`].
+    pub end_offset_padded: usize,
+}
+
+impl From<CopyRecord> for PresserCopyRecord {
+    fn from(record: CopyRecord) -> Self {
+        Self {
+            start_offset: record.start_offset,
+            end_offset: record.end_offset,
+            end_offset_padded: record.end_offset_padded,
+        }
+    }
+}
+
+/// Writes `record` to `out_record` unless it's null, and converts `result` into a status code.
+///
+/// # Safety
+///
+/// `out_record` must be null or valid for writes.
+unsafe fn finish(
+    result: Result<CopyRecord, Error>,
+    out_record: *mut PresserCopyRecord,
+) -> PresserStatus {
+    match result {
+        Ok(record) => {
+            if !out_record.is_null() {
+                // SAFETY: same safety requirements
+                unsafe { out_record.write(record.into()) };
+            }
+            PresserStatus::Ok
+        }
+        Err(err) => err.into(),
+    }
+}
+
+/// Copies `src_size` bytes from `src` into the `dst_size` byte large buffer at `dst`, starting at
+/// a minimum location of `start_offset` bytes past the start of `dst`, aligned to at least
+/// `min_alignment`. On success, the record of the copy is written to `out_record` unless it's
+/// null.
+///
+/// This is the C equivalent of [`copy_from_slice_to_offset_with_align`], see it for more.
+///
+/// # Safety
+///
+/// - `dst` must be valid for writes of `dst_size` bytes, and not be accessed by anything else
+///   during the call. It may point to uninitialized memory.
+/// - `src` must be valid for reads of `src_size` bytes and initialized, and must not overlap
+///   `dst`. It may be null only if `src_size` is 0.
+/// - `out_record` must be null or valid for writes.
+///
+/// Also see the [top-level safety documentation][`crate#safety`].
+#[no_mangle]
+pub unsafe extern "C" fn presser_copy_bytes(
+    dst: *mut c_void,
+    dst_size: usize,
+    start_offset: usize,
+    src: *const c_void,
+    src_size: usize,
+    min_alignment: usize,
+    out_record: *mut PresserCopyRecord,
+) -> PresserStatus {
+    let dst = match NonNull::new(dst.cast::<u8>()) {
+        Some(dst) => dst,
+        None => return PresserStatus::InvalidLayout,
+    };
+    if dst_size > isize::MAX as usize {
+        return PresserStatus::InvalidLayout;
+    }
+    let src: &[u8] = if src_size == 0 {
+        &[]
+    } else if src.is_null() {
+        return PresserStatus::InvalidLayout;
+    } else {
+        // SAFETY: same safety requirements
+        unsafe { core::slice::from_raw_parts(src.cast::<u8>(), src_size) }
+    };
+
+    let mut raw = RawAllocation::from_raw_parts(dst, dst_size);
+    // SAFETY: same safety requirements
+    let mut slab = unsafe { raw.borrow_as_slab() };
+    let result = copy_from_slice_to_offset_with_align(src, &mut slab, start_offset, min_alignment);
+
+    // SAFETY: same safety requirements
+    unsafe { finish(result, out_record) }
+}
+
+/// Computes the record that a copy of `size` bytes with alignment `align` into the `dst_size`
+/// byte large buffer at `dst` would produce, starting at a minimum location of `start_offset`
+/// bytes past the start of `dst` and aligned to at least `min_alignment`, without copying
+/// anything. On success, the record is written to `out_record` unless it's null.
+///
+/// `dst` is never accessed, only its address matters. This is the C equivalent of
+/// [`plan_copy_for_raw_parts`], see it for more.
+///
+/// # Safety
+///
+/// `out_record` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn presser_plan_offset(
+    dst: *const c_void,
+    dst_size: usize,
+    start_offset: usize,
+    size: usize,
+    align: usize,
+    min_alignment: usize,
+    out_record: *mut PresserCopyRecord,
+) -> PresserStatus {
+    let result = Layout::from_size_align(size, align)
+        .map_err(Error::from)
+        .and_then(|layout| {
+            plan_copy_for_raw_parts(addr(dst), dst_size, layout, start_offset, min_alignment)
+        });
+
+    // SAFETY: same safety requirements
+    unsafe { finish(result, out_record) }
+}
@@ -0,0 +1,176 @@
+use super::*;
+
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+
+/// Lets multiple threads copy into a single [`Slab`] at once, each claiming its own range with an
+/// atomic bump of a shared cursor and then copying into it without any further synchronization.
+///
+/// The writer borrows the slab mutably for its whole lifetime, so no other access to the slab can
+/// race with the copies, and since every claimed range is disjoint from all others, the copies
+/// can't race with each other either. Once all producers are done, drop the writer (or call
+/// [`finish`][ConcurrentWriter::finish]) to get access to the slab again, for example to hand the
+/// written range to a device.
+///
+/// ```
+/// # use presser::*;
+/// let mut slab = make_stack_slab::<u32, 64>();
+/// let writer = ConcurrentWriter::new(&mut slab);
+///
+/// std::thread::scope(|s| {
+///     for thread in 0..4u32 {
+///         let writer = &writer;
+///         s.spawn(move || writer.copy_slice(&[thread; 8], 1).unwrap());
+///     }
+/// });
+///
+/// assert_eq!(writer.finish(), 4 * 8 * 4);
+/// ```
+///
+/// Ranges are claimed in whatever order the threads get to them, so use the returned
+/// [`CopyRecord`]s to find out where each copy was placed.
+///
+/// Since the writer lets other threads write into the slab, the slab must be [`Send`]. Slabs
+/// viewing memory through a raw pointer are not, since this crate can't know whether that memory
+/// may be accessed from other threads:
+///
+/// ```compile_fail
+/// # use presser::*;
+/// let mut slab = make_stack_slab::<u8, 64>();
+/// let mut raw = RawAllocation::from_uninit_slice(&mut slab);
+/// let writer = ConcurrentWriter::new(&mut raw);
+/// ```
+pub struct ConcurrentWriter<'a> {
+    base_ptr: NonNull<u8>,
+    size: usize,
+    cursor: AtomicUsize,
+    _marker: PhantomData<&'a mut [MaybeUninit<u8>]>,
+}
+
+// SAFETY: the writer only hands out access to disjoint ranges of the slab it borrows mutably, and
+// that slab is `Send` as required by the constructors, so it may be shared and sent between threads
+// just like a `&mut [MaybeUninit<u8>]`.
+unsafe impl<'a> Send for ConcurrentWriter<'a> {}
+// SAFETY: see above
+unsafe impl<'a> Sync for ConcurrentWriter<'a> {}
+
+impl<'a> ConcurrentWriter<'a> {
+    /// Start writing into `slab`, with the first copy placed at the beginning of it.
+    #[inline]
+    pub fn new<S: Slab + Send + ?Sized>(slab: &'a mut S) -> Self {
+        Self::starting_at(slab, 0)
+    }
+
+    /// Start writing into `slab`, with no copy placed before `start_offset`.
+    #[inline]
+    pub fn starting_at<S: Slab + Send + ?Sized>(slab: &'a mut S, start_offset: usize) -> Self {
+        let size = slab.size();
+        let base_ptr = slab.base_non_null();
+        Self {
+            base_ptr,
+            size,
+            cursor: AtomicUsize::new(start_offset),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Claim a range for a resource with layout `layout` after all previously claimed ranges,
+    /// aligned to at least `min_alignment`.
+    ///
+    /// Returns a [`CopyRecord`] describing the claimed range. If it doesn't fit in the slab, an
+    /// error is returned and nothing is claimed.
+    pub fn claim(&self, layout: Layout, min_alignment: usize) -> Result<CopyRecord, Error> {
+        let mut cursor = self.cursor.load(Ordering::Relaxed);
+        loop {
+            let offsets = compute_and_validate_offsets_for_raw_parts(
+                addr(self.base_ptr.as_ptr()),
+                self.size,
+                cursor,
+                layout,
+                min_alignment,
+                false,
+            )?;
+            match self.cursor.compare_exchange_weak(
+                cursor,
+                offsets.end,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Ok(offsets.into()),
+                Err(current) => cursor = current,
+            }
+        }
+    }
+
+    /// Copy `src` into a newly claimed range, aligned to at least `min_alignment`.
+    ///
+    /// See [`claim`][ConcurrentWriter::claim] for more.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe on its own, however it is very possible to do unsafe
+    /// things if you read the copied data in the wrong way. See the
+    /// [crate-level Safety documentation][`crate#safety`] for more.
+    pub fn copy<T: Copy>(&self, src: &T, min_alignment: usize) -> Result<CopyRecord, Error> {
+        let record = self.claim(Layout::new::<T>(), min_alignment)?;
+
+        // SAFETY:
+        // - src is valid as we have a reference to it
+        // - the claimed range is within bounds of the slab, properly aligned for `T`, and no
+        // other copy or access may touch it while we borrow the slab.
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                src,
+                self.base_ptr.as_ptr().add(record.start_offset).cast::<T>(),
+                1,
+            );
+        }
+
+        Ok(record)
+    }
+
+    /// Copy all elements of `src` into a newly claimed range, laid out as a `[T]` aligned to at
+    /// least `min_alignment`.
+    ///
+    /// See [`claim`][ConcurrentWriter::claim] for more.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe on its own, however it is very possible to do unsafe
+    /// things if you read the copied data in the wrong way. See the
+    /// [crate-level Safety documentation][`crate#safety`] for more.
+    pub fn copy_slice<T: Copy>(
+        &self,
+        src: &[T],
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error> {
+        let record = self.claim(Layout::for_value(src), min_alignment)?;
+
+        // SAFETY:
+        // - src is valid as we have a reference to it
+        // - the claimed range is within bounds of the slab, properly aligned for `T`, and no
+        // other copy or access may touch it while we borrow the slab.
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                src.as_ptr(),
+                self.base_ptr.as_ptr().add(record.start_offset).cast::<T>(),
+                src.len(),
+            );
+        }
+
+        Ok(record)
+    }
+
+    /// The offset directly after the end of the last claimed range.
+    #[inline]
+    pub fn used(&self) -> usize {
+        self.cursor.load(Ordering::Relaxed)
+    }
+
+    /// Stop writing, releasing the borrow of the slab, and return the offset directly after the
+    /// end of the last claimed range.
+    #[inline]
+    pub fn finish(self) -> usize {
+        self.cursor.into_inner()
+    }
+}
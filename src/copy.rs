@@ -1,7 +1,9 @@
 use super::*;
 
 /// Record of the results of a copy operation
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CopyRecord {
     /// The offset from the start of the allocation, in bytes, at which the
     /// copy operation began to write data.
@@ -26,6 +28,61 @@ pub struct CopyRecord {
     pub end_offset_padded: usize,
 }
 
+impl CopyRecord {
+    /// The number of bytes written by the copy, not including any padding at the end.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end_offset - self.start_offset
+    }
+
+    /// Whether the copy wrote no bytes at all.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of bytes taken up by the copy, including any padding at the end necessary to
+    /// maintain alignment requirements.
+    #[inline]
+    pub fn padded_len(&self) -> usize {
+        self.end_offset_padded - self.start_offset
+    }
+
+    /// The range of offsets into the allocation that the copy wrote to.
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.start_offset..self.end_offset
+    }
+
+    /// Computes the offset at which a `U` copied with the given `min_alignment` directly after
+    /// this copy would be placed.
+    ///
+    /// This is the first offset at or after [`end_offset`][CopyRecord::end_offset] that is a
+    /// multiple of the greater of `align_of::<U>()` and `min_alignment.next_power_of_two()`. Note
+    /// that this assumes the base address of the slab is itself aligned to at least that much;
+    /// if it isn't, the actual copy may be placed at a different offset, as copy functions align
+    /// the *address* of the copied data.
+    ///
+    /// Returns `None` if the computation would overflow.
+    #[inline]
+    pub fn next_offset_for<U>(&self, min_alignment: usize) -> Option<usize> {
+        let align = core::mem::align_of::<U>().max(min_alignment.next_power_of_two());
+        align_offset_up_to(0, self.end_offset, align)
+    }
+
+    /// Merges `self` and `other` into one record spanning both copies.
+    ///
+    /// If the two copies are not adjacent, the returned record also spans the gap in between them.
+    #[inline]
+    pub fn merge(self, other: CopyRecord) -> CopyRecord {
+        CopyRecord {
+            start_offset: self.start_offset.min(other.start_offset),
+            end_offset: self.end_offset.max(other.end_offset),
+            end_offset_padded: self.end_offset_padded.max(other.end_offset_padded),
+        }
+    }
+}
+
 impl From<ComputedOffsets> for CopyRecord {
     fn from(
         ComputedOffsets {
@@ -46,9 +103,9 @@ impl From<ComputedOffsets> for CopyRecord {
 /// `start_offset` bytes past the start of `dst`
 ///
 /// - `start_offset` is the offset into the allocation represented by `dst`, in bytes,
-/// where the first byte of the copied data will be placed. If the requested
-/// start offset does not satisfy computed alignment requirements, an error will
-/// be returned and no data will be copied.
+///   where the first byte of the copied data will be placed. If the requested
+///   start offset does not satisfy computed alignment requirements, an error will
+///   be returned and no data will be copied.
 ///
 /// # Safety
 ///
@@ -70,13 +127,13 @@ pub fn copy_to_offset_exact<T: Copy, S: Slab + ?Sized>(
 /// an error will be returned.
 ///
 /// - `start_offset` is the offset into the allocation represented by `dst`, in bytes,
-/// where the first byte of the copied data will be placed. If the requested
-/// start offset does not satisfy computed alignment requirements, an error will
-/// be returned and no data will be copied.
+///   where the first byte of the copied data will be placed. If the requested
+///   start offset does not satisfy computed alignment requirements, an error will
+///   be returned and no data will be copied.
 /// - `min_alignment` is the minimum alignment that you are requesting the copy be aligned to. The
-/// copy may be aligned greater than `min_alignment` depending on the alignment requirements
-/// of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
-/// `min_align.next_power_of_two()`).
+///   copy may be aligned greater than `min_alignment` depending on the alignment requirements
+///   of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
+///   `min_align.next_power_of_two()`).
 ///
 /// # Safety
 ///
@@ -94,7 +151,7 @@ pub fn copy_to_offset_with_align_exact<T: Copy, S: Slab + ?Sized>(
     let offsets = compute_and_validate_offsets(&*dst, start_offset, t_layout, min_alignment, true)?;
 
     // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
-    let dst_ptr = unsafe { dst.base_ptr_mut().add(offsets.start) }.cast::<T>();
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
 
     // SAFETY:
     // - src is valid as we have a reference to it
@@ -112,14 +169,57 @@ pub fn copy_to_offset_with_align_exact<T: Copy, S: Slab + ?Sized>(
     Ok(offsets.into())
 }
 
+/// Copies `src` into the memory represented by `dst` starting at *exactly* `start_offset` bytes
+/// past the start of `dst`, which doesn't need to be aligned for `T`.
+///
+/// This is useful for tightly packed layouts, such as 12-byte `[f32; 3]` vertex streams inside
+/// structs with other fields, or file formats, where the usual alignment of the copy would add
+/// unwanted padding.
+///
+/// - `start_offset` is the offset into the allocation represented by `dst`, in bytes,
+///   where the first byte of the copied data will be placed.
+///
+/// Since the copy may be unaligned, it must be read back with an unaligned read, such as
+/// [`read_unaligned_at_offset`].
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_to_offset_unaligned<T: Copy, S: Slab + ?Sized>(
+    src: &T,
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    let layout = Layout::from_size_align(core::mem::size_of::<T>(), 1)?;
+    let offsets = compute_and_validate_offsets(&*dst, start_offset, layout, 1, true)?;
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
+
+    // SAFETY:
+    // - dst is valid so long as requirements for `slab` were met, i.e.
+    // we have unique access to the region described and that it is valid for the duration
+    // of 'a.
+    // - `write_unaligned` has no alignment requirements
+    // - checked that copy stays within bounds of our allocation
+    unsafe {
+        core::ptr::write_unaligned(dst_ptr, *src);
+    }
+
+    Ok(offsets.into())
+}
+
 /// Copies `src` into the memory represented by `dst` starting at a minimum location
 /// of `start_offset` bytes past the start of `dst`.
 ///
 /// - `start_offset` is the offset into the allocation represented by `dst`,
-/// in bytes, before which any copied data will *certainly not* be placed. However,
-/// the actual beginning of the copied data may not be exactly at `start_offset` if
-/// padding bytes are needed to satisfy alignment requirements. The actual beginning
-/// of the copied bytes is contained in the returned [`CopyRecord`].
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
 ///
 /// # Safety
 ///
@@ -140,13 +240,13 @@ pub fn copy_to_offset<T: Copy, S: Slab + ?Sized>(
 /// `min_alignment`.
 ///
 /// - `start_offset` is the offset into the allocation represented by `dst`,
-/// in bytes, before which any copied data will *certainly not* be placed. However,
-/// the actual beginning of the copied data may not be exactly at `start_offset` if
-/// padding bytes are needed to satisfy alignment requirements. The actual beginning
-/// of the copied bytes is contained in the returned [`CopyRecord`].
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
 /// - `min_alignment` is the minimum alignment to which the copy will be aligned. The
-/// copy may not actually be aligned to `min_alignment` depending on the alignment requirements
-/// of `T` (the actual alignment will be the greater between `align_of::<T>` and `min_align.next_power_of_two()`).
+///   copy may not actually be aligned to `min_alignment` depending on the alignment requirements
+///   of `T` (the actual alignment will be the greater between `align_of::<T>` and `min_align.next_power_of_two()`).
 ///
 /// # Safety
 ///
@@ -163,9 +263,10 @@ pub fn copy_to_offset_with_align<T: Copy, S: Slab + ?Sized>(
     let t_layout = Layout::new::<T>();
     let offsets =
         compute_and_validate_offsets(&*dst, start_offset, t_layout, min_alignment, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
 
     // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
-    let dst_ptr = unsafe { dst.base_ptr_mut().add(offsets.start) }.cast::<T>();
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
 
     // SAFETY:
     // - src is valid as we have a reference to it
@@ -183,13 +284,36 @@ pub fn copy_to_offset_with_align<T: Copy, S: Slab + ?Sized>(
     Ok(offsets.into())
 }
 
+/// Like [`copy_to_offset_with_align`], except that `min_alignment` must be a power of two,
+/// rather than being rounded up to one.
+///
+/// This catches passing something which isn't an alignment, like a stride, by mistake. The
+/// function will return an error of kind [`ErrorKind::InvalidAlignment`] and no data will be
+/// copied if `min_alignment` isn't a power of two.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_to_offset_with_align_strict<T: Copy, S: Slab + ?Sized>(
+    src: &T,
+    dst: &mut S,
+    start_offset: usize,
+    min_alignment: usize,
+) -> Result<CopyRecord, Error> {
+    validate_alignment(min_alignment)?;
+    copy_to_offset_with_align(src, dst, start_offset, min_alignment)
+}
+
 /// Copies from `slice` into the memory represented by `dst` starting at *exactly*
 /// `start_offset` bytes past the start of `self`.
 ///
 /// - `start_offset` is the offset into the allocation represented by `dst`, in bytes,
-/// where the first byte of the copied data will be placed. If the requested
-/// start offset does not satisfy computed alignment requirements, an error will
-/// be returned and no data will be copied.
+///   where the first byte of the copied data will be placed. If the requested
+///   start offset does not satisfy computed alignment requirements, an error will
+///   be returned and no data will be copied.
 ///
 /// # Safety
 ///
@@ -209,15 +333,15 @@ pub fn copy_from_slice_to_offset_exact<T: Copy, S: Slab + ?Sized>(
 /// `start_offset` bytes past the start of `dst` and with minimum alignment `min_alignment`.
 ///
 /// - `start_offset` is the offset into the allocation represented by `dst`, in bytes,
-/// where the first byte of the copied data will be placed. If the requested
-/// start offset does not satisfy computed alignment requirements, an error will
-/// be returned and no data will be copied.
+///   where the first byte of the copied data will be placed. If the requested
+///   start offset does not satisfy computed alignment requirements, an error will
+///   be returned and no data will be copied.
 /// - `min_alignment` is the minimum alignment that you are requesting the copy be aligned to. The
-/// copy may be aligned greater than `min_alignment` depending on the alignment requirements
-/// of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
-/// `min_align.next_power_of_two()`).
+///   copy may be aligned greater than `min_alignment` depending on the alignment requirements
+///   of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
+///   `min_align.next_power_of_two()`).
 ///     - The whole data of the slice will be copied directly, so, alignment between elements
-///     ignores `min_alignment`.
+///       ignores `min_alignment`.
 ///
 /// # Safety
 ///
@@ -235,7 +359,7 @@ pub fn copy_from_slice_to_offset_with_align_exact<T: Copy, S: Slab + ?Sized>(
     let offsets = compute_and_validate_offsets(&*dst, start_offset, t_layout, min_alignment, true)?;
 
     // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
-    let dst_ptr = unsafe { dst.base_ptr_mut().add(offsets.start) }.cast::<T>();
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
 
     // SAFETY:
     // - src is valid as we have a reference to it
@@ -253,14 +377,56 @@ pub fn copy_from_slice_to_offset_with_align_exact<T: Copy, S: Slab + ?Sized>(
     Ok(offsets.into())
 }
 
+/// Copies from `src` into the memory represented by `dst` starting at *exactly* `start_offset`
+/// bytes past the start of `dst`, which doesn't need to be aligned for `T`.
+///
+/// The elements are laid out as a `[T]`, so each of them is placed `size_of::<T>()` bytes after
+/// the previous one. See [`copy_to_offset_unaligned`] for more.
+///
+/// - `start_offset` is the offset into the allocation represented by `dst`, in bytes,
+///   where the first byte of the copied data will be placed.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_from_slice_to_offset_unaligned<T: Copy, S: Slab + ?Sized>(
+    src: &[T],
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    let layout = Layout::from_size_align(core::mem::size_of_val(src), 1)?;
+    let offsets = compute_and_validate_offsets(&*dst, start_offset, layout, 1, true)?;
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) };
+
+    // SAFETY:
+    // - src is valid as we have a reference to it
+    // - dst is valid so long as requirements for `slab` were met, i.e.
+    // we have unique access to the region described and that it is valid for the duration
+    // of 'a.
+    // - areas not overlapping as long as safety requirements of creation of `self` were met,
+    // i.e. that we have exclusive access to the region of memory described.
+    // - copying bytes has no alignment requirements
+    // - checked that copy stays within bounds of our allocation
+    unsafe {
+        core::ptr::copy_nonoverlapping(src.as_ptr().cast::<u8>(), dst_ptr, layout.size());
+    }
+
+    Ok(offsets.into())
+}
+
 /// Copies from `slice` into the memory represented by `dst` starting at a minimum location
 /// of `start_offset` bytes past the start of `self`.
 ///
 /// - `start_offset` is the offset into the allocation represented by `dst`,
-/// in bytes, before which any copied data will *certainly not* be placed. However,
-/// the actual beginning of the copied data may not be exactly at `start_offset` if
-/// padding bytes are needed to satisfy alignment requirements. The actual beginning
-/// of the copied bytes is contained in the returned [`CopyRecord`].
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
 ///
 /// # Safety
 ///
@@ -280,16 +446,16 @@ pub fn copy_from_slice_to_offset<T: Copy, S: Slab + ?Sized>(
 /// of `start_offset` bytes past the start of `dst`.
 ///
 /// - `start_offset` is the offset into the allocation represented by `dst`,
-/// in bytes, before which any copied data will *certainly not* be placed. However,
-/// the actual beginning of the copied data may not be exactly at `start_offset` if
-/// padding bytes are needed to satisfy alignment requirements. The actual beginning
-/// of the copied bytes is contained in the returned [`CopyRecord`].
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
 /// - `min_alignment` is the minimum alignment that you are requesting the copy be aligned to. The
-/// copy may be aligned greater than `min_alignment` depending on the alignment requirements
-/// of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
-/// `min_align.next_power_of_two()`).
+///   copy may be aligned greater than `min_alignment` depending on the alignment requirements
+///   of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
+///   `min_align.next_power_of_two()`).
 ///     - The whole data of the slice will be copied directly, so alignment between elements
-///     ignores `min_alignment`.
+///       ignores `min_alignment`.
 ///
 /// # Safety
 ///
@@ -306,9 +472,10 @@ pub fn copy_from_slice_to_offset_with_align<T: Copy, S: Slab + ?Sized>(
     let t_layout = Layout::for_value(src);
     let offsets =
         compute_and_validate_offsets(&*dst, start_offset, t_layout, min_alignment, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
 
     // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
-    let dst_ptr = unsafe { dst.base_ptr_mut().add(offsets.start) }.cast::<T>();
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
 
     // SAFETY:
     // - src is valid as we have a reference to it
@@ -326,23 +493,99 @@ pub fn copy_from_slice_to_offset_with_align<T: Copy, S: Slab + ?Sized>(
     Ok(offsets.into())
 }
 
+/// Like [`copy_from_slice_to_offset_with_align`], except that `min_alignment` must be a power of
+/// two, rather than being rounded up to one.
+///
+/// This catches passing something which isn't an alignment, like a stride, by mistake. The
+/// function will return an error of kind [`ErrorKind::InvalidAlignment`] and no data will be
+/// copied if `min_alignment` isn't a power of two.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_from_slice_to_offset_with_align_strict<T: Copy, S: Slab + ?Sized>(
+    src: &[T],
+    dst: &mut S,
+    start_offset: usize,
+    min_alignment: usize,
+) -> Result<CopyRecord, Error> {
+    validate_alignment(min_alignment)?;
+    copy_from_slice_to_offset_with_align(src, dst, start_offset, min_alignment)
+}
+
+/// Copies the bytes of `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, placed as data with the layout `layout`.
+///
+/// This is for data whose type isn't known at compile time, for example a struct described by
+/// shader reflection, but whose size and alignment are known at runtime. The copy is validated and
+/// aligned just like a copy of a `T` with `Layout::new::<T>() == layout` would be.
+///
+/// - `start_offset` is the offset into the allocation represented by `dst`,
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy the alignment of `layout`. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
+///
+/// The function will return an error of kind [`ErrorKind::InvalidLayout`] if the length of `src`
+/// isn't the size of `layout`.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_bytes_to_offset_with_layout<S: Slab + ?Sized>(
+    src: &[u8],
+    layout: Layout,
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    if src.len() != layout.size() {
+        return Err(Error::invalid_layout());
+    }
+
+    let offsets = compute_and_validate_offsets(&*dst, start_offset, layout, 1, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) };
+
+    // SAFETY:
+    // - src is valid as we have a reference to it
+    // - dst is valid so long as requirements for `slab` were met, i.e.
+    // we have unique access to the region described and that it is valid for the duration
+    // of 'a.
+    // - areas not overlapping as long as safety requirements of creation of `self` were met,
+    // i.e. that we have exclusive access to the region of memory described.
+    // - checked that copy stays within bounds of our allocation
+    unsafe {
+        core::ptr::copy_nonoverlapping(src.as_ptr(), dst_ptr, src.len());
+    }
+
+    Ok(offsets.into())
+}
+
 /// Copies from `src` iterator into the memory represented by `dst` starting at a minimum location
 /// of `start_offset` bytes past the start of `dst`.
 ///
 /// Returns a vector of [`CopyRecord`]s, one for each item in the `src` iterator.
 ///
 /// - `start_offset` is the offset into the allocation represented by `dst`,
-/// in bytes, before which any copied data will *certainly not* be placed. However,
-/// the actual beginning of the copied data may not be exactly at `start_offset` if
-/// padding bytes are needed to satisfy alignment requirements. The actual beginning
-/// of the copied bytes is contained in the returned [`CopyRecord`]s.
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`]s.
 /// - `min_alignment` is the minimum alignment that you are requesting the copy be aligned to. The
-/// copy may be aligned greater than `min_alignment` depending on the alignment requirements
-/// of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
-/// `min_align.next_power_of_two()`).
+///   copy may be aligned greater than `min_alignment` depending on the alignment requirements
+///   of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
+///   `min_align.next_power_of_two()`).
 /// - For this variation, `min_alignment` will also be respected *between* elements yielded by
-/// the iterator. To copy inner elements aligned only to `align_of::<T>()` (i.e. with the layout of
-/// an `[T]`), see [`copy_from_iter_to_offset_with_align_packed`].
+///   the iterator. To copy inner elements aligned only to `align_of::<T>()` (i.e. with the layout of
+///   an `[T]`), see [`copy_from_iter_to_offset_with_align_packed`].
 ///
 /// # Safety
 ///
@@ -367,6 +610,49 @@ pub fn copy_from_iter_to_offset_with_align<T: Copy, Iter: Iterator<Item = T>, S:
     .collect::<Result<Vec<_>, _>>()
 }
 
+/// Like [`copy_from_iter_to_offset_with_align`], except that rather than collecting the
+/// [`CopyRecord`] of each item into a vector, `on_record` is called with each item's record
+/// directly after that item is copied. This makes it usable without the `std` feature, for example
+/// by pushing the records into a fixed-capacity collection.
+///
+/// Returns a single [`CopyRecord`] spanning all of the copied items, or `None` if the `src`
+/// iterator is empty.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_from_iter_to_offset_with_align_with_callback<T, Iter, S, F>(
+    src: Iter,
+    dst: &mut S,
+    start_offset: usize,
+    min_alignment: usize,
+    mut on_record: F,
+) -> Result<Option<CopyRecord>, Error>
+where
+    T: Copy,
+    Iter: Iterator<Item = T>,
+    S: Slab + ?Sized,
+    F: FnMut(CopyRecord),
+{
+    let mut offset = start_offset;
+    let mut span: Option<CopyRecord> = None;
+
+    for item in src {
+        let copy_record = copy_to_offset_with_align(&item, dst, offset, min_alignment)?;
+        offset = copy_record.end_offset;
+        span = Some(match span {
+            Some(span) => span.merge(copy_record),
+            None => copy_record,
+        });
+        on_record(copy_record);
+    }
+
+    Ok(span)
+}
+
 /// Like [`copy_from_iter_to_offset_with_align`] except that
 /// alignment between elements yielded by the iterator will ignore `min_alignment`
 /// and rather only be aligned to the alignment of `T`.
@@ -437,3 +723,982 @@ pub fn copy_from_iter_to_offset_with_align_exact_packed<
         end_offset_padded: prev_record.end_offset_padded,
     }))
 }
+
+/// Writes `count` copies of `value` contiguously into the memory represented by `dst` starting
+/// at a minimum location of `start_offset` bytes past the start of `dst` and with minimum
+/// alignment `min_alignment`. This is essentially a typed `memset`.
+///
+/// Returns one [`CopyRecord`] covering the whole filled block. If `count` is 0, no data is
+/// written.
+///
+/// - `start_offset` is the offset into the allocation represented by `dst`,
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
+/// - `min_alignment` is the minimum alignment that you are requesting the copy be aligned to. The
+///   copy may be aligned greater than `min_alignment` depending on the alignment requirements
+///   of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
+///   `min_align.next_power_of_two()`).
+///     - The copies of `value` are laid out as a `[T; count]`, so alignment between elements
+///       ignores `min_alignment`.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn fill_at_offset<T: Copy, S: Slab + ?Sized>(
+    value: &T,
+    count: usize,
+    dst: &mut S,
+    start_offset: usize,
+    min_alignment: usize,
+) -> Result<CopyRecord, Error> {
+    let t_layout = Layout::array::<T>(count)?;
+    let offsets =
+        compute_and_validate_offsets(&*dst, start_offset, t_layout, min_alignment, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
+
+    for i in 0..count {
+        // SAFETY:
+        // - dst is valid so long as requirements for `slab` were met, i.e.
+        // we have unique access to the region described and that it is valid for the duration
+        // of 'a.
+        // - dst aligned at least to align_of::<T>(), so each element is as well
+        // - checked that the whole `[T; count]` stays within bounds of our allocation
+        unsafe {
+            dst_ptr.add(i).write(*value);
+        }
+    }
+
+    Ok(offsets.into())
+}
+
+/// Fills `range` of the memory represented by `dst` with zero bytes.
+///
+/// After this function returns successfully, every byte within `range` is **initialized** (to 0),
+/// which makes this a natural building block for "clear then upload" patterns, or for satisfying
+/// APIs which require some region of a buffer to be zeroed.
+///
+/// The returned [`CopyRecord`] describes the zeroed range. No alignment is applied, so its
+/// `end_offset` and `end_offset_padded` are equal.
+///
+/// The function will return an error if `range` is not within the bounds of `dst`, in which case
+/// no memory is written.
+#[inline]
+pub fn zero_range<S: Slab + ?Sized, R: RangeBounds<usize>>(
+    dst: &mut S,
+    range: R,
+) -> Result<CopyRecord, Error> {
+    let range = validate_range(&*dst, range)?;
+
+    // SAFETY: `range` has been checked to be within the bounds of `dst`
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(range.start) };
+
+    // SAFETY:
+    // - dst is valid so long as requirements for `slab` were met, i.e.
+    // we have unique access to the region described and that it is valid for the duration
+    // of 'a.
+    // - checked that the range stays within bounds of our allocation
+    // - `u8` has no alignment requirements
+    unsafe {
+        core::ptr::write_bytes(dst_ptr, 0, range.len());
+    }
+
+    Ok(CopyRecord {
+        start_offset: range.start,
+        end_offset: range.end,
+        end_offset_padded: range.end,
+    })
+}
+
+/// Moves the bytes within `src_range` of `slab` to start at *exactly* `dst_offset` bytes past the
+/// start of `slab`. The source and destination ranges may overlap.
+///
+/// This is useful, for example, when compacting a partially-filled staging buffer or shifting
+/// data to make room for a header.
+///
+/// The returned [`CopyRecord`] describes where the data now lives. No alignment is applied, so it
+/// is up to you to make sure `dst_offset` is properly aligned for whatever data you're moving.
+///
+/// The function will return an error if either the source or destination range is not within
+/// the bounds of `slab`, in which case no memory is written.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the moved data in the wrong way. Bytes in the source range which are
+/// uninitialized will also be uninitialized in the destination range, and bytes in the source
+/// range which are not overwritten by the move retain their previous contents. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn move_range<S: Slab + ?Sized, R: RangeBounds<usize>>(
+    slab: &mut S,
+    src_range: R,
+    dst_offset: usize,
+) -> Result<CopyRecord, Error> {
+    let src_range = validate_range(&*slab, src_range)?;
+    let dst_end = dst_offset
+        .checked_add(src_range.len())
+        .ok_or(Error::invalid_layout())?;
+    let dst_range = validate_range(&*slab, dst_offset..dst_end)?;
+
+    let base_ptr = slab.base_non_null().as_ptr();
+
+    // SAFETY: both ranges have been checked to be within the bounds of `slab`
+    let (src_ptr, dst_ptr) =
+        unsafe { (base_ptr.add(src_range.start), base_ptr.add(dst_range.start)) };
+
+    // SAFETY:
+    // - slab is valid so long as requirements for `slab` were met, i.e.
+    // we have unique access to the region described and that it is valid for the duration
+    // of 'a.
+    // - checked that both ranges stay within bounds of our allocation
+    // - `ptr::copy` allows the ranges to overlap
+    // - `u8` has no alignment requirements
+    unsafe {
+        core::ptr::copy(src_ptr.cast_const(), dst_ptr, src_range.len());
+    }
+
+    Ok(CopyRecord {
+        start_offset: dst_range.start,
+        end_offset: dst_range.end,
+        end_offset_padded: dst_range.end,
+    })
+}
+
+/// Copies the bytes within `src_range` of `src` into the memory represented by `dst` starting at
+/// *exactly* `dst_offset` bytes past the start of `dst`.
+///
+/// This is useful for moving data between two slabs, for example from a CPU-side [`HeapSlab`]
+/// into a GPU-mapped slab. No alignment is applied, so it is up to you to make sure `dst_offset`
+/// is properly aligned for whatever data you're copying. To have the destination offset aligned
+/// for you, see [`copy_between_slabs_with_align`].
+///
+/// The function will return an error if either `src_range` is not within the bounds of `src` or
+/// the destination range is not within the bounds of `dst`, in which case no data is copied.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. Bytes in `src_range` which are
+/// uninitialized will also be uninitialized in the destination. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_between_slabs<S1, S2, R>(
+    src: &S1,
+    src_range: R,
+    dst: &mut S2,
+    dst_offset: usize,
+) -> Result<CopyRecord, Error>
+where
+    S1: Slab + ?Sized,
+    S2: Slab + ?Sized,
+    R: RangeBounds<usize>,
+{
+    let src_range = validate_range(src, src_range)?;
+    let dst_end = dst_offset
+        .checked_add(src_range.len())
+        .ok_or(Error::invalid_layout())?;
+    let dst_range = validate_range(&*dst, dst_offset..dst_end)?;
+
+    copy_validated_range_between_slabs(src, src_range, dst, dst_range.start);
+
+    Ok(CopyRecord {
+        start_offset: dst_range.start,
+        end_offset: dst_range.end,
+        end_offset_padded: dst_range.end,
+    })
+}
+
+/// Copies the bytes within `src_range` of `src` into the memory represented by `dst` starting at
+/// a minimum location of `dst_start_offset` bytes past the start of `dst` and with minimum
+/// alignment `min_alignment`.
+///
+/// - `dst_start_offset` is the offset into the allocation represented by `dst`,
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `dst_start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
+/// - `min_alignment` is the minimum alignment to which the copy will be aligned.
+///
+/// The function will return an error if either `src_range` is not within the bounds of `src` or
+/// the destination range is not within the bounds of `dst`, in which case no data is copied.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. Bytes in `src_range` which are
+/// uninitialized will also be uninitialized in the destination. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_between_slabs_with_align<S1, S2, R>(
+    src: &S1,
+    src_range: R,
+    dst: &mut S2,
+    dst_start_offset: usize,
+    min_alignment: usize,
+) -> Result<CopyRecord, Error>
+where
+    S1: Slab + ?Sized,
+    S2: Slab + ?Sized,
+    R: RangeBounds<usize>,
+{
+    let src_range = validate_range(src, src_range)?;
+    let layout = Layout::from_size_align(src_range.len(), 1)?;
+    let offsets =
+        compute_and_validate_offsets(&*dst, dst_start_offset, layout, min_alignment, false)?;
+    poison_alignment_gap(dst, dst_start_offset, &offsets);
+
+    copy_validated_range_between_slabs(src, src_range, dst, offsets.start);
+
+    Ok(offsets.into())
+}
+
+/// `src_range` must have been validated to be within `src`, and `dst_offset + src_range.len()`
+/// must have been validated to be within `dst`.
+#[inline(always)]
+fn copy_validated_range_between_slabs<S1, S2>(
+    src: &S1,
+    src_range: Range<usize>,
+    dst: &mut S2,
+    dst_offset: usize,
+) where
+    S1: Slab + ?Sized,
+    S2: Slab + ?Sized,
+{
+    // SAFETY: both ranges have been checked to be within the bounds of their slabs by the caller
+    let (src_ptr, dst_ptr) = unsafe {
+        (
+            src.read_base_non_null().as_ptr().add(src_range.start),
+            dst.base_non_null().as_ptr().add(dst_offset),
+        )
+    };
+
+    // SAFETY:
+    // - src is valid so long as requirements for `src` were met, i.e. it is not mutably
+    // aliased for the duration of the borrow.
+    // - dst is valid so long as requirements for `dst` were met, i.e.
+    // we have unique access to the region described and that it is valid for the duration
+    // of 'a.
+    // - areas not overlapping as long as safety requirements of creation of `dst` were met,
+    // i.e. that we have exclusive access to the region of memory described, which means
+    // the shared borrow of `src` can't alias it.
+    // - checked that both ranges stay within bounds of their allocations
+    // - `u8` has no alignment requirements
+    unsafe {
+        core::ptr::copy_nonoverlapping(src_ptr, dst_ptr, src_range.len());
+    }
+}
+
+/// Copies `src` into each of the slabs in `dsts`, starting at a minimum location of
+/// `start_offset` bytes past the start of each slab and with minimum alignment `min_alignment`.
+///
+/// This is useful for keeping several copies of the same data consistent, for example in a set of
+/// per-frame-in-flight uniform buffers.
+///
+/// Returns a vector of [`CopyRecord`]s, one for each slab in `dsts`, in the same order. Since the
+/// base addresses of the slabs may be aligned differently, the records are not necessarily equal.
+///
+/// The slabs are borrowed, so they may be owned elsewhere. To broadcast to slabs of different
+/// types, view each of them as bytes with [`Slab::as_maybe_uninit_bytes_mut`].
+///
+/// The destinations in all slabs are validated before any data is copied, so if an error is
+/// returned, *no* slab has been written to.
+///
+/// - `start_offset` is the offset into each allocation represented by `dsts`,
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`]s.
+/// - `min_alignment` is the minimum alignment to which the copy will be aligned. The
+///   copy may not actually be aligned to `min_alignment` depending on the alignment requirements
+///   of `T` (the actual alignment will be the greater between `align_of::<T>` and `min_align.next_power_of_two()`).
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[cfg(feature = "std")]
+#[inline]
+pub fn broadcast_to_offsets<T: Copy, S: Slab + ?Sized>(
+    src: &T,
+    dsts: &mut [&mut S],
+    start_offset: usize,
+    min_alignment: usize,
+) -> Result<Vec<CopyRecord>, Error> {
+    let t_layout = Layout::new::<T>();
+    let all_offsets = dsts
+        .iter()
+        .map(|dst| {
+            compute_and_validate_offsets(&**dst, start_offset, t_layout, min_alignment, false)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(dsts
+        .iter_mut()
+        .zip(all_offsets)
+        .map(|(dst, offsets)| {
+            poison_alignment_gap(&mut **dst, start_offset, &offsets);
+
+            // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+            let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
+
+            // SAFETY:
+            // - src is valid as we have a reference to it
+            // - dst is valid so long as requirements for `slab` were met, i.e.
+            // we have unique access to the region described and that it is valid for the duration
+            // of 'a.
+            // - areas not overlapping as long as safety requirements of creation of `self` were met,
+            // i.e. that we have exclusive access to the region of memory described.
+            // - dst aligned at least to align_of::<T>()
+            // - checked that copy stays within bounds of our allocation
+            unsafe {
+                core::ptr::copy_nonoverlapping(src as *const T, dst_ptr, 1);
+            }
+
+            offsets.into()
+        })
+        .collect())
+}
+
+/// Copies `src` into the memory represented by `dst` `count` times in a row, starting at a
+/// minimum location of `start_offset` bytes past the start of `dst` and with minimum alignment
+/// `min_alignment`.
+///
+/// Returns one [`CopyRecord`] covering the whole block of repetitions. If `count` is 0, no data
+/// is written.
+///
+/// - `stride` is the distance, in bytes, between the start of one repetition and the start of the
+///   next. If `None`, repetitions are placed directly after each other, i.e. the stride is
+///   `size_of_val(src)`. If `Some`, it must be at least `size_of_val(src)` and a multiple of
+///   `align_of::<T>()`, otherwise an error is returned. The bytes between repetitions are
+///   not written.
+/// - `start_offset` is the offset into the allocation represented by `dst`,
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
+/// - `min_alignment` is the minimum alignment that you are requesting the copy be aligned to. The
+///   copy may be aligned greater than `min_alignment` depending on the alignment requirements
+///   of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
+///   `min_align.next_power_of_two()`).
+///     - Only the first repetition is aligned to `min_alignment`, the others are placed
+///       according to `stride`.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn repeat_to_offset<T: Copy, S: Slab + ?Sized>(
+    src: &[T],
+    count: usize,
+    stride: Option<usize>,
+    dst: &mut S,
+    start_offset: usize,
+    min_alignment: usize,
+) -> Result<CopyRecord, Error> {
+    let src_layout = Layout::for_value(src);
+    let stride = stride.unwrap_or(src_layout.size());
+    if stride < src_layout.size() {
+        return Err(Error::invalid_layout());
+    }
+    if stride % src_layout.align() != 0 {
+        return Err(Error::requested_offset_unaligned());
+    }
+
+    let total_size = match count.checked_sub(1) {
+        Some(extra) => stride
+            .checked_mul(extra)
+            .and_then(|size| size.checked_add(src_layout.size()))
+            .ok_or(Error::invalid_layout())?,
+        None => 0,
+    };
+    let t_layout = Layout::from_size_align(total_size, src_layout.align())?;
+    let offsets =
+        compute_and_validate_offsets(&*dst, start_offset, t_layout, min_alignment, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) };
+
+    for i in 0..count {
+        // SAFETY:
+        // - src is valid as we have a reference to it
+        // - dst is valid so long as requirements for `slab` were met, i.e.
+        // we have unique access to the region described and that it is valid for the duration
+        // of 'a.
+        // - areas not overlapping as long as safety requirements of creation of `self` were met,
+        // i.e. that we have exclusive access to the region of memory described.
+        // - dst aligned at least to align_of::<T>(), and `stride` is a multiple of it
+        // - checked that all repetitions stay within bounds of our allocation
+        unsafe {
+            let rep_ptr = dst_ptr.add(i * stride).cast::<T>();
+            core::ptr::copy_nonoverlapping(src.as_ptr(), rep_ptr, src.len());
+        }
+    }
+
+    Ok(offsets.into())
+}
+
+/// Computes the [`CopyRecord`] that [`copy_to_offset_with_align`] would return for a `T`, without
+/// copying any data.
+///
+/// This is useful to pre-compute where data will land in `dst` (for example, to fill in
+/// descriptor offsets) before the data itself is ready. So long as the same parameters are given
+/// to the actual copy and `dst` doesn't change, the copy will land exactly where planned.
+///
+/// Returns the same errors the copy would.
+#[inline]
+pub fn plan_copy<T, S: Slab + ?Sized>(
+    dst: &S,
+    start_offset: usize,
+    min_alignment: usize,
+) -> Result<CopyRecord, Error> {
+    plan_copy_for_layout(dst, Layout::new::<T>(), start_offset, min_alignment)
+}
+
+/// Computes the [`CopyRecord`] that [`copy_to_offset_with_align_exact`] would return for a `T`,
+/// without copying any data.
+///
+/// See [`plan_copy`] for more.
+#[inline]
+pub fn plan_copy_exact<T, S: Slab + ?Sized>(
+    dst: &S,
+    start_offset: usize,
+    min_alignment: usize,
+) -> Result<CopyRecord, Error> {
+    let offsets =
+        compute_and_validate_offsets(dst, start_offset, Layout::new::<T>(), min_alignment, true)?;
+    Ok(offsets.into())
+}
+
+/// Computes the [`CopyRecord`] that a copy of data with layout `layout` into `dst` would produce,
+/// without copying any data.
+///
+/// For example, to plan a [`copy_from_slice_to_offset_with_align`], use
+/// `Layout::array::<T>(len)`.
+///
+/// See [`plan_copy`] for more.
+#[inline]
+pub fn plan_copy_for_layout<S: Slab + ?Sized>(
+    dst: &S,
+    layout: Layout,
+    start_offset: usize,
+    min_alignment: usize,
+) -> Result<CopyRecord, Error> {
+    let offsets = compute_and_validate_offsets(dst, start_offset, layout, min_alignment, false)?;
+    Ok(offsets.into())
+}
+
+/// Computes the [`CopyRecord`] that a copy of data with layout `layout` would produce in a slab
+/// whose base pointer has the address `base_addr` and which is `slab_size` bytes large, without
+/// copying any data.
+///
+/// This allows planning copies before the slab itself is available, for example before a buffer
+/// has been mapped. Only the alignment of `base_addr` matters, so if you know the alignment the
+/// mapping will have, you may pass that alignment as `base_addr`.
+///
+/// See [`plan_copy`] for more.
+#[inline]
+pub fn plan_copy_for_raw_parts(
+    base_addr: usize,
+    slab_size: usize,
+    layout: Layout,
+    start_offset: usize,
+    min_alignment: usize,
+) -> Result<CopyRecord, Error> {
+    let offsets = compute_and_validate_offsets_for_raw_parts(
+        base_addr,
+        slab_size,
+        start_offset,
+        layout,
+        min_alignment,
+        false,
+    )?;
+    Ok(offsets.into())
+}
+
+/// Computes the number of bytes a slab needs to have for a [`copy_from_slice_to_offset_with_align`]
+/// (or [`copy_from_iter_to_offset_with_align_packed`]) of `len` elements of `T`, starting at offset
+/// 0, to succeed.
+///
+/// This assumes that the base address of the slab is aligned to at least the alignment of the
+/// copy, i.e. the greater of `align_of::<T>()` and `min_alignment.next_power_of_two()`. If it is
+/// not, extra padding may be needed at the start of the slab.
+#[inline]
+pub fn required_size_for_slice<T>(len: usize, min_alignment: usize) -> Result<usize, Error> {
+    let layout = Layout::array::<T>(len)?.align_to(min_alignment.next_power_of_two())?;
+    Ok(layout.pad_to_align().size())
+}
+
+/// Computes the number of bytes a slab needs to have for a
+/// [`copy_from_iter_to_offset_with_align`] of `count` items of type `T`, starting at offset 0, to
+/// succeed. Unlike [`required_size_for_slice`], this accounts for each item being aligned to
+/// `min_alignment`.
+///
+/// This assumes that the base address of the slab is aligned to at least the alignment of the
+/// copy, i.e. the greater of `align_of::<T>()` and `min_alignment.next_power_of_two()`. If it is
+/// not, extra padding may be needed at the start of the slab.
+#[inline]
+pub fn required_size_for_iter<T>(count: usize, min_alignment: usize) -> Result<usize, Error> {
+    let item_layout = Layout::new::<T>()
+        .align_to(min_alignment.next_power_of_two())?
+        .pad_to_align();
+    let size = item_layout
+        .size()
+        .checked_mul(count)
+        .ok_or(Error::invalid_layout())?;
+    if size > isize::MAX as usize {
+        return Err(Error::invalid_layout());
+    }
+    Ok(size)
+}
+
+/// Copies from `src` iterator into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, laid out as a `[T]`.
+///
+/// Unlike the other iterator copy functions, the whole destination is validated up front using
+/// the length reported by `src`, so if an error is returned, `dst` is left untouched.
+///
+/// See [`copy_from_exact_iter_to_offset_with_align`] for more, including what happens if `src`
+/// reports the wrong length.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_from_exact_iter_to_offset<T, Iter, S>(
+    src: Iter,
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error>
+where
+    T: Copy,
+    Iter: ExactSizeIterator<Item = T>,
+    S: Slab + ?Sized,
+{
+    copy_from_exact_iter_to_offset_with_align(src, dst, start_offset, 1)
+}
+
+/// Copies from `src` iterator into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst` and with minimum alignment `min_alignment`,
+/// laid out as a `[T]`.
+///
+/// Unlike the other iterator copy functions, the whole destination is validated up front using
+/// the length reported by `src`, so if an error is returned, `dst` is left untouched.
+///
+/// `ExactSizeIterator` implementations may (incorrectly, but safely) report the wrong length, which
+/// can only be noticed while copying. Any items past the reported length are ignored. If `src`
+/// yields fewer items than it reported, the items it did yield have already been copied when
+/// that is noticed, so `Ok` is returned with a [`CopyRecord`] which only covers those items.
+///
+/// - `start_offset` is the offset into the allocation represented by `dst`,
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
+/// - `min_alignment` is the minimum alignment that you are requesting the copy be aligned to. The
+///   copy may be aligned greater than `min_alignment` depending on the alignment requirements
+///   of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
+///   `min_align.next_power_of_two()`).
+///     - The items will be laid out as a `[T]`, so alignment between elements
+///       ignores `min_alignment`.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_from_exact_iter_to_offset_with_align<T, Iter, S>(
+    src: Iter,
+    dst: &mut S,
+    start_offset: usize,
+    min_alignment: usize,
+) -> Result<CopyRecord, Error>
+where
+    T: Copy,
+    Iter: ExactSizeIterator<Item = T>,
+    S: Slab + ?Sized,
+{
+    let len = src.len();
+    let t_layout = Layout::array::<T>(len)?;
+    let offsets =
+        compute_and_validate_offsets(&*dst, start_offset, t_layout, min_alignment, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
+
+    let mut copied = 0;
+    for item in src.take(len) {
+        // SAFETY:
+        // - dst is valid so long as requirements for `slab` were met, i.e.
+        // we have unique access to the region described and that it is valid for the duration
+        // of 'a.
+        // - dst aligned at least to align_of::<T>(), so each element is as well
+        // - checked that a `[T; len]` stays within bounds of our allocation, and we copy at most
+        // `len` items
+        unsafe {
+            dst_ptr.add(copied).write(item);
+        }
+        copied += 1;
+    }
+
+    if copied == len {
+        Ok(offsets.into())
+    } else {
+        let end_offset = offsets.start + copied * core::mem::size_of::<T>();
+        Ok(CopyRecord {
+            start_offset: offsets.start,
+            end_offset,
+            end_offset_padded: end_offset,
+        })
+    }
+}
+
+/// Lazily copies items from `src` iterator into the memory represented by `dst` starting at a
+/// minimum location of `start_offset` bytes past the start of `dst` and with minimum alignment
+/// `min_alignment`.
+///
+/// Returns an iterator which copies one item each time [`next`][Iterator::next] is called,
+/// yielding the [`CopyRecord`] of that item's copy. Nothing is copied until the returned iterator
+/// is advanced, and you may stop at any time, for example once some budget is exhausted.
+///
+/// Items are placed exactly as with [`copy_from_iter_to_offset_with_align`], i.e. `min_alignment`
+/// is respected *between* items as well. Once a copy fails, the iterator yields that error and
+/// then ends.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_iter_records<'a, T, Iter, S>(
+    src: Iter,
+    dst: &'a mut S,
+    start_offset: usize,
+    min_alignment: usize,
+) -> CopyIterRecords<'a, Iter, S>
+where
+    T: Copy,
+    Iter: Iterator<Item = T>,
+    S: Slab + ?Sized,
+{
+    CopyIterRecords {
+        src,
+        dst,
+        offset: start_offset,
+        min_alignment,
+        failed: false,
+    }
+}
+
+/// Iterator which lazily copies items into a [`Slab`]. Created by [`copy_iter_records`].
+pub struct CopyIterRecords<'a, Iter, S: Slab + ?Sized> {
+    src: Iter,
+    dst: &'a mut S,
+    offset: usize,
+    min_alignment: usize,
+    failed: bool,
+}
+
+impl<'a, Iter, S: Slab + ?Sized> CopyIterRecords<'a, Iter, S> {
+    /// The offset at or after which the next item will be copied.
+    #[inline]
+    pub fn next_offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<'a, T, Iter, S> Iterator for CopyIterRecords<'a, Iter, S>
+where
+    T: Copy,
+    Iter: Iterator<Item = T>,
+    S: Slab + ?Sized,
+{
+    type Item = Result<CopyRecord, Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let item = self.src.next()?;
+        match copy_to_offset_with_align(&item, self.dst, self.offset, self.min_alignment) {
+            Ok(copy_record) => {
+                self.offset = copy_record.end_offset;
+                Some(Ok(copy_record))
+            }
+            Err(err) => {
+                self.failed = true;
+                Some(Err(err))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.failed {
+            (0, Some(0))
+        } else {
+            self.src.size_hint()
+        }
+    }
+}
+
+impl<'a, T, Iter, S> core::iter::FusedIterator for CopyIterRecords<'a, Iter, S>
+where
+    T: Copy,
+    Iter: core::iter::FusedIterator<Item = T>,
+    S: Slab + ?Sized,
+{
+}
+
+/// Copies each value in `pairs` into the memory represented by `dst` at *exactly* the paired
+/// offset, in bytes, past the start of `dst`.
+///
+/// This is useful for patch-style updates, for example updating a handful of per-object
+/// transforms inside a big buffer. All destinations are validated before any data is copied, so
+/// either every value is copied or an error is returned and `dst` is left untouched. `pairs` is
+/// iterated twice to achieve this, hence the `Clone` requirement on its iterator (which is
+/// satisfied by, for example, iterators over slices).
+///
+/// If the destinations of several values overlap, values later in `pairs` overwrite earlier ones.
+///
+/// The function will return an error if, for any pair:
+/// - the offset within `dst` is not properly aligned for `T`
+/// - the offset is out of bounds of `dst`
+/// - `offset + size_of::<T>()` is out of bounds of `dst`
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn scatter_copy<'t, T, I, S>(pairs: I, dst: &mut S) -> Result<(), Error>
+where
+    T: Copy + 't,
+    I: IntoIterator<Item = (usize, &'t T)>,
+    I::IntoIter: Clone,
+    S: Slab + ?Sized,
+{
+    let pairs = pairs.into_iter();
+    let t_layout = Layout::new::<T>();
+
+    for (offset, _) in pairs.clone() {
+        compute_and_validate_offsets(&*dst, offset, t_layout, 1, true)?;
+    }
+
+    for (offset, src) in pairs {
+        // the iterator may (incorrectly, but safely) yield different items the second time around,
+        // so we can't rely on the validation above for memory safety.
+        let offsets = compute_and_validate_offsets(&*dst, offset, t_layout, 1, true)?;
+
+        // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+        let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
+
+        // SAFETY:
+        // - src is valid as we have a reference to it
+        // - dst is valid so long as requirements for `slab` were met, i.e.
+        // we have unique access to the region described and that it is valid for the duration
+        // of 'a.
+        // - areas not overlapping as long as safety requirements of creation of `self` were met,
+        // i.e. that we have exclusive access to the region of memory described.
+        // - dst aligned at least to align_of::<T>()
+        // - checked that copy stays within bounds of our allocation
+        unsafe {
+            core::ptr::copy_nonoverlapping(src as *const T, dst_ptr, 1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies from each slice in `srcs`, one after another, into the memory represented by `dst`
+/// starting at a minimum location of `start_offset` bytes past the start of `dst`.
+///
+/// See [`copy_from_slices_to_offset_with_align`] for more.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[cfg(feature = "std")]
+#[inline]
+pub fn copy_from_slices_to_offset<T: Copy, S: Slab + ?Sized>(
+    srcs: &[&[T]],
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<(CopyRecord, Vec<usize>), Error> {
+    copy_from_slices_to_offset_with_align(srcs, dst, start_offset, 1)
+}
+
+/// Copies from each slice in `srcs`, one after another, into the memory represented by `dst`
+/// starting at a minimum location of `start_offset` bytes past the start of `dst`.
+///
+/// The slices are laid out contiguously, exactly as if they had first been concatenated into a
+/// single `[T]` which was then copied with [`copy_from_slice_to_offset_with_align`]. This is
+/// useful when data that should end up in a single buffer is split across several arrays on the
+/// CPU side, for example mesh data.
+///
+/// Returns a [`CopyRecord`] covering all of the copied data, along with a vector containing the
+/// offset in `dst`, in bytes, at which each slice in `srcs` begins, in the same order.
+///
+/// - `start_offset` is the offset into the allocation represented by `dst`,
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
+/// - `min_alignment` is the minimum alignment that you are requesting the copy be aligned to. The
+///   copy may be aligned greater than `min_alignment` depending on the alignment requirements
+///   of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
+///   `min_align.next_power_of_two()`).
+///     - Only the start of the first slice is aligned to `min_alignment`, the data of all slices
+///       is copied directly one after another.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[cfg(feature = "std")]
+#[inline]
+pub fn copy_from_slices_to_offset_with_align<T: Copy, S: Slab + ?Sized>(
+    srcs: &[&[T]],
+    dst: &mut S,
+    start_offset: usize,
+    min_alignment: usize,
+) -> Result<(CopyRecord, Vec<usize>), Error> {
+    let total_len = srcs
+        .iter()
+        .try_fold(0usize, |total, src| total.checked_add(src.len()))
+        .ok_or(Error::invalid_layout())?;
+    let t_layout = Layout::array::<T>(total_len)?;
+    let offsets =
+        compute_and_validate_offsets(&*dst, start_offset, t_layout, min_alignment, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let mut dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
+    let mut sub_offset = offsets.start;
+    let mut sub_offsets = Vec::with_capacity(srcs.len());
+
+    for src in srcs {
+        sub_offsets.push(sub_offset);
+
+        // SAFETY:
+        // - src is valid as we have a reference to it
+        // - dst is valid so long as requirements for `slab` were met, i.e.
+        // we have unique access to the region described and that it is valid for the duration
+        // of 'a.
+        // - areas not overlapping as long as safety requirements of creation of `self` were met,
+        // i.e. that we have exclusive access to the region of memory described.
+        // - dst aligned at least to align_of::<T>(), so the start of each slice is as well
+        // - checked that the combined length of all slices stays within bounds of our allocation
+        unsafe {
+            core::ptr::copy_nonoverlapping(src.as_ptr(), dst_ptr, src.len());
+            dst_ptr = dst_ptr.add(src.len());
+        }
+        sub_offset += core::mem::size_of_val(*src);
+    }
+
+    Ok((offsets.into(), sub_offsets))
+}
+
+/// Copies `header` followed by the elements of `items` into the memory represented by `dst`
+/// starting at a minimum location of `start_offset` bytes past the start of `dst`.
+///
+/// See [`copy_header_and_slice_to_offset_with_align`] for more.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_header_and_slice_to_offset<H: Copy, T: Copy, S: Slab + ?Sized>(
+    header: &H,
+    items: &[T],
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<(CopyRecord, usize), Error> {
+    copy_header_and_slice_to_offset_with_align(header, items, dst, start_offset, 1)
+}
+
+/// Copies `header` followed by the elements of `items` into the memory represented by `dst`
+/// starting at a minimum location of `start_offset` bytes past the start of `dst`.
+///
+/// This lays out the data the same way as a `#[repr(C)]` struct made up of an `H` followed by a
+/// trailing array of `items.len()` `T`s would be, i.e. the "flexible array member" pattern used
+/// by many FFI and GPU structures. The items begin at the first offset after the header which is
+/// properly aligned for `T`.
+///
+/// Returns a [`CopyRecord`] covering both the header and the items, along with the offset in
+/// `dst`, in bytes, at which the items begin.
+///
+/// - `start_offset` is the offset into the allocation represented by `dst`,
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
+/// - `min_alignment` is the minimum alignment that you are requesting the copy be aligned to. The
+///   copy may be aligned greater than `min_alignment` depending on the alignment requirements
+///   of `H` and `T` (the actual alignment will be the greatest of `align_of::<H>()`,
+///   `align_of::<T>()` and `min_align.next_power_of_two()`).
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_header_and_slice_to_offset_with_align<H: Copy, T: Copy, S: Slab + ?Sized>(
+    header: &H,
+    items: &[T],
+    dst: &mut S,
+    start_offset: usize,
+    min_alignment: usize,
+) -> Result<(CopyRecord, usize), Error> {
+    let (layout, items_offset) = Layout::new::<H>().extend(Layout::for_value(items))?;
+    let offsets = compute_and_validate_offsets(&*dst, start_offset, layout, min_alignment, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let header_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) };
+
+    // SAFETY: `items_offset` is within `layout`, which has been checked to fit in `dst`.
+    let items_ptr = unsafe { header_ptr.add(items_offset) }.cast::<T>();
+
+    // SAFETY:
+    // - header and items are valid as we have references to them
+    // - dst is valid so long as requirements for `slab` were met, i.e.
+    // we have unique access to the region described and that it is valid for the duration
+    // of 'a.
+    // - areas not overlapping as long as safety requirements of creation of `self` were met,
+    // i.e. that we have exclusive access to the region of memory described.
+    // - the start of the copy is aligned to `layout.align()`, which is at least `align_of::<H>()`
+    // and `align_of::<T>()`, and `items_offset` is a multiple of `align_of::<T>()`
+    // - checked that both copies stay within bounds of our allocation
+    unsafe {
+        core::ptr::copy_nonoverlapping(header as *const H, header_ptr.cast::<H>(), 1);
+        core::ptr::copy_nonoverlapping(items.as_ptr(), items_ptr, items.len());
+    }
+
+    Ok((offsets.into(), offsets.start + items_offset))
+}
@@ -0,0 +1,205 @@
+use super::*;
+
+use windows::Win32::Graphics::Direct3D12::ID3D12Resource;
+use windows::Win32::Graphics::Direct3D12::D3D12_HEAP_PROPERTIES;
+use windows::Win32::Graphics::Direct3D12::D3D12_HEAP_TYPE_UPLOAD;
+use windows::Win32::Graphics::Direct3D12::D3D12_RANGE;
+use windows::Win32::Graphics::Direct3D12::D3D12_RESOURCE_DIMENSION_BUFFER;
+use windows::Win32::Graphics::Direct3D12::D3D12_TEXTURE_DATA_PITCH_ALIGNMENT;
+use windows::Win32::Graphics::Direct3D12::D3D12_TEXTURE_DATA_PLACEMENT_ALIGNMENT;
+
+/// A [`Slab`] over a D3D12 buffer resource mapped with `ID3D12Resource::Map`, which is unmapped
+/// again when dropped.
+///
+/// Buffers in upload heaps are write-combined memory, so the copy functions on this type default
+/// to [`CopyHint::Streaming`] for them (see [`copy_hint`][D3D12MappedSlab::copy_hint]).
+pub struct D3D12MappedSlab<'a> {
+    resource: &'a ID3D12Resource,
+    base_ptr: NonNull<u8>,
+    size: usize,
+    upload_heap: bool,
+}
+
+/// Record of the results of a copy operation performed by
+/// [`D3D12MappedSlab::copy_texture_rows`].
+#[derive(Debug, Copy, Clone)]
+pub struct PitchedCopyRecord {
+    /// The record of the copy itself, spanning from the start of the first row to the end of the
+    /// last one.
+    pub record: CopyRecord,
+
+    /// The distance, in bytes, between the starts of consecutive rows. This is the `RowPitch` to
+    /// put in the `D3D12_SUBRESOURCE_FOOTPRINT` when copying the rows into a texture.
+    pub row_pitch: u32,
+}
+
+impl<'a> D3D12MappedSlab<'a> {
+    /// Map all of `resource`, which must be a buffer, into CPU memory.
+    ///
+    /// Returns an error if `resource` isn't a buffer, if it's too large to be mapped into the
+    /// address space, or if mapping fails.
+    ///
+    /// # Safety
+    ///
+    /// For as long as the returned value is alive, the mapped memory must uphold all the
+    /// requirements of the [`Slab`] trait. In particular, it must not be accessed other than
+    /// through the returned value, neither by the CPU (for example through another mapping of the
+    /// same resource) nor by the GPU.
+    ///
+    /// Also see the [top-level safety documentation][`crate#safety`].
+    pub unsafe fn map(resource: &'a ID3D12Resource) -> windows::core::Result<Self> {
+        let invalid = || windows::core::Error::from(windows::Win32::Foundation::E_INVALIDARG);
+
+        // SAFETY: `GetDesc` and `GetHeapProperties` have no safety requirements beyond being
+        // called on a valid resource.
+        let (desc, upload_heap) = unsafe {
+            let desc = resource.GetDesc();
+            let mut heap_properties = D3D12_HEAP_PROPERTIES::default();
+            // Reserved resources don't have a heap, treat them as not being in an upload heap.
+            let upload_heap = resource
+                .GetHeapProperties(Some(&mut heap_properties), None)
+                .is_ok()
+                && heap_properties.Type == D3D12_HEAP_TYPE_UPLOAD;
+            (desc, upload_heap)
+        };
+        if desc.Dimension != D3D12_RESOURCE_DIMENSION_BUFFER {
+            return Err(invalid());
+        }
+        let size = usize::try_from(desc.Width).map_err(|_| invalid())?;
+        if size > isize::MAX as usize {
+            return Err(invalid());
+        }
+
+        // Upload heaps are never read by the CPU, which an empty read range tells the driver.
+        let no_read = D3D12_RANGE { Begin: 0, End: 0 };
+        let read_range = if upload_heap {
+            Some(&no_read as *const D3D12_RANGE)
+        } else {
+            None
+        };
+        let mut data = core::ptr::null_mut();
+        // SAFETY: `read_range` and `data` are valid for the duration of the call.
+        unsafe { resource.Map(0, read_range, Some(&mut data))? };
+        let base_ptr = NonNull::new(data.cast::<u8>()).ok_or_else(invalid)?;
+
+        Ok(Self {
+            resource,
+            base_ptr,
+            size,
+            upload_heap,
+        })
+    }
+
+    /// The resource `self` is a mapping of.
+    #[inline]
+    pub fn resource(&self) -> &'a ID3D12Resource {
+        self.resource
+    }
+
+    /// Whether the resource lives in an upload heap, i.e. in write-combined memory.
+    #[inline]
+    pub fn is_upload_heap(&self) -> bool {
+        self.upload_heap
+    }
+
+    /// The copy strategy used by the copy functions on `self`: [`CopyHint::Streaming`] for
+    /// upload heaps, and [`CopyHint::Default`] otherwise.
+    #[inline]
+    pub fn copy_hint(&self) -> CopyHint {
+        if self.upload_heap {
+            CopyHint::Streaming
+        } else {
+            CopyHint::Default
+        }
+    }
+
+    /// Copies from `src` into `self` starting at a minimum location of `start_offset` bytes past
+    /// the start of `self`, using the [`copy_hint`][D3D12MappedSlab::copy_hint] of `self`.
+    ///
+    /// See [`copy_from_slice_to_offset_with_hint`] for more.
+    #[inline]
+    pub fn copy_from_slice_to_offset<T: Copy>(
+        &mut self,
+        src: &[T],
+        start_offset: usize,
+    ) -> Result<CopyRecord, Error> {
+        let hint = self.copy_hint();
+        copy_from_slice_to_offset_with_hint(src, self, start_offset, hint)
+    }
+
+    /// Copies the tightly packed rows of texture data in `src`, each `row_size` bytes long, into
+    /// `self`, laid out as required for a `CopyTextureRegion` from this buffer into a texture.
+    ///
+    /// The first row is placed at the first offset at or after `start_offset` which is aligned to
+    /// `D3D12_TEXTURE_DATA_PLACEMENT_ALIGNMENT`, and each following row starts a multiple of
+    /// `D3D12_TEXTURE_DATA_PITCH_ALIGNMENT` bytes after the previous one. The bytes between the
+    /// end of each row and the start of the next are left untouched.
+    ///
+    /// Returns [`ErrorKind::InvalidLayout`] if `row_size` is 0 or `src` isn't made up of whole rows.
+    pub fn copy_texture_rows(
+        &mut self,
+        src: &[u8],
+        row_size: usize,
+        start_offset: usize,
+    ) -> Result<PitchedCopyRecord, Error> {
+        if row_size == 0 || src.len() % row_size != 0 {
+            return Err(Error::invalid_layout());
+        }
+        let row_pitch = round_up_to_multiple(row_size, D3D12_TEXTURE_DATA_PITCH_ALIGNMENT as usize)
+            .ok_or(Error::invalid_layout())?;
+        let row_pitch_u32 = u32::try_from(row_pitch).map_err(|_| Error::invalid_layout())?;
+        let row_count = src.len() / row_size;
+        let size = match row_count.checked_sub(1) {
+            Some(rows_before_last) => rows_before_last
+                .checked_mul(row_pitch)
+                .and_then(|size| size.checked_add(row_size))
+                .ok_or(Error::invalid_layout())?,
+            None => 0,
+        };
+
+        let layout = Layout::from_size_align(size, 1)?;
+        let offsets = compute_and_validate_offsets(
+            &*self,
+            start_offset,
+            layout,
+            D3D12_TEXTURE_DATA_PLACEMENT_ALIGNMENT as usize,
+            false,
+        )?;
+
+        let hint = self.copy_hint();
+        for (i, row) in src.chunks_exact(row_size).enumerate() {
+            copy_from_slice_to_offset_with_hint(row, self, offsets.start + i * row_pitch, hint)?;
+        }
+
+        Ok(PitchedCopyRecord {
+            record: offsets.into(),
+            row_pitch: row_pitch_u32,
+        })
+    }
+}
+
+impl<'a> Drop for D3D12MappedSlab<'a> {
+    fn drop(&mut self) {
+        // SAFETY: `self` holds the only mapping of subresource 0 created through it.
+        unsafe { self.resource.Unmap(0, None) };
+    }
+}
+
+// SAFETY: So long as the safety requirements of `D3D12MappedSlab::map` are met, this is also
+// safe since the resource stays mapped until `self` is dropped.
+unsafe impl<'a> Slab for D3D12MappedSlab<'a> {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.base_ptr.as_ptr() as *const u8
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.base_ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.size
+    }
+}
@@ -0,0 +1,176 @@
+use super::*;
+
+/// Offset alignment limits imposed by a graphics device, as reported by the graphics API.
+///
+/// In Vulkan these correspond to the similarly-named members of `VkPhysicalDeviceLimits`. In
+/// D3D12 uniform (constant) buffer offsets must be aligned to 256 bytes.
+///
+/// The [`Default`] value uses 256 bytes for every limit, which is the largest value permitted by
+/// the Vulkan specification for any of them and is therefore always valid, if wasteful.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DeviceAlignments {
+    /// Required alignment, in bytes, of dynamic offsets into uniform buffers.
+    ///
+    /// `minUniformBufferOffsetAlignment` in Vulkan.
+    pub min_uniform_offset: usize,
+
+    /// Required alignment, in bytes, of dynamic offsets into storage buffers.
+    ///
+    /// `minStorageBufferOffsetAlignment` in Vulkan.
+    pub min_storage_offset: usize,
+
+    /// Required alignment, in bytes, of the offsets and sizes of ranges of non-coherent memory
+    /// that are flushed or invalidated.
+    ///
+    /// `nonCoherentAtomSize` in Vulkan.
+    pub non_coherent_atom_size: usize,
+}
+
+impl Default for DeviceAlignments {
+    fn default() -> Self {
+        Self {
+            min_uniform_offset: 256,
+            min_storage_offset: 256,
+            non_coherent_atom_size: 256,
+        }
+    }
+}
+
+/// The kind of buffer binding that a dynamic offset will be used with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DynamicBufferKind {
+    /// A dynamic uniform (constant) buffer binding.
+    Uniform,
+    /// A dynamic storage buffer binding.
+    Storage,
+}
+
+impl DeviceAlignments {
+    /// Get the required offset alignment for bindings of the given `kind`.
+    #[inline]
+    pub fn offset_alignment(&self, kind: DynamicBufferKind) -> usize {
+        match kind {
+            DynamicBufferKind::Uniform => self.min_uniform_offset,
+            DynamicBufferKind::Storage => self.min_storage_offset,
+        }
+        .max(1)
+    }
+
+    /// Round `offset` up to the next offset that is valid as a dynamic offset for bindings of
+    /// the given `kind`.
+    ///
+    /// Returns `None` if the result would overflow.
+    #[inline]
+    pub fn align_offset(&self, offset: usize, kind: DynamicBufferKind) -> Option<usize> {
+        round_up_to_multiple(offset, self.offset_alignment(kind))
+    }
+
+    /// Expands `range` of a mapped range of `size` bytes of non-coherent memory outwards to the
+    /// nearest multiples of [`non_coherent_atom_size`][DeviceAlignments::non_coherent_atom_size],
+    /// clamping its end to `size`, so that it may be flushed or invalidated.
+    ///
+    /// This is what [`MappedSlab::atom_aligned_range`] does, for mapped memory which isn't
+    /// wrapped in a [`MappedSlab`]. The same assumptions about the mapped range apply.
+    ///
+    /// Returns an error if `range` is out of bounds of `size`.
+    #[inline]
+    pub fn atom_aligned_range(
+        &self,
+        range: Range<usize>,
+        size: usize,
+    ) -> Result<Range<usize>, Error> {
+        align_range_to_atoms(range, self.non_coherent_atom_size, size)
+    }
+}
+
+/// Record of the results of a copy operation performed by [`copy_for_dynamic_offset`] or
+/// [`copy_from_slice_for_dynamic_offset`].
+#[derive(Debug, Copy, Clone)]
+pub struct DynamicOffsetRecord {
+    /// The record of the copy itself.
+    pub record: CopyRecord,
+
+    /// The offset to bind the copied data with, i.e. the dynamic offset to pass to
+    /// `vkCmdBindDescriptorSets` or equivalent.
+    ///
+    /// This is the same as `record.start_offset`, converted to the type graphics APIs expect.
+    pub dynamic_offset: u32,
+}
+
+/// Copies `src` into the memory represented by `dst` starting at the first offset at or after
+/// `start_offset` which is valid as a dynamic offset for bindings of the given `kind`, according
+/// to `alignments`.
+///
+/// The offset is aligned *relative to the start of `dst`*, as dynamic offsets are, so `dst`
+/// should represent the whole bound buffer (or at least begin at a suitably aligned offset
+/// within it). If the resulting offset is not also properly aligned in memory for `T`, an error
+/// is returned and no data is copied.
+///
+/// Returns the [`CopyRecord`] for the copy along with the dynamic offset to bind.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_for_dynamic_offset<T: Copy, S: Slab + ?Sized>(
+    src: &T,
+    dst: &mut S,
+    start_offset: usize,
+    alignments: &DeviceAlignments,
+    kind: DynamicBufferKind,
+) -> Result<DynamicOffsetRecord, Error> {
+    let offset = alignments
+        .align_offset(start_offset, kind)
+        .ok_or(Error::invalid_layout())?;
+    let dynamic_offset = u32::try_from(offset).map_err(|_| Error::invalid_layout())?;
+    let record = copy_to_offset_exact(src, dst, offset)?;
+
+    Ok(DynamicOffsetRecord {
+        record,
+        dynamic_offset,
+    })
+}
+
+/// Copies from `src` into the memory represented by `dst` starting at the first offset at or after
+/// `start_offset` which is valid as a dynamic offset for bindings of the given `kind`, according
+/// to `alignments`.
+///
+/// See [`copy_for_dynamic_offset`] for more.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_from_slice_for_dynamic_offset<T: Copy, S: Slab + ?Sized>(
+    src: &[T],
+    dst: &mut S,
+    start_offset: usize,
+    alignments: &DeviceAlignments,
+    kind: DynamicBufferKind,
+) -> Result<DynamicOffsetRecord, Error> {
+    let offset = alignments
+        .align_offset(start_offset, kind)
+        .ok_or(Error::invalid_layout())?;
+    let dynamic_offset = u32::try_from(offset).map_err(|_| Error::invalid_layout())?;
+    let record = copy_from_slice_to_offset_with_align_exact(src, dst, offset, 1)?;
+
+    Ok(DynamicOffsetRecord {
+        record,
+        dynamic_offset,
+    })
+}
+
+/// Rounds `value` up to the next multiple of `multiple`, which must not be 0.
+#[inline(always)]
+pub(crate) fn round_up_to_multiple(value: usize, multiple: usize) -> Option<usize> {
+    let rem = value % multiple;
+    if rem == 0 {
+        Some(value)
+    } else {
+        value.checked_add(multiple - rem)
+    }
+}
@@ -0,0 +1,90 @@
+/// Owns two slabs used for double buffering: the CPU writes into the [current][DoubleBuffered::current]
+/// one while the device reads from the [previous][DoubleBuffered::previous] one, and the two
+/// trade places with [`swap`][DoubleBuffered::swap] once per frame.
+///
+/// ```
+/// # use presser::*;
+/// let mut buffers = DoubleBuffered::new(make_stack_slab::<u32, 4>(), make_stack_slab::<u32, 4>());
+///
+/// for frame in 0..3u32 {
+///     copy_to_offset(&frame, buffers.current_mut(), 0)?;
+///     // ...submit `buffers.current()` to the device...
+///     buffers.swap();
+/// }
+///
+/// // SAFETY: a `u32` was copied to offset 0 of the previous buffer in the last frame.
+/// assert_eq!(unsafe { read_at_offset::<u32, _>(buffers.previous(), 0) }?, &2);
+/// # Ok::<(), presser::Error>(())
+/// ```
+///
+/// Waiting until the device is done reading a slab before writing into it again is up to you.
+#[derive(Debug, Clone)]
+pub struct DoubleBuffered<S> {
+    slabs: [S; 2],
+    current: usize,
+}
+
+impl<S> DoubleBuffered<S> {
+    /// Double buffer `first` and `second`, starting with `first` as the current slab.
+    #[inline]
+    pub fn new(first: S, second: S) -> Self {
+        Self {
+            slabs: [first, second],
+            current: 0,
+        }
+    }
+
+    /// The slab to write into for the current frame.
+    #[inline]
+    pub fn current(&self) -> &S {
+        &self.slabs[self.current]
+    }
+
+    /// The slab to write into for the current frame.
+    #[inline]
+    pub fn current_mut(&mut self) -> &mut S {
+        &mut self.slabs[self.current]
+    }
+
+    /// The slab written into in the previous frame, which the device may still be reading from.
+    #[inline]
+    pub fn previous(&self) -> &S {
+        &self.slabs[self.current ^ 1]
+    }
+
+    /// The slab written into in the previous frame, which the device may still be reading from.
+    #[inline]
+    pub fn previous_mut(&mut self) -> &mut S {
+        &mut self.slabs[self.current ^ 1]
+    }
+
+    /// Both slabs at once, as `(current, previous)`.
+    #[inline]
+    pub fn both_mut(&mut self) -> (&mut S, &mut S) {
+        let [first, second] = &mut self.slabs;
+        if self.current == 0 {
+            (first, second)
+        } else {
+            (second, first)
+        }
+    }
+
+    /// Make the current slab the previous one and vice versa.
+    #[inline]
+    pub fn swap(&mut self) {
+        self.current ^= 1;
+    }
+
+    /// The index of the current slab, i.e. 0 if it's the one passed as `first` to
+    /// [`new`][DoubleBuffered::new] and 1 if it's `second`.
+    #[inline]
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// Unwrap both slabs, in the order they were passed to [`new`][DoubleBuffered::new].
+    #[inline]
+    pub fn into_inner(self) -> [S; 2] {
+        self.slabs
+    }
+}
@@ -0,0 +1,122 @@
+use super::*;
+
+/// The number of bytes shown per line of a [`HexDump`].
+const BYTES_PER_LINE: usize = 16;
+
+/// A view of a range of a slab which formats as a hexdump, in the style of `hexdump -C`, for
+/// inspecting what actually landed in a buffer.
+///
+/// Each line shows the offset of its first byte within the slab, 16 bytes in hex, and those
+/// bytes as ASCII (with `.` for non-printable bytes). Both [`Debug`][core::fmt::Debug] and
+/// [`Display`][core::fmt::Display] produce the dump.
+///
+/// Created with [`hex_dump`].
+#[derive(Clone, Copy)]
+pub struct HexDump<'a> {
+    bytes: &'a [u8],
+    start_offset: usize,
+}
+
+/// Get a [`HexDump`] of the bytes of `slab` within `range`.
+///
+/// The function will return an error if `range` is out of bounds of `slab`.
+///
+/// # Safety
+///
+/// You must have previously **fully-initialized** all bytes of `slab` within `range`.
+#[inline]
+pub unsafe fn hex_dump<S, R>(slab: &S, range: R) -> Result<HexDump<'_>, Error>
+where
+    S: ReadSlab + ?Sized,
+    R: RangeBounds<usize>,
+{
+    let range = validate_range(slab, range)?;
+
+    // SAFETY:
+    // - `range` is within bounds of `slab`, checked by us
+    // - `u8` has no alignment requirements
+    // - if the function-level safety guarantees are met, all bytes within `range` are initialized
+    let bytes = unsafe {
+        core::slice::from_raw_parts(
+            slab.read_base_non_null().as_ptr().add(range.start),
+            range.len(),
+        )
+    };
+
+    Ok(HexDump {
+        bytes,
+        start_offset: range.start,
+    })
+}
+
+/// Format the bytes of `slab` within `range` as a hexdump. See [`HexDump`] for the format.
+///
+/// The function will return an error if `range` is out of bounds of `slab`.
+///
+/// # Safety
+///
+/// You must have previously **fully-initialized** all bytes of `slab` within `range`.
+#[cfg(feature = "std")]
+pub unsafe fn dump_range<S, R>(slab: &S, range: R) -> Result<String, Error>
+where
+    S: ReadSlab + ?Sized,
+    R: RangeBounds<usize>,
+{
+    // SAFETY: same safety requirements
+    let dump = unsafe { hex_dump(slab, range) }?;
+    Ok(dump.to_string())
+}
+
+impl<'a> HexDump<'a> {
+    /// The dumped bytes.
+    #[inline]
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The offset within the slab of the first dumped byte.
+    #[inline]
+    pub fn start_offset(&self) -> usize {
+        self.start_offset
+    }
+}
+
+impl<'a> core::fmt::Display for HexDump<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, line) in self.bytes.chunks(BYTES_PER_LINE).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{:08x} ", self.start_offset + i * BYTES_PER_LINE)?;
+
+            for column in 0..BYTES_PER_LINE {
+                // an extra space in the middle, like `hexdump -C`
+                if column % 8 == 0 {
+                    write!(f, " ")?;
+                }
+                match line.get(column) {
+                    Some(byte) => write!(f, "{:02x} ", byte)?,
+                    None => write!(f, "   ")?,
+                }
+            }
+
+            write!(f, " |")?;
+            for &byte in line {
+                let c = if byte.is_ascii_graphic() || byte == b' ' {
+                    byte as char
+                } else {
+                    '.'
+                };
+                write!(f, "{}", c)?;
+            }
+            write!(f, "|")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> core::fmt::Debug for HexDump<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,228 @@
+use super::*;
+
+/// Byte order to use when writing fixed-size scalar data into a [`Slab`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first, also known as "network byte order".
+    Big,
+    /// The byte order of the target platform. Copies with this endianness never swap bytes.
+    Native,
+}
+
+impl Endianness {
+    /// Returns whether data laid out with the native byte order of the target platform needs to be
+    /// byte-swapped to be laid out with `self`'s byte order.
+    #[inline(always)]
+    pub fn needs_swap(self) -> bool {
+        match self {
+            Self::Little => cfg!(target_endian = "big"),
+            Self::Big => cfg!(target_endian = "little"),
+            Self::Native => false,
+        }
+    }
+}
+
+/// A fixed-size value whose in-memory representation can be byte-swapped.
+///
+/// Implemented for all of the primitive integer and float types, as well as arrays of them. You
+/// may also implement this for your own `#[repr(C)]` types made up of scalars by swapping each
+/// field in turn.
+pub trait EndianScalar: Copy {
+    /// Reverse the byte order of `self`.
+    fn swap_bytes(self) -> Self;
+
+    /// Convert `self` from the native byte order of the target platform to `endianness`.
+    #[inline(always)]
+    fn to_endianness(self, endianness: Endianness) -> Self {
+        if endianness.needs_swap() {
+            self.swap_bytes()
+        } else {
+            self
+        }
+    }
+}
+
+macro_rules! impl_endian_scalar_int {
+    ($($t:ty),*) => {
+        $(
+            impl EndianScalar for $t {
+                #[inline(always)]
+                fn swap_bytes(self) -> Self {
+                    <$t>::swap_bytes(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_endian_scalar_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl EndianScalar for f32 {
+    #[inline(always)]
+    fn swap_bytes(self) -> Self {
+        f32::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+impl EndianScalar for f64 {
+    #[inline(always)]
+    fn swap_bytes(self) -> Self {
+        f64::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+impl<T: EndianScalar, const N: usize> EndianScalar for [T; N] {
+    #[inline(always)]
+    fn swap_bytes(mut self) -> Self {
+        for item in self.iter_mut() {
+            *item = item.swap_bytes();
+        }
+        self
+    }
+}
+
+/// Copies `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst` and with minimum alignment
+/// `min_alignment`, writing it with the byte order given by `endianness`.
+///
+/// - `start_offset` is the offset into the allocation represented by `dst`,
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
+/// - `min_alignment` is the minimum alignment to which the copy will be aligned. The
+///   copy may not actually be aligned to `min_alignment` depending on the alignment requirements
+///   of `T` (the actual alignment will be the greater between `align_of::<T>` and `min_align.next_power_of_two()`).
+/// - `endianness` is the byte order the data will have once written into `dst`.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_to_offset_with_endianness<T: EndianScalar, S: Slab + ?Sized>(
+    src: &T,
+    dst: &mut S,
+    start_offset: usize,
+    min_alignment: usize,
+    endianness: Endianness,
+) -> Result<CopyRecord, Error> {
+    copy_to_offset_with_align(
+        &src.to_endianness(endianness),
+        dst,
+        start_offset,
+        min_alignment,
+    )
+}
+
+/// Copies from `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, writing each element with the byte order
+/// given by `endianness`.
+///
+/// - `start_offset` is the offset into the allocation represented by `dst`,
+///   in bytes, before which any copied data will *certainly not* be placed. However,
+///   the actual beginning of the copied data may not be exactly at `start_offset` if
+///   padding bytes are needed to satisfy alignment requirements. The actual beginning
+///   of the copied bytes is contained in the returned [`CopyRecord`].
+/// - `min_alignment` is the minimum alignment that you are requesting the copy be aligned to. The
+///   copy may be aligned greater than `min_alignment` depending on the alignment requirements
+///   of `T` (the actual alignment will be the greater of the two between `align_of::<T>()` and
+///   `min_align.next_power_of_two()`).
+///     - The whole data of the slice will be copied directly, so alignment between elements
+///       ignores `min_alignment`.
+/// - `endianness` is the byte order each element will have once written into `dst`.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_from_slice_to_offset_with_endianness<T: EndianScalar, S: Slab + ?Sized>(
+    src: &[T],
+    dst: &mut S,
+    start_offset: usize,
+    min_alignment: usize,
+    endianness: Endianness,
+) -> Result<CopyRecord, Error> {
+    if !endianness.needs_swap() {
+        return copy_from_slice_to_offset_with_align(src, dst, start_offset, min_alignment);
+    }
+
+    let t_layout = Layout::for_value(src);
+    let offsets =
+        compute_and_validate_offsets(&*dst, start_offset, t_layout, min_alignment, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
+
+    for (i, item) in src.iter().enumerate() {
+        // SAFETY:
+        // - dst is valid so long as requirements for `slab` were met, i.e.
+        // we have unique access to the region described and that it is valid for the duration
+        // of 'a.
+        // - dst aligned at least to align_of::<T>(), so each element is as well
+        // - checked that the whole slice stays within bounds of our allocation
+        unsafe {
+            dst_ptr.add(i).write(item.swap_bytes());
+        }
+    }
+
+    Ok(offsets.into())
+}
+
+/// Copies `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, writing it in little-endian byte order.
+///
+/// See [`copy_to_offset_with_endianness`] for more.
+#[inline]
+pub fn copy_to_offset_le<T: EndianScalar, S: Slab + ?Sized>(
+    src: &T,
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    copy_to_offset_with_endianness(src, dst, start_offset, 1, Endianness::Little)
+}
+
+/// Copies `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, writing it in big-endian byte order.
+///
+/// See [`copy_to_offset_with_endianness`] for more.
+#[inline]
+pub fn copy_to_offset_be<T: EndianScalar, S: Slab + ?Sized>(
+    src: &T,
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    copy_to_offset_with_endianness(src, dst, start_offset, 1, Endianness::Big)
+}
+
+/// Copies from `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, writing each element in little-endian byte order.
+///
+/// See [`copy_from_slice_to_offset_with_endianness`] for more.
+#[inline]
+pub fn copy_from_slice_to_offset_le<T: EndianScalar, S: Slab + ?Sized>(
+    src: &[T],
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    copy_from_slice_to_offset_with_endianness(src, dst, start_offset, 1, Endianness::Little)
+}
+
+/// Copies from `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, writing each element in big-endian byte order.
+///
+/// See [`copy_from_slice_to_offset_with_endianness`] for more.
+#[inline]
+pub fn copy_from_slice_to_offset_be<T: EndianScalar, S: Slab + ?Sized>(
+    src: &[T],
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    copy_from_slice_to_offset_with_endianness(src, dst, start_offset, 1, Endianness::Big)
+}
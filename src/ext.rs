@@ -0,0 +1,179 @@
+use super::*;
+
+/// Extension methods exposing the crate's copy and read helpers as methods on any [`Slab`], so
+/// that call sites read left-to-right, for example `slab.copy_at(&value, 0)?`.
+///
+/// This is implemented for every [`Slab`], and each method is a thin wrapper around the free
+/// function of the same purpose, which documents it in full.
+pub trait SlabExt: Slab {
+    /// Copies `src` into `self` at a minimum offset of `start_offset` bytes.
+    ///
+    /// See [`copy_to_offset`] for more.
+    #[inline]
+    fn copy_at<T: Copy>(&mut self, src: &T, start_offset: usize) -> Result<CopyRecord, Error> {
+        copy_to_offset(src, self, start_offset)
+    }
+
+    /// Copies `src` into `self` at a minimum offset of `start_offset` bytes, aligned to at least
+    /// `min_alignment`.
+    ///
+    /// See [`copy_to_offset_with_align`] for more.
+    #[inline]
+    fn copy_at_with_align<T: Copy>(
+        &mut self,
+        src: &T,
+        start_offset: usize,
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error> {
+        copy_to_offset_with_align(src, self, start_offset, min_alignment)
+    }
+
+    /// Copies `src` into `self` at *exactly* `start_offset` bytes.
+    ///
+    /// See [`copy_to_offset_exact`] for more.
+    #[inline]
+    fn copy_at_exact<T: Copy>(
+        &mut self,
+        src: &T,
+        start_offset: usize,
+    ) -> Result<CopyRecord, Error> {
+        copy_to_offset_exact(src, self, start_offset)
+    }
+
+    /// Copies from `src` into `self` at a minimum offset of `start_offset` bytes.
+    ///
+    /// See [`copy_from_slice_to_offset`] for more.
+    #[inline]
+    fn copy_slice_at<T: Copy>(
+        &mut self,
+        src: &[T],
+        start_offset: usize,
+    ) -> Result<CopyRecord, Error> {
+        copy_from_slice_to_offset(src, self, start_offset)
+    }
+
+    /// Copies from `src` into `self` at a minimum offset of `start_offset` bytes, aligned to at
+    /// least `min_alignment`.
+    ///
+    /// See [`copy_from_slice_to_offset_with_align`] for more.
+    #[inline]
+    fn copy_slice_at_with_align<T: Copy>(
+        &mut self,
+        src: &[T],
+        start_offset: usize,
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error> {
+        copy_from_slice_to_offset_with_align(src, self, start_offset, min_alignment)
+    }
+
+    /// Copies from `src` into `self` at *exactly* `start_offset` bytes.
+    ///
+    /// See [`copy_from_slice_to_offset_with_align_exact`] for more.
+    #[inline]
+    fn copy_slice_at_exact<T: Copy>(
+        &mut self,
+        src: &[T],
+        start_offset: usize,
+    ) -> Result<CopyRecord, Error> {
+        copy_from_slice_to_offset_with_align_exact(src, self, start_offset, 1)
+    }
+
+    /// Copies the items yielded by `src` into `self` at a minimum offset of `start_offset` bytes,
+    /// laid out as a `[T]` aligned to at least `min_alignment`.
+    ///
+    /// See [`copy_from_exact_iter_to_offset_with_align`] for more.
+    #[inline]
+    fn copy_iter_at<T, I>(
+        &mut self,
+        src: I,
+        start_offset: usize,
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error>
+    where
+        T: Copy,
+        I: ExactSizeIterator<Item = T>,
+    {
+        copy_from_exact_iter_to_offset_with_align(src, self, start_offset, min_alignment)
+    }
+
+    /// Fills `self` with `count` copies of `value` at a minimum offset of `start_offset` bytes,
+    /// aligned to at least `min_alignment`.
+    ///
+    /// See [`fill_at_offset`] for more.
+    #[inline]
+    fn fill_at<T: Copy>(
+        &mut self,
+        value: &T,
+        count: usize,
+        start_offset: usize,
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error> {
+        fill_at_offset(value, count, self, start_offset, min_alignment)
+    }
+
+    /// Fills `range` of `self` with zero bytes.
+    ///
+    /// See [`zero_range`] for more.
+    #[inline]
+    fn zero<R: RangeBounds<usize>>(&mut self, range: R) -> Result<CopyRecord, Error> {
+        zero_range(self, range)
+    }
+
+    /// Gets a shared reference to a `T` within `self` at `offset`.
+    ///
+    /// See [`read_at_offset`] for more.
+    ///
+    /// # Safety
+    ///
+    /// See [`read_at_offset`].
+    #[inline]
+    unsafe fn read_at<T>(&self, offset: usize) -> Result<&T, Error> {
+        // SAFETY: same safety requirements
+        unsafe { read_at_offset(self, offset) }
+    }
+
+    /// Gets a mutable reference to a `T` within `self` at `offset`.
+    ///
+    /// See [`read_at_offset_mut`] for more.
+    ///
+    /// # Safety
+    ///
+    /// See [`read_at_offset_mut`].
+    #[inline]
+    unsafe fn read_at_mut<T>(&mut self, offset: usize) -> Result<&mut T, Error> {
+        // SAFETY: same safety requirements
+        unsafe { read_at_offset_mut(self, offset) }
+    }
+
+    /// Gets a shared reference to a `[T; len]` within `self` at `offset`.
+    ///
+    /// See [`read_slice_at_offset`] for more.
+    ///
+    /// # Safety
+    ///
+    /// See [`read_slice_at_offset`].
+    #[inline]
+    unsafe fn read_slice_at<T>(&self, offset: usize, len: usize) -> Result<&[T], Error> {
+        // SAFETY: same safety requirements
+        unsafe { read_slice_at_offset(self, offset, len) }
+    }
+
+    /// Gets a mutable reference to a `[T; len]` within `self` at `offset`.
+    ///
+    /// See [`read_slice_at_offset_mut`] for more.
+    ///
+    /// # Safety
+    ///
+    /// See [`read_slice_at_offset_mut`].
+    #[inline]
+    unsafe fn read_slice_at_mut<T>(
+        &mut self,
+        offset: usize,
+        len: usize,
+    ) -> Result<&mut [T], Error> {
+        // SAFETY: same safety requirements
+        unsafe { read_slice_at_offset_mut(self, offset, len) }
+    }
+}
+
+impl<S: Slab + ?Sized> SlabExt for S {}
@@ -0,0 +1,142 @@
+use super::*;
+
+/// Where to copy one field of a struct from, and where to place it, as part of a [`FieldTable`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldPlacement {
+    /// The offset, in bytes, of the field within the source data
+    pub src_field_offset: usize,
+    /// The size, in bytes, of the field
+    pub src_size: usize,
+    /// The offset, in bytes, at which the field is placed, relative to the base offset of the copy
+    pub dst_offset: usize,
+}
+
+/// A table of [`FieldPlacement`]s describing how to pack the fields of a struct whose layout is
+/// only known at runtime, such as a material parameter block described by shader reflection.
+///
+/// Copy a struct with it using [`copy_fields_to_offset`]. The sizes of the source data and of
+/// the packed data are computed once when making the table, so they don't need to be recomputed
+/// for every copy.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FieldTable<'a> {
+    fields: &'a [FieldPlacement],
+    src_size: usize,
+    dst_size: usize,
+}
+
+impl<'a> FieldTable<'a> {
+    /// Make a table from the placements of each field.
+    ///
+    /// The function will return an error of kind [`ErrorKind::InvalidLayout`] if the end of any
+    /// field overflows a `usize`.
+    pub fn new(fields: &'a [FieldPlacement]) -> Result<Self, Error> {
+        let mut src_size = 0;
+        let mut dst_size = 0;
+        for field in fields {
+            let src_end = field.src_field_offset.checked_add(field.src_size);
+            let dst_end = field.dst_offset.checked_add(field.src_size);
+            src_size = src_end.ok_or(Error::invalid_layout())?.max(src_size);
+            dst_size = dst_end.ok_or(Error::invalid_layout())?.max(dst_size);
+        }
+        Ok(Self {
+            fields,
+            src_size,
+            dst_size,
+        })
+    }
+
+    /// The placements of each field.
+    #[inline]
+    pub fn fields(&self) -> &'a [FieldPlacement] {
+        self.fields
+    }
+
+    /// The number of bytes the source data needs to contain all fields.
+    #[inline]
+    pub fn src_size(&self) -> usize {
+        self.src_size
+    }
+
+    /// The number of bytes past the base offset of a copy up to the end of the last placed field.
+    #[inline]
+    pub fn dst_size(&self) -> usize {
+        self.dst_size
+    }
+}
+
+/// Copies each field described by `table` from `src` into the memory represented by `dst`, at
+/// its destination offset past *exactly* `base_offset` bytes from the start of `dst`.
+///
+/// ```
+/// # use presser::*;
+/// // a `vec3` and a `float` read from a tightly packed source, placed into a uniform block
+/// // with the `float` in the fourth component of a `vec4`
+/// let fields = [
+///     FieldPlacement { src_field_offset: 0, src_size: 12, dst_offset: 0 },
+///     FieldPlacement { src_field_offset: 12, src_size: 4, dst_offset: 12 },
+/// ];
+/// let table = FieldTable::new(&fields)?;
+///
+/// let mut slab = make_stack_slab::<u8, 64>();
+/// let src = [1.0f32, 2.0, 3.0, 0.5].map(f32::to_ne_bytes).concat();
+/// let record = copy_fields_to_offset(&src, &table, &mut slab, 16)?;
+/// assert_eq!((record.start_offset, record.end_offset), (16, 32));
+/// # Ok::<(), presser::Error>(())
+/// ```
+///
+/// The returned [`CopyRecord`] spans from `base_offset` to the end of the last placed field. No
+/// alignment is applied, so it is up to you to make sure `base_offset` is properly aligned for
+/// the packed struct. Bytes between the placed fields are not written, and if the destinations
+/// of two fields overlap, the one later in `table` is placed last.
+///
+/// The function will return an error if `src` is shorter than
+/// [`table.src_size()`][FieldTable::src_size], or if the packed struct doesn't fit within `dst`
+/// at `base_offset`, in which case no memory is written.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+pub fn copy_fields_to_offset<S: Slab + ?Sized>(
+    src: &[u8],
+    table: &FieldTable<'_>,
+    dst: &mut S,
+    base_offset: usize,
+) -> Result<CopyRecord, Error> {
+    if src.len() < table.src_size {
+        return Err(Error::out_of_memory(0, table.src_size, src.len()));
+    }
+    let end_offset = base_offset
+        .checked_add(table.dst_size)
+        .ok_or(Error::invalid_layout())?;
+    let range = validate_range(&*dst, base_offset..end_offset)?;
+
+    // SAFETY: `range` has been checked to be within the bounds of `dst`
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(range.start) };
+
+    for field in table.fields {
+        // SAFETY:
+        // - the field is within `src`, since its end is at most `table.src_size`, checked by us
+        // - the field is within `range`, since its end is at most `table.dst_size`, and `range`
+        // is within the bounds of `dst`, checked by us
+        // - dst is valid so long as requirements for `slab` were met, i.e.
+        // we have unique access to the region described, so it can't overlap `src`
+        // - `u8` has no alignment requirements
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                src.as_ptr().add(field.src_field_offset),
+                dst_ptr.add(field.dst_offset),
+                field.src_size,
+            );
+        }
+    }
+
+    Ok(CopyRecord {
+        start_offset: range.start,
+        end_offset: range.end,
+        end_offset_padded: range.end,
+    })
+}
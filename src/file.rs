@@ -0,0 +1,98 @@
+use super::*;
+
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io;
+use std::path::Path;
+
+use memmap2::MmapMut;
+
+/// A file mapped into memory, which implements [`Slab`].
+///
+/// This lets you use this crate's validated copy and read helpers to write binary blobs (for
+/// example baked assets) directly into disk-backed memory. Changes are written back to the file
+/// by the operating system at some point, or explicitly with [`FileSlab::flush`].
+///
+/// Requires the `memmap` feature.
+pub struct FileSlab {
+    mmap: MmapMut,
+}
+
+impl FileSlab {
+    /// Map the existing file at `path` into memory, for reading and writing.
+    ///
+    /// The size of the slab is the length of the file.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified (by this or any other process, or by truncating it) while it
+    /// is mapped, other than through the returned slab. See [`MmapMut::map_mut`] for more.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        // SAFETY: function-level safety requirements are the same as `map_mut`'s
+        unsafe { Self::from_file(&file) }
+    }
+
+    /// Create a file at `path` that is `len` bytes long, truncating it if it already exists, and
+    /// map it into memory, for reading and writing.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified (by this or any other process, or by truncating it) while it
+    /// is mapped, other than through the returned slab. See [`MmapMut::map_mut`] for more.
+    pub unsafe fn create<P: AsRef<Path>>(path: P, len: u64) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        file.set_len(len)?;
+        // SAFETY: function-level safety requirements are the same as `map_mut`'s
+        unsafe { Self::from_file(&file) }
+    }
+
+    /// Map the whole of an already-opened `file` into memory, for reading and writing.
+    ///
+    /// `file` must have been opened for both reading and writing.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified (by this or any other process, or by truncating it) while it
+    /// is mapped, other than through the returned slab. See [`MmapMut::map_mut`] for more.
+    pub unsafe fn from_file(file: &File) -> io::Result<Self> {
+        // SAFETY: function-level safety requirements are the same as `map_mut`'s
+        let mmap = unsafe { MmapMut::map_mut(file)? };
+        Ok(Self { mmap })
+    }
+
+    /// Synchronously write all modifications made to the slab back to the file.
+    pub fn flush(&self) -> io::Result<()> {
+        self.mmap.flush()
+    }
+
+    /// Asynchronously start writing all modifications made to the slab back to the file.
+    pub fn flush_async(&self) -> io::Result<()> {
+        self.mmap.flush_async()
+    }
+}
+
+// SAFETY: The mapping is a single valid region of `len()` bytes which stays valid until we are
+// dropped, and if the safety requirements of our constructors are met, nothing else may access it,
+// so our `base_ptr` access is as required.
+unsafe impl Slab for FileSlab {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.mmap.as_ptr()
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.mmap.as_mut_ptr()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.mmap.len()
+    }
+}
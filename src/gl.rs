@@ -0,0 +1,97 @@
+use super::*;
+
+/// A [`Slab`] over a range of an OpenGL buffer mapped with `glMapBufferRange`, typically a
+/// persistent mapping (`GL_MAP_PERSISTENT_BIT`) that lives as long as the buffer itself.
+///
+/// If the mapping isn't coherent (i.e. it was mapped with `GL_MAP_FLUSH_EXPLICIT_BIT` instead of
+/// `GL_MAP_COHERENT_BIT`), writes must be made visible to the GL with `glFlushMappedBufferRange`
+/// before they're used. [`flush_args`][GlMappedSlab::flush_args] computes the arguments for that
+/// call from a [`CopyRecord`].
+pub struct GlMappedSlab {
+    base_ptr: NonNull<u8>,
+    size: usize,
+    coherent: bool,
+}
+
+impl GlMappedSlab {
+    /// Create a new [`GlMappedSlab`] from the pointer returned by `glMapBufferRange` and the
+    /// `length` that was passed to it, along with whether the mapping is coherent.
+    ///
+    /// Returns [`ErrorKind::InvalidLayout`] if `ptr` is null or `length` is negative.
+    ///
+    /// # Safety
+    ///
+    /// For as long as the returned value is alive, `ptr` and `length` must uphold all the
+    /// requirements of the [`Slab`] trait, i.e. the buffer must stay mapped and its mapped memory
+    /// must not be accessed other than through the returned value. In particular, the GL must
+    /// not be reading from the ranges you copy into, which usually requires fencing
+    /// (`glFenceSync`) with persistent mappings.
+    ///
+    /// Also see the [top-level safety documentation][`crate#safety`].
+    pub unsafe fn new(ptr: *mut c_void, length: isize, coherent: bool) -> Result<Self, Error> {
+        let base_ptr = NonNull::new(ptr.cast::<u8>()).ok_or(Error::invalid_layout())?;
+        let size = usize::try_from(length).map_err(|_| Error::invalid_layout())?;
+
+        Ok(Self {
+            base_ptr,
+            size,
+            coherent,
+        })
+    }
+
+    /// Whether the mapping is coherent, in which case nothing ever needs to be flushed.
+    #[inline]
+    pub fn is_coherent(&self) -> bool {
+        self.coherent
+    }
+
+    /// The `offset` and `length` arguments to pass to `glFlushMappedBufferRange` to flush the
+    /// bytes written by the copy described by `record`.
+    ///
+    /// The offset is relative to the start of the mapped range, as `glFlushMappedBufferRange`
+    /// expects. Returns `None` if the mapping is coherent or the copy wrote no bytes, in which
+    /// case nothing needs to be flushed.
+    #[inline]
+    pub fn flush_args(&self, record: &CopyRecord) -> Option<(isize, isize)> {
+        if self.coherent || record.is_empty() {
+            return None;
+        }
+
+        // Records of copies into `self` always lie within `self.size`, which fits in an `isize`.
+        Some((record.start_offset as isize, record.len() as isize))
+    }
+
+    /// Like [`flush_args`][GlMappedSlab::flush_args], but for a single flush covering all the
+    /// copies described by `records`, including any gaps between them.
+    #[inline]
+    pub fn merged_flush_args<'r, I>(&self, records: I) -> Option<(isize, isize)>
+    where
+        I: IntoIterator<Item = &'r CopyRecord>,
+    {
+        let merged = records
+            .into_iter()
+            .filter(|record| !record.is_empty())
+            .copied()
+            .reduce(CopyRecord::merge)?;
+        self.flush_args(&merged)
+    }
+}
+
+// SAFETY: So long as the safety requirements of `GlMappedSlab::new` are met, this is also safe
+// since it's just a basic pass-thru of info.
+unsafe impl Slab for GlMappedSlab {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.base_ptr.as_ptr() as *const u8
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.base_ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.size
+    }
+}
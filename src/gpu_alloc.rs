@@ -0,0 +1,32 @@
+use super::*;
+
+use gpu_allocator::vulkan::Allocation as VulkanAllocation;
+
+/// Borrow the host-mapped memory of a [`gpu-allocator`] Vulkan [`Allocation`] as a [`Slab`].
+///
+/// Returns `None` if `allocation` is not mapped, i.e. if it wasn't allocated from host-visible
+/// memory, or if its size doesn't fit in a `usize`.
+///
+/// This is safe to call because the mapped pointer of an allocation stays valid for
+/// [`size()`][VulkanAllocation::size] bytes until the allocation is freed, which can't happen
+/// while it's borrowed, and `allocation` being borrowed mutably means no other host-side
+/// references to its memory can exist through it. As with any GPU memory, you must still make
+/// sure the device isn't accessing the memory while you copy into it.
+///
+/// Note that `gpu-allocator`'s D3D12 allocations are never mapped by the allocator itself, so
+/// there is no equivalent for them here.
+///
+/// [`gpu-allocator`]: gpu_allocator
+/// [`Allocation`]: VulkanAllocation
+pub fn vulkan_allocation_as_slab(
+    allocation: &mut VulkanAllocation,
+) -> Option<BorrowedRawAllocation<'_>> {
+    let base_ptr = allocation.mapped_ptr()?.cast::<u8>();
+    let size = usize::try_from(allocation.size()).ok()?;
+
+    Some(BorrowedRawAllocation {
+        base_ptr,
+        size,
+        phantom: PhantomData,
+    })
+}
@@ -0,0 +1,106 @@
+use super::*;
+
+/// A slab in its own virtual memory mapping, surrounded by inaccessible guard pages, which
+/// implements [`Slab`] and gets unmapped on [`Drop`].
+///
+/// The usable region is placed so that it ends directly before the trailing guard page, so any
+/// access past the end of the slab faults immediately instead of silently corrupting neighboring
+/// memory. Accesses far enough before the start of the slab to reach the leading guard page fault
+/// as well. This makes it a handy debugging tool when developing new copy code, but since every
+/// slab takes up at least three pages of address space, it's not meant for use in production.
+///
+/// Only supported on Unix-like platforms and Windows. On other platforms, creating one always
+/// fails.
+pub struct GuardedSlab {
+    mapping: NonNull<u8>,
+    mapping_len: usize,
+    base_ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+impl GuardedSlab {
+    /// Make a new guarded slab with the given layout. Begins as uninitialized.
+    ///
+    /// The slab ends exactly at the trailing guard page if `layout.size()` is a multiple of
+    /// `layout.align()`, as it is for any layout made with [`Layout::new`] or [`Layout::array`].
+    ///
+    /// The function will return an error if:
+    /// - the size of the given layout is 0, or its alignment is greater than the page size
+    ///   ([`ErrorKind::InvalidLayout`])
+    /// - the memory could not be mapped ([`ErrorKind::AllocationFailed`])
+    pub fn new(layout: Layout) -> Result<Self, Error> {
+        let page_size = page_size();
+        if layout.size() == 0 || layout.align() > page_size {
+            return Err(Error::invalid_layout());
+        }
+
+        let usable_len =
+            round_up_to_multiple(layout.size(), page_size).ok_or(Error::invalid_layout())?;
+        let mapping_len = usable_len
+            .checked_add(2 * page_size)
+            .ok_or(Error::invalid_layout())?;
+
+        let mapping = reserve(mapping_len).ok_or(Error::allocation_failed())?;
+
+        // SAFETY: the usable pages start one page into the mapping and end one page before its
+        // end, so they're within it, and the mapping is page-aligned.
+        let committed = unsafe {
+            commit(
+                NonNull::new_unchecked(mapping.as_ptr().add(page_size)),
+                usable_len,
+            )
+        };
+        if !committed {
+            // SAFETY: we just reserved this mapping and haven't handed it out.
+            unsafe { release(mapping, mapping_len) };
+            return Err(Error::allocation_failed());
+        }
+
+        // place the slab as late as possible in the usable pages while keeping it aligned.
+        let base_offset = (page_size + usable_len - layout.size()) & !(layout.align() - 1);
+
+        // SAFETY: `base_offset` is at least `page_size`, and the slab ends at or before the end of
+        // the usable pages, so this is within the mapping.
+        let base_ptr = unsafe { NonNull::new_unchecked(mapping.as_ptr().add(base_offset)) };
+
+        Ok(Self {
+            mapping,
+            mapping_len,
+            base_ptr,
+            layout,
+        })
+    }
+}
+
+impl Drop for GuardedSlab {
+    fn drop(&mut self) {
+        // SAFETY: the mapping was reserved in `new`, and unless the user did something unsafely
+        // wrong, this memory won't be used after drop.
+        unsafe { release(self.mapping, self.mapping_len) }
+    }
+}
+
+// SAFETY: a `GuardedSlab` owns its mapping exclusively, just like a `Box<[MaybeUninit<u8>]>`, so it
+// may be sent to and shared with other threads.
+unsafe impl Send for GuardedSlab {}
+// SAFETY: see above
+unsafe impl Sync for GuardedSlab {}
+
+// SAFETY: We point to a single valid committed region of memory of at least `layout.size()` bytes,
+// which stays valid until we are dropped, so our `base_ptr` access is as required.
+unsafe impl Slab for GuardedSlab {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.base_ptr.as_ptr().cast_const()
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.base_ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.layout.size()
+    }
+}
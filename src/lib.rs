@@ -38,7 +38,7 @@
 //! The main idea is to implement [`Slab`] on raw-buffer-esque-types (see [the `Slab` safety docs][Slab#Safety]),
 //! which then enables the use of the other functions within the crate.
 //!
-//! For built-in slab types, see [`RawAllocation`], [`HeapSlab`], and [`make_stack_slab`].
+//! For built-in slab types, see [`RawAllocation`], [`HeapSlab`], [`make_stack_slab`], and [`AlignedStackSlab`].
 //!
 //! Depending on your use case, you may be able to implement [`Slab`] directly for your buffer type, or it may
 //! be more convenient or necessary to create a wrapping struct that borrows your raw buffer type and in turn
@@ -93,7 +93,42 @@
 //! ### `#[no_std]`
 //!
 //! This crate supports `no_std` environments by building without the '`std`' feature. This will limit some
-//! of the fuctions the crate can perform.
+//! of the fuctions the crate can perform. The '`defmt`' feature implements `defmt::Format` for [`Error`] and
+//! [`CopyRecord`], so they can be logged on embedded targets.
+//!
+//! ### C API
+//!
+//! With the '`capi`' feature, the crate exports a small `extern "C"` API (`presser_copy_bytes` and
+//! `presser_plan_offset`) so that C and C++ code sharing the same buffers can route its copies through
+//! the same validation. Build it as a dynamic library with
+//! `cargo rustc --release --features capi --crate-type cdylib`, and use it through `include/presser.h`.
+//!
+//! ### Zero-sized types
+//!
+//! Copies of zero-sized types, empty slices and empty iterators are supported and never touch the
+//! memory of the [`Slab`]. Their start offset is still aligned and validated as usual, which means it
+//! may be equal to, but not past, the size of the slab, and the returned [`CopyRecord`] is empty,
+//! with its start and end offsets being the same. Copies from iterators which return a single record
+//! spanning all of the copied items return `None` for empty iterators. Reading zero-sized types and
+//! empty slices back works the same way.
+//!
+//! The few operations which can't support zero-sized types, like those which divide a number of bytes
+//! by the size of a type, return an error of kind [`ErrorKind::ZeroSizedType`] instead.
+//!
+//! ### Poisoning
+//!
+//! For debugging, the '`poison`' feature fills memory that the crate knows is uninitialized with
+//! [`POISON_BYTE`], so that bytes which were never written stand out in memory views and graphics
+//! debugger captures. This covers new slabs made by [`make_stack_slab`], `boxed_slab` and `HeapSlab`,
+//! and the padding skipped to align the start of a copy. Poisoned bytes must still be treated as
+//! uninitialized.
+//!
+//! ### Minimum Rust version
+//!
+//! The crate itself, with the '`std`' feature or with no features at all, builds on Rust 1.65. Most
+//! of the optional features integrate with other crates, such as `wgpu`, `tokio` or `defmt`, whose
+//! current versions need a newer Rust toolchain; using those features requires whatever toolchain
+//! the integrated crate requires.
 //!
 //! # Safety
 //!
@@ -118,24 +153,146 @@
 #![deny(unsafe_op_in_unsafe_fn)]
 #![deny(missing_docs)]
 // only enables the `doc_auto_cfg` feature when
-// the `docs_build` configuration attribute is defined
+// the `docsrs` configuration attribute is defined
 // this cfg is defined when building on docs.rs (defined thru the project
 // Cargo.toml) and when building the docs for publishing on github pages (thru the
 // .github/workflows/rustdoc-pages.yml workflow)
-#![cfg_attr(docs_build, feature(doc_auto_cfg))]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 use core::alloc::Layout;
 use core::alloc::LayoutError;
 use core::ffi::c_void;
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
+use core::ops::Bound;
+use core::ops::Range;
+use core::ops::RangeBounds;
 use core::ptr::NonNull;
 
+#[cfg(feature = "allocator-api2")]
+use allocator_api2::alloc::Allocator;
+#[cfg(feature = "allocator-api2")]
+use allocator_api2::alloc::Global;
+
+mod aligned_stack;
+#[cfg(feature = "std")]
+mod arena;
+#[cfg(feature = "async")]
+mod async_copy;
+mod brand;
+#[cfg(feature = "std")]
+mod canary;
+#[cfg(feature = "capi")]
+mod capi;
+#[cfg(target_has_atomic = "ptr")]
+mod concurrent;
 mod copy;
+#[cfg(all(feature = "d3d12", windows))]
+mod d3d12;
+mod device;
+mod double_buffered;
+mod dump;
+mod endian;
+mod ext;
+mod fields;
+#[cfg(feature = "memmap")]
+mod file;
+mod gl;
+#[cfg(feature = "gpu-allocator")]
+mod gpu_alloc;
+#[cfg(feature = "os")]
+mod guarded;
+mod mapped;
+#[cfg(all(feature = "metal", target_vendor = "apple"))]
+mod metal_buffer;
+mod options;
+#[cfg(feature = "os")]
+mod os;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(any(feature = "cuda", feature = "hip"))]
+mod pinned;
+mod planner;
 mod read;
+mod scalar;
+mod spare_capacity;
+mod static_slab;
+mod stats;
+mod streaming;
+mod strings;
+#[cfg(feature = "std")]
+mod sync;
+#[cfg(feature = "std")]
+mod transaction;
+mod unalign;
+#[cfg(feature = "os")]
+mod virtual_slab;
+#[cfg(feature = "ash")]
+mod vulkan;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+mod wasm_memory;
+#[cfg(feature = "wgpu")]
+mod wgpu_view;
 
+pub use aligned_stack::*;
+#[cfg(feature = "std")]
+pub use arena::*;
+#[cfg(feature = "async")]
+pub use async_copy::*;
+pub use brand::*;
+#[cfg(feature = "std")]
+pub use canary::*;
+#[cfg(feature = "capi")]
+pub use capi::*;
+#[cfg(target_has_atomic = "ptr")]
+pub use concurrent::*;
 pub use copy::*;
+#[cfg(all(feature = "d3d12", windows))]
+pub use d3d12::*;
+pub use device::*;
+pub use double_buffered::*;
+pub use dump::*;
+pub use endian::*;
+pub use ext::*;
+pub use fields::*;
+#[cfg(feature = "memmap")]
+pub use file::*;
+pub use gl::*;
+#[cfg(feature = "gpu-allocator")]
+pub use gpu_alloc::*;
+#[cfg(feature = "os")]
+pub use guarded::*;
+pub use mapped::*;
+#[cfg(all(feature = "metal", target_vendor = "apple"))]
+pub use metal_buffer::*;
+pub use options::*;
+#[cfg(feature = "os")]
+pub use os::*;
+#[cfg(feature = "rayon")]
+pub use parallel::*;
+#[cfg(any(feature = "cuda", feature = "hip"))]
+pub use pinned::*;
+pub use planner::*;
 pub use read::*;
+pub use scalar::*;
+pub use spare_capacity::*;
+pub use static_slab::*;
+pub use stats::*;
+pub use streaming::*;
+pub use strings::*;
+#[cfg(feature = "std")]
+pub use sync::*;
+#[cfg(feature = "std")]
+pub use transaction::*;
+pub use unalign::*;
+#[cfg(feature = "os")]
+pub use virtual_slab::*;
+#[cfg(feature = "ash")]
+pub use vulkan::*;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub use wasm_memory::*;
+#[cfg(feature = "wgpu")]
+pub use wgpu_view::*;
 
 /// Represents a contiguous piece of a single allocation with some layout that is used as a
 /// data copying destination or reading source. May be wholly or partially uninitialized.
@@ -149,7 +306,7 @@ pub use read::*;
 /// but the idea is that you can also implement this for your own data structure which can
 /// serve as a slab and then use that structure directly with `presser`'s helpers.
 ///
-/// For built-in slabs, see [`RawAllocation`], [`HeapSlab`], and [`make_stack_slab`].
+/// For built-in slabs, see [`RawAllocation`], [`HeapSlab`], [`make_stack_slab`], and [`AlignedStackSlab`].
 ///
 /// # Safety
 ///
@@ -158,7 +315,8 @@ pub use read::*;
 /// - The memory range represented by `base_ptr` and `size` **may** be wholly or partially uninitialized
 /// - `base_ptr` **must** point to a valid, single allocation of at least `size` bytes.
 ///     - Thus, `size` must return a size that, when added to `base_ptr`, stays within
-/// that single valid allocation.
+///       that single valid allocation.
+///     - As an exception, `base_ptr` and `base_ptr_mut` **may** be null if `size` is 0.
 /// - `size` **must not** be greater than `isize::MAX`
 ///
 /// Assume the lifetime of a shared borrow of self is named `'a`:
@@ -166,14 +324,14 @@ pub use read::*;
 /// - `base_ptr` **must** be [valid][`core::ptr#safety`] for `'a`
 /// - `base_ptr` **must *not*** be mutably aliased for `'a`
 ///     - It is necessary but not sufficient for this requirement that
-/// **no outside *mutable* references** may exist to its data, even if they are unused by user code.
+///       **no outside *mutable* references** may exist to its data, even if they are unused by user code.
 ///
 /// Assume the lifetime of a mutable borrow of self is named `'a`:
 ///
 /// - `base_ptr_mut` **must** be [valid][`core::ptr#safety`] for `'a`
 /// - `base_ptr_mut` **must *not*** be aliased at all for `'a`
 ///     - It is necessary but not sufficient for this requirement that
-/// **no outside references** may exist to its data, even if they are unused by user code.
+///       **no outside references** may exist to its data, even if they are unused by user code.
 ///
 /// Also see the [crate-level safety documentation][`crate#safety`].
 pub unsafe trait Slab {
@@ -186,13 +344,42 @@ pub unsafe trait Slab {
     /// Get the size of the allocation represented by `self`.
     fn size(&self) -> usize;
 
+    /// Get a non-null pointer to the beginning of the allocation represented by `self`.
+    ///
+    /// The returned pointer carries the same provenance as [`base_ptr_mut`][Slab::base_ptr_mut].
+    /// If `self` is empty and [`base_ptr_mut`][Slab::base_ptr_mut] returned null, a dangling (but
+    /// well-aligned for `u8`) pointer is returned instead, which is valid for zero-sized accesses.
+    ///
+    /// All of the helpers in this crate access the memory of a slab through this pointer (or
+    /// [`ReadSlab::read_base_non_null`]) rather than through the raw base pointer.
+    #[inline]
+    fn base_non_null(&mut self) -> NonNull<u8> {
+        NonNull::new(self.base_ptr_mut()).unwrap_or_else(NonNull::dangling)
+    }
+
+    /// Get a raw slice pointer covering the whole allocation represented by `self`.
+    ///
+    /// Unlike [`as_maybe_uninit_bytes_mut`][Slab::as_maybe_uninit_bytes_mut], this does not create
+    /// a reference to the memory of `self`, so it does not assert unique access to the whole
+    /// allocation. The copy and read helpers in this crate likewise only work with raw pointers
+    /// derived from the slab's base pointer, and only access the exact bytes they copy to or read
+    /// from, which keeps them well-behaved under Stacked Borrows when other views of the same
+    /// allocation coexist with the slab.
+    #[inline]
+    fn as_raw_slice(&mut self) -> NonNull<[u8]> {
+        let size = self.size();
+        raw_slice_from_parts(self.base_non_null(), size)
+    }
+
     /// Interpret a portion of `self` as a slice of [`MaybeUninit<u8>`]. This is likely not
     /// incredibly useful, you probably want to use [`Slab::as_maybe_uninit_bytes_mut`]
     #[inline(always)]
     fn as_maybe_uninit_bytes(&self) -> &[MaybeUninit<u8>] {
         // SAFETY: Safe so long as top level safety guarantees are held, since
         // `MaybeUninit` has same layout as bare type.
-        unsafe { core::slice::from_raw_parts(self.base_ptr().cast(), self.size()) }
+        unsafe {
+            core::slice::from_raw_parts(self.read_base_non_null().as_ptr().cast(), self.size())
+        }
     }
 
     /// Interpret a portion of `self` as a mutable slice of [`MaybeUninit<u8>`].
@@ -200,7 +387,9 @@ pub unsafe trait Slab {
     fn as_maybe_uninit_bytes_mut(&mut self) -> &mut [MaybeUninit<u8>] {
         // SAFETY: Safe so long as top level safety guarantees are held, since
         // `MaybeUninit` has same layout as bare type.
-        unsafe { core::slice::from_raw_parts_mut(self.base_ptr_mut().cast(), self.size()) }
+        unsafe {
+            core::slice::from_raw_parts_mut(self.base_non_null().as_ptr().cast(), self.size())
+        }
     }
 
     /// Interpret `self` as a byte slice. This assumes that **all bytes**
@@ -219,7 +408,9 @@ pub unsafe trait Slab {
     unsafe fn assume_initialized_as_bytes(&self) -> &[u8] {
         // SAFETY: same requirements as function-level safety assuming the requirements
         // for creating `self` are met
-        unsafe { core::slice::from_raw_parts(self.base_ptr().cast(), self.size()) }
+        unsafe {
+            core::slice::from_raw_parts(self.read_base_non_null().as_ptr().cast(), self.size())
+        }
     }
 
     /// Interpret `self` as a mutable byte slice. This assumes that **all bytes**
@@ -238,7 +429,9 @@ pub unsafe trait Slab {
     unsafe fn assume_initialized_as_bytes_mut(&mut self) -> &mut [u8] {
         // SAFETY: same requirements as function-level safety assuming the requirements
         // for creating `self` are met
-        unsafe { core::slice::from_raw_parts_mut(self.base_ptr_mut().cast(), self.size()) }
+        unsafe {
+            core::slice::from_raw_parts_mut(self.base_non_null().as_ptr().cast(), self.size())
+        }
     }
 
     /// Interpret a range of `self` as a byte slice. This assumes that **all bytes**
@@ -305,6 +498,65 @@ pub unsafe trait Slab {
         }
     }
 
+    /// Fill all of `self` with zero bytes, and then interpret `self` as a mutable byte slice.
+    ///
+    /// This is safe, as every byte in `self` is initialized by this function before the
+    /// returned slice is created. It is the safe way to go from freshly allocated or
+    /// mapped (and therefore uninitialized) memory to memory which is usable as plain bytes.
+    #[inline(always)]
+    fn initialize_all_zeroed(&mut self) -> &mut [u8] {
+        let size = self.size();
+        let ptr = self.base_non_null().as_ptr();
+
+        // SAFETY: Safe so long as top level safety guarantees are held, since `ptr` is then valid
+        // for writes of `size` bytes and `u8` has no alignment requirements.
+        unsafe { core::ptr::write_bytes(ptr, 0, size) };
+
+        // SAFETY: same as above, and all bytes within `self` were just initialized.
+        unsafe { core::slice::from_raw_parts_mut(ptr, size) }
+    }
+
+    /// Returns whether a `T` would fit in `self` if copied with [`copy_to_offset_with_align`] with the
+    /// given `start_offset` and `min_alignment`, i.e. whether such a copy would succeed.
+    #[inline]
+    fn has_room_for<T>(&self, start_offset: usize, min_alignment: usize) -> bool {
+        compute_and_validate_offsets(self, start_offset, Layout::new::<T>(), min_alignment, false)
+            .is_ok()
+    }
+
+    /// Returns whether a `[T; count]` would fit in `self` if copied with
+    /// [`copy_from_slice_to_offset_with_align`] with the given `start_offset` and `min_alignment`,
+    /// i.e. whether such a copy would succeed.
+    #[inline]
+    fn has_room_for_array<T>(
+        &self,
+        count: usize,
+        start_offset: usize,
+        min_alignment: usize,
+    ) -> bool {
+        match Layout::array::<T>(count) {
+            Ok(layout) => {
+                compute_and_validate_offsets(self, start_offset, layout, min_alignment, false)
+                    .is_ok()
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the number of bytes remaining in `self` after `start_offset` has been aligned up to
+    /// `min_alignment.next_power_of_two()`, or 0 if the aligned offset is out of bounds.
+    #[inline]
+    fn remaining_after(&self, start_offset: usize, min_alignment: usize) -> usize {
+        align_offset_up_to(
+            addr(self.read_base_non_null().as_ptr()),
+            start_offset,
+            min_alignment.next_power_of_two(),
+        )
+        .map_or(0, |aligned_offset| {
+            self.size().saturating_sub(aligned_offset)
+        })
+    }
+
     /// View a portion of `self` as a [`c_void`] pointer and size, appropriate for sending to an FFI function
     /// to have it read the contents of `self`. If you want the buffer to be filled with data
     /// from the other side of the ffi and then read it back, use
@@ -335,7 +587,7 @@ pub unsafe trait Slab {
     /// View a portion of `self` as a [`c_void`] pointer and size, appropriate for sending to an FFI function
     /// to be filled and then read using one or more of the `read_` helper functions.
     ///
-    /// You may want to use [`readback_from_ffi`] or [`readback_sice_from_ffi`] instead, which are
+    /// You may want to use [`readback_from_ffi`] or [`readback_slice_from_ffi`] instead, which are
     /// even less prone to misuse.
     ///
     /// # Panics
@@ -358,6 +610,105 @@ pub unsafe trait Slab {
             maybe_uninit_slice.len(),
         )
     }
+
+    /// Like [`as_ffi_buffer`][Slab::as_ffi_buffer], but returns an error instead of panicking if
+    /// `range` is out of bounds of `self`, for example when it was computed from sizes reported
+    /// by the other side of the FFI.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe in and of itself, but you must be careful not to use `self` for
+    /// anything else while the returned pointer is in use by whatever you're sending it to, and
+    /// be sure that you're upholding any alignment requirements needed.
+    #[inline]
+    fn try_as_ffi_buffer<R>(&self, range: R) -> Result<(*const c_void, usize), Error>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = validate_range(self, range)?;
+        // SAFETY: `range` was checked to be within bounds of `self`.
+        let ptr = unsafe { self.read_base_non_null().as_ptr().add(range.start) };
+        Ok((ptr.cast(), range.len()))
+    }
+
+    /// Like [`as_ffi_readback_buffer`][Slab::as_ffi_readback_buffer], but returns an error
+    /// instead of panicking if `range` is out of bounds of `self`, for example when it was
+    /// computed from sizes reported by the other side of the FFI.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe in and of itself, but you must be careful not to use `self` for
+    /// anything else while the returned pointer is in use by whatever you're sending it to,
+    /// and be sure that you're upholding any alignment requirements needed.
+    #[inline]
+    fn try_as_ffi_readback_buffer<R>(&mut self, range: R) -> Result<(*mut c_void, usize), Error>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = validate_range(&*self, range)?;
+        // SAFETY: `range` was checked to be within bounds of `self`.
+        let ptr = unsafe { self.base_non_null().as_ptr().add(range.start) };
+        Ok((ptr.cast(), range.len()))
+    }
+}
+
+/// Represents a contiguous piece of a single allocation which may be used as a reading source
+/// through a shared borrow. May be wholly or partially uninitialized.
+///
+/// This is the read-only counterpart of [`Slab`], which it is implemented for automatically. It
+/// is only needed by the read helpers which take the slab by shared reference, such as
+/// [`read_at_offset`] and [`read_slice_at_offset`]. Implement it directly for types which only
+/// provide read access to their memory, like [`SharedRawAllocation`].
+///
+/// The methods of this trait are named differently from those of [`Slab`] so that calls to the
+/// latter aren't ambiguous when both traits are in scope.
+///
+/// # Safety
+///
+/// Implementors of this trait must ensure these guarantees:
+///
+/// - The memory range represented by `read_base_ptr` and `read_size` **may** be wholly or partially uninitialized
+/// - `read_base_ptr` **must** point to a valid, single allocation of at least `read_size` bytes.
+///     - As an exception, `read_base_ptr` **may** be null if `read_size` is 0.
+/// - `read_size` **must not** be greater than `isize::MAX`
+///
+/// Assume the lifetime of a shared borrow of self is named `'a`:
+///
+/// - `read_base_ptr` **must** be [valid][`core::ptr#safety`] for reads for `'a`
+/// - `read_base_ptr` **must *not*** be mutably aliased for `'a`
+///
+/// Also see the [crate-level safety documentation][`crate#safety`].
+pub unsafe trait ReadSlab {
+    /// Get a pointer to the beginning of the allocation represented by `self`.
+    fn read_base_ptr(&self) -> *const u8;
+
+    /// Get the size of the allocation represented by `self`.
+    fn read_size(&self) -> usize;
+
+    /// Get a non-null pointer to the beginning of the allocation represented by `self`.
+    ///
+    /// The returned pointer carries the same provenance as [`read_base_ptr`][ReadSlab::read_base_ptr].
+    /// If `self` is empty and [`read_base_ptr`][ReadSlab::read_base_ptr] returned null, a dangling
+    /// (but well-aligned for `u8`) pointer is returned instead, which is valid for zero-sized reads.
+    ///
+    /// The returned pointer must only be used for reads.
+    #[inline]
+    fn read_base_non_null(&self) -> NonNull<u8> {
+        NonNull::new(self.read_base_ptr().cast_mut()).unwrap_or_else(NonNull::dangling)
+    }
+}
+
+// SAFETY: The guarantees of `Slab` for shared borrows are a superset of those of `ReadSlab`.
+unsafe impl<S: Slab + ?Sized> ReadSlab for S {
+    #[inline(always)]
+    fn read_base_ptr(&self) -> *const u8 {
+        self.base_ptr()
+    }
+
+    #[inline(always)]
+    fn read_size(&self) -> usize {
+        self.size()
+    }
 }
 
 // SAFETY: The captured `[MaybeUninit<T>]` will all be part of the same allocation object, and borrowck
@@ -377,9 +728,29 @@ unsafe impl<T> Slab for [MaybeUninit<T>] {
     }
 }
 
-/// An error that may occur during a copy or read operation.
-#[derive(Debug)]
-pub enum Error {
+// SAFETY: The captured `[MaybeUninit<T>; N]` is a single object, and borrowck will ensure that the
+// borrows that occur on `self` on the relevant methods live long enough since they are native borrows
+// anyway.
+unsafe impl<T, const N: usize> Slab for [MaybeUninit<T>; N] {
+    fn base_ptr(&self) -> *const u8 {
+        self.as_ptr().cast()
+    }
+
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.as_mut_ptr().cast()
+    }
+
+    fn size(&self) -> usize {
+        core::mem::size_of_val(self)
+    }
+}
+
+/// The kind of an [`Error`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ErrorKind {
     /// Copy or read would exceed the end of the allocation
     OutOfMemory,
     /// Requested to copy to or read from an offset outside the bounds of the allocation
@@ -392,25 +763,187 @@ pub enum Error {
     /// In an `exact` variant copy function, the computed copy start offset did not match the requested start offset,
     /// meaning the requested start offset was not properly aligned.
     RequestedOffsetUnaligned,
+    /// The operation doesn't support zero-sized types, usually because it needs to divide a number
+    /// of bytes by the size of the type. See [Zero-sized types][crate#zero-sized-types] for which
+    /// operations do.
+    ZeroSizedType,
+    /// The requested alignment was not a power of two, in a `strict` variant copy function which
+    /// doesn't round it up to one.
+    InvalidAlignment,
+    /// A slab which allocates its own memory, such as `HeapSlab`, failed to allocate or commit it.
+    AllocationFailed,
 }
 
-impl core::fmt::Display for Error {
+/// The offsets and sizes involved in an operation which went out of bounds of its allocation,
+/// available through [`Error::bounds`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundsDetails {
+    /// The offset at which the operation was requested to start
+    pub requested_offset: usize,
+    /// The size the allocation would need to have for the operation to succeed
+    pub required_bytes: usize,
+    /// The actual size of the allocation
+    pub slab_size: usize,
+}
+
+/// An error that may occur during a copy or read operation.
+///
+/// Use [`kind`][Error::kind] to find out what went wrong. Errors of kind
+/// [`ErrorKind::OutOfMemory`] and [`ErrorKind::OffsetOutOfBounds`] usually also carry the
+/// [`BoundsDetails`] of the failed operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Error {
+    kind: ErrorKind,
+    bounds: Option<BoundsDetails>,
+}
+
+impl Error {
+    /// Create a new error of the given `kind`, without [`BoundsDetails`].
+    #[inline]
+    pub const fn new(kind: ErrorKind) -> Self {
+        Self { kind, bounds: None }
+    }
+
+    /// The kind of this error.
+    #[inline]
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The offsets and sizes involved in the failed operation, if this error is of kind
+    /// [`ErrorKind::OutOfMemory`] or [`ErrorKind::OffsetOutOfBounds`] and they're known.
+    #[inline]
+    pub const fn bounds(&self) -> Option<&BoundsDetails> {
+        self.bounds.as_ref()
+    }
+
+    /// An error of kind [`ErrorKind::OutOfMemory`] for an operation which would need
+    /// `required_bytes` bytes when starting at `requested_offset`, in an allocation of
+    /// `slab_size` bytes.
+    #[inline]
+    pub(crate) const fn out_of_memory(
+        requested_offset: usize,
+        required_bytes: usize,
+        slab_size: usize,
+    ) -> Self {
+        Self {
+            kind: ErrorKind::OutOfMemory,
+            bounds: Some(BoundsDetails {
+                requested_offset,
+                required_bytes,
+                slab_size,
+            }),
+        }
+    }
+
+    /// An error of kind [`ErrorKind::OffsetOutOfBounds`] for an operation which would need
+    /// `required_bytes` bytes when starting at `requested_offset`, in an allocation of
+    /// `slab_size` bytes.
+    #[inline]
+    pub(crate) const fn offset_out_of_bounds(
+        requested_offset: usize,
+        required_bytes: usize,
+        slab_size: usize,
+    ) -> Self {
+        Self {
+            kind: ErrorKind::OffsetOutOfBounds,
+            bounds: Some(BoundsDetails {
+                requested_offset,
+                required_bytes,
+                slab_size,
+            }),
+        }
+    }
+
+    /// An error of kind [`ErrorKind::AllocationFailed`].
+    // only the allocating slabs use this, which mostly need `std`.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    #[inline]
+    pub(crate) const fn allocation_failed() -> Self {
+        Self::new(ErrorKind::AllocationFailed)
+    }
+
+    /// An error of kind [`ErrorKind::InvalidLayout`].
+    #[inline]
+    pub(crate) const fn invalid_layout() -> Self {
+        Self::new(ErrorKind::InvalidLayout)
+    }
+
+    /// An error of kind [`ErrorKind::RequestedOffsetUnaligned`].
+    #[inline]
+    pub(crate) const fn requested_offset_unaligned() -> Self {
+        Self::new(ErrorKind::RequestedOffsetUnaligned)
+    }
+
+    /// An error of kind [`ErrorKind::ZeroSizedType`].
+    #[inline]
+    pub(crate) const fn zero_sized_type() -> Self {
+        Self::new(ErrorKind::ZeroSizedType)
+    }
+
+    /// An error of kind [`ErrorKind::InvalidAlignment`].
+    #[inline]
+    pub(crate) const fn invalid_alignment() -> Self {
+        Self::new(ErrorKind::InvalidAlignment)
+    }
+}
+
+impl From<ErrorKind> for Error {
+    #[inline]
+    fn from(kind: ErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl core::fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", match self {
             Self::OutOfMemory => "End of copy or read operation would exceed the end of the allocation",
             Self::OffsetOutOfBounds => "Requested read from or copy to a location starting outside the allocation",
             Self::InvalidLayout => "Computed invalid layout requirements, probably caused by incredibly large size, offset, or alignment parameters",
             Self::RequestedOffsetUnaligned => "Requested offset into Slab did not satisfy computed alignment requirements",
+            Self::ZeroSizedType => "Requested operation is not supported for zero-sized types",
+            Self::InvalidAlignment => "Requested alignment is not a power of two",
+            Self::AllocationFailed => "Failed to allocate the memory of a slab",
         })
     }
 }
 
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if let Some(bounds) = &self.bounds {
+            write!(
+                f,
+                " (requested offset {}, requires {} bytes, allocation has {})",
+                bounds.requested_offset, bounds.required_bytes, bounds.slab_size,
+            )?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
+/// Converts into an IO error of kind [`InvalidInput`][std::io::ErrorKind::InvalidInput] which
+/// wraps the original [`Error`], so presser calls compose with `?` in functions returning
+/// [`std::io::Result`].
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, err)
+    }
+}
+
 impl From<LayoutError> for Error {
     fn from(_err: LayoutError) -> Self {
-        Self::InvalidLayout
+        Self::invalid_layout()
     }
 }
 
@@ -554,6 +1087,109 @@ impl RawAllocation {
         Self { base_ptr, size }
     }
 
+    /// Create a new [`RawAllocation`] from a raw slice pointer.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe in and of itself, as nothing will be done
+    /// with the pointer upon creation.
+    #[inline]
+    pub fn from_raw_slice(slice: NonNull<[u8]>) -> Self {
+        Self {
+            base_ptr: slice.cast(),
+            size: slice.len(),
+        }
+    }
+
+    /// Get a raw slice pointer covering the memory range represented by `self`.
+    #[inline]
+    pub fn as_raw_slice(&self) -> NonNull<[u8]> {
+        raw_slice_from_parts(self.base_ptr, self.size)
+    }
+
+    /// Asserts that the memory range represented by `self` is not mutated for the duration of the
+    /// borrow, giving us a [`SharedRawAllocation`] which implements [`ReadSlab`].
+    ///
+    /// # Safety
+    ///
+    /// Using this method makes some strong guarantees about the contained `base_ptr` and `size`
+    /// for the duration of the borrow. See the [safety][`ReadSlab#safety`] documentation for the
+    /// [`ReadSlab`] trait for a list of the guarantees you must make to use this method.
+    ///
+    /// Also see the [top-level safety documentation][`crate#safety`]
+    #[allow(clippy::needless_lifetimes)] // Important to be explicit in this case because of unsafety
+    pub unsafe fn borrow_as_read_slab<'a>(&'a self) -> SharedRawAllocation<'a> {
+        SharedRawAllocation {
+            base_ptr: self.base_ptr,
+            size: self.size,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Create a new [`RawAllocation`] from a raw `c_void` pointer and size, as returned by graphics
+    /// APIs when mapping memory (for example `vkMapMemory` or `ID3D12Resource::Map`).
+    ///
+    /// The function will return [`ErrorKind::InvalidLayout`] if `base_ptr` is null or `size` is
+    /// greater than `isize::MAX`.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe in and of itself, as nothing will be done
+    /// with the pointer and size upon creation.
+    pub fn from_c_parts(base_ptr: *mut c_void, size: usize) -> Result<Self, Error> {
+        if size > isize::MAX as usize {
+            return Err(Error::invalid_layout());
+        }
+        let base_ptr = NonNull::new(base_ptr.cast::<u8>()).ok_or(Error::invalid_layout())?;
+        Ok(Self { base_ptr, size })
+    }
+
+    /// Split `self` into two non-overlapping allocations: the first `mid` bytes, and the rest.
+    ///
+    /// This can be used to partition one allocation, for example a single mapped GPU heap, between
+    /// independent users which each borrow their own half as a slab. The safety requirements of
+    /// [`borrow_as_slab`][RawAllocation::borrow_as_slab] then apply to each half separately, and
+    /// since the halves don't overlap, borrowing both at once doesn't violate them.
+    ///
+    /// The function will return [`ErrorKind::OffsetOutOfBounds`] if `mid` is greater than the size of
+    /// `self`.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe in and of itself, as nothing will be done
+    /// with the pointer and size upon creation.
+    pub fn split_at(self, mid: usize) -> Result<(Self, Self), Error> {
+        if mid > self.size {
+            return Err(Error::offset_out_of_bounds(mid, mid, self.size));
+        }
+        // if `self` represents a real allocation, this is in bounds of it, but we can't rely on that.
+        let second_ptr =
+            NonNull::new(self.base_ptr.as_ptr().wrapping_add(mid)).ok_or(Error::invalid_layout())?;
+        Ok((
+            Self {
+                base_ptr: self.base_ptr,
+                size: mid,
+            },
+            Self {
+                base_ptr: second_ptr,
+                size: self.size - mid,
+            },
+        ))
+    }
+
+    /// Borrow `slice` as a [`BorrowedRawAllocation`], which implements [`Slab`].
+    ///
+    /// Unlike [`borrow_as_slab`][RawAllocation::borrow_as_slab], this is entirely safe, since the
+    /// unique borrow of `slice` already upholds all the guarantees [`Slab`] requires.
+    #[inline]
+    pub fn from_uninit_slice(slice: &mut [MaybeUninit<u8>]) -> BorrowedRawAllocation<'_> {
+        BorrowedRawAllocation {
+            size: slice.len(),
+            base_ptr: NonNull::from(slice).cast(),
+            phantom: PhantomData,
+        }
+    }
+
     /// Asserts that we are uniquely borrowing the memory range represented by `self` for
     /// the duration of the borrow, giving us a [`BorrowedRawAllocation`] which implements [`Slab`].
     ///
@@ -574,10 +1210,40 @@ impl RawAllocation {
     }
 }
 
+/// Represents a shared borrow of a contiguous piece of a single allocation with some layout, which
+/// may only be read from. May be wholly or partially uninitialized.
+///
+/// This implements [`ReadSlab`] but not [`Slab`], so it can be used with the read helpers that only
+/// need shared access, such as [`read_at_offset`] and [`read_slice_at_offset`], from several places
+/// at once.
+///
+/// This type can only be obtained through the [`borrow_as_read_slab`][`RawAllocation::borrow_as_read_slab`]
+/// method on [`RawAllocation`].
+#[derive(Clone, Copy)]
+pub struct SharedRawAllocation<'a> {
+    base_ptr: NonNull<u8>,
+    size: usize,
+    phantom: PhantomData<&'a ()>,
+}
+
+// SAFETY: So long as the safety requirements of `borrow_as_read_slab` are met, this is also safe
+// since it's just a basic pass-thru of info.
+unsafe impl<'a> ReadSlab for SharedRawAllocation<'a> {
+    fn read_base_ptr(&self) -> *const u8 {
+        self.base_ptr.as_ptr().cast_const()
+    }
+
+    fn read_size(&self) -> usize {
+        self.size
+    }
+}
+
 /// Represents the unique borrow of a contiguous piece of a single allocation with some layout that is used as a
 /// data copying destination. May be wholly or partially uninitialized.
 ///
-/// This type can only be obtained through the [`borrow_as_slab`][`RawAllocation::borrow_as_slab`] method on [`RawAllocation`].
+/// This type can only be obtained through the [`borrow_as_slab`][`RawAllocation::borrow_as_slab`] and
+/// [`from_uninit_slice`][`RawAllocation::from_uninit_slice`] methods on [`RawAllocation`], or through
+/// one of the graphics API adaptors behind this crate's optional features.
 pub struct BorrowedRawAllocation<'a> {
     base_ptr: NonNull<u8>,
     size: usize,
@@ -611,37 +1277,117 @@ pub(crate) struct ComputedOffsets {
 
 /// Compute and validate offsets for a copy or read operation with the given parameters.
 #[inline(always)]
-pub(crate) fn compute_and_validate_offsets<S: Slab + ?Sized>(
+pub(crate) fn compute_and_validate_offsets<S: ReadSlab + ?Sized>(
     slab: &S,
     start_offset: usize,
     t_layout: Layout,
     min_alignment: usize,
     require_exact_start_offset: bool,
+) -> Result<ComputedOffsets, Error> {
+    compute_and_validate_offsets_for_raw_parts(
+        addr(slab.read_base_non_null().as_ptr()),
+        slab.read_size(),
+        start_offset,
+        t_layout,
+        min_alignment,
+        require_exact_start_offset,
+    )
+}
+
+/// Fill the padding skipped to align a copy, i.e. the bytes from `start_offset` up to
+/// `offsets.start`, with [`POISON_BYTE`] if the '`poison`' feature is enabled. Does nothing
+/// otherwise.
+///
+/// `offsets` must have been computed for `dst` with the same `start_offset`.
+#[inline(always)]
+pub(crate) fn poison_alignment_gap<S: Slab + ?Sized>(
+    dst: &mut S,
+    start_offset: usize,
+    offsets: &ComputedOffsets,
+) {
+    #[cfg(feature = "poison")]
+    if offsets.start > start_offset && offsets.end > offsets.start {
+        // SAFETY: `start_offset <= offsets.start`, and `offsets.start` was checked to be within
+        // the bounds of `dst` when `offsets` was computed.
+        unsafe {
+            core::ptr::write_bytes(
+                dst.base_non_null().as_ptr().add(start_offset),
+                POISON_BYTE,
+                offsets.start - start_offset,
+            );
+        }
+    }
+    #[cfg(not(feature = "poison"))]
+    let _ = (dst, start_offset, offsets);
+}
+
+/// Create a raw slice pointer from a non-null base pointer and length.
+///
+/// This is `NonNull::slice_from_raw_parts`, which is not available on our MSRV.
+#[inline(always)]
+pub(crate) fn raw_slice_from_parts(base_ptr: NonNull<u8>, len: usize) -> NonNull<[u8]> {
+    let slice = core::ptr::slice_from_raw_parts_mut(base_ptr.as_ptr(), len);
+    // SAFETY: `slice` has the same (non-null) address as `base_ptr`.
+    unsafe { NonNull::new_unchecked(slice) }
+}
+
+/// Get the address of `ptr`, without exposing its provenance.
+///
+/// This is equivalent to `ptr.addr()` under the strict provenance APIs, which are not available
+/// on our MSRV. Unlike an `as` cast, which exposes the provenance of the pointer, transmuting it to
+/// an integer just drops the provenance, which is how `ptr.addr()` is specified too. The result is
+/// only ever used for alignment and bounds math; all pointers that are actually dereferenced are
+/// derived from a slab's base pointer with `add`, so they keep its provenance.
+#[inline(always)]
+#[allow(clippy::transmutes_expressible_as_ptr_casts)] // the cast would expose the provenance
+pub(crate) fn addr<T>(ptr: *const T) -> usize {
+    // SAFETY: a thin pointer has the same size as a `usize`, and every bit pattern is a valid
+    // `usize`.
+    unsafe { core::mem::transmute::<*const (), usize>(ptr.cast::<()>()) }
+}
+
+/// Like [`compute_and_validate_offsets`], but for a slab described only by the address of its
+/// base pointer and its size.
+#[inline(always)]
+pub(crate) fn compute_and_validate_offsets_for_raw_parts(
+    base_addr: usize,
+    slab_size: usize,
+    start_offset: usize,
+    t_layout: Layout,
+    min_alignment: usize,
+    require_exact_start_offset: bool,
 ) -> Result<ComputedOffsets, Error> {
     let layout = t_layout.align_to(min_alignment.next_power_of_two())?;
 
-    let computed_start_offset =
-        align_offset_up_to(slab.base_ptr() as usize, start_offset, layout.align())
-            .ok_or(Error::InvalidLayout)?;
+    let computed_start_offset = align_offset_up_to(base_addr, start_offset, layout.align())
+        .ok_or(Error::invalid_layout())?;
     if require_exact_start_offset && start_offset != computed_start_offset {
-        return Err(Error::RequestedOffsetUnaligned);
+        return Err(Error::requested_offset_unaligned());
     }
     let computed_end_offset = computed_start_offset
         .checked_add(layout.size())
-        .ok_or(Error::InvalidLayout)?;
+        .ok_or(Error::invalid_layout())?;
     let computed_end_offset_padded = computed_start_offset
         .checked_add(layout.pad_to_align().size())
-        .ok_or(Error::InvalidLayout)?;
+        .ok_or(Error::invalid_layout())?;
 
     // check start is inside slab
     // if within slab, we also know that copy_start_offset is <= isize::MAX since slab.size() must be <= isize::MAX
-    if computed_start_offset > slab.size() {
-        return Err(Error::OffsetOutOfBounds);
+    if computed_start_offset > slab_size {
+        return Err(Error::offset_out_of_bounds(
+            start_offset,
+            computed_end_offset_padded,
+            slab_size,
+        ));
     }
 
     // check end is inside slab
-    if computed_end_offset_padded > slab.size() {
-        return Err(Error::OutOfMemory);
+    if computed_end_offset_padded > slab_size {
+        return Err(Error::out_of_memory(
+            start_offset,
+            computed_end_offset_padded,
+            slab_size,
+        ));
     }
 
     Ok(ComputedOffsets {
@@ -651,29 +1397,215 @@ pub(crate) fn compute_and_validate_offsets<S: Slab + ?Sized>(
     })
 }
 
-/// Given pointer and offset, returns a new offset aligned to `align`.
-///
-/// `align` *must* be a power of two and >= 1 or else the result is meaningless.
+/// Check that `min_alignment` is a power of two, for the copies which don't round it up to one.
 #[inline(always)]
-fn align_offset_up_to(ptr: usize, offset: usize, align: usize) -> Option<usize> {
-    let offsetted_ptr = ptr.checked_add(offset)?;
-    let aligned_ptr = offsetted_ptr.checked_add(align - 1)? & !(align - 1);
-    // don't need to check since we know aligned_ptr is >= ptr at this point
-    Some(aligned_ptr - ptr)
+pub(crate) fn validate_alignment(min_alignment: usize) -> Result<(), Error> {
+    if min_alignment.is_power_of_two() {
+        Ok(())
+    } else {
+        Err(Error::invalid_alignment())
+    }
 }
 
-/// Make a `[MaybeUninit<T>; N]` on the stack, which implements [`Slab`] and can therefore be used
-/// with many of the helpers provided by this crate.
-pub fn make_stack_slab<T, const N: usize>() -> [MaybeUninit<T>; N] {
-    // SAFETY: An uninitialized `[MaybeUninit<_>; N]` is valid.
-    unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() }
-}
+/// Resolve and validate a byte range within `slab`, returning it as a concrete `Range`.
+#[inline(always)]
+pub(crate) fn validate_range<S: ReadSlab + ?Sized, R: RangeBounds<usize>>(
+    slab: &S,
+    range: R,
+) -> Result<Range<usize>, Error> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).ok_or(Error::invalid_layout())?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).ok_or(Error::invalid_layout())?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => slab.read_size(),
+    };
+
+    if start > slab.read_size() || start > end {
+        return Err(Error::offset_out_of_bounds(
+            start,
+            start.max(end),
+            slab.read_size(),
+        ));
+    }
+
+    if end > slab.read_size() {
+        return Err(Error::out_of_memory(start, end, slab.read_size()));
+    }
+
+    Ok(start..end)
+}
+
+/// Given pointer and offset, returns a new offset aligned to `align`.
+///
+/// `align` *must* be a power of two and >= 1 or else the result is meaningless.
+#[inline(always)]
+fn align_offset_up_to(ptr: usize, offset: usize, align: usize) -> Option<usize> {
+    let offsetted_ptr = ptr.checked_add(offset)?;
+    let aligned_ptr = offsetted_ptr.checked_add(align - 1)? & !(align - 1);
+    // don't need to check since we know aligned_ptr is >= ptr at this point
+    Some(aligned_ptr - ptr)
+}
+
+/// The byte that uninitialized memory is filled with when the '`poison`' feature is enabled.
+///
+/// See the [crate-level documentation][`crate#poisoning`] for more.
+pub const POISON_BYTE: u8 = 0xCD;
+
+/// Make a `[MaybeUninit<T>; N]` on the stack, which implements [`Slab`] and can therefore be used
+/// with many of the helpers provided by this crate.
+pub fn make_stack_slab<T, const N: usize>() -> [MaybeUninit<T>; N] {
+    #[cfg_attr(not(feature = "poison"), allow(unused_mut))]
+    // SAFETY: An uninitialized `[MaybeUninit<_>; N]` is valid.
+    let mut slab = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
+    #[cfg(feature = "poison")]
+    // SAFETY: `slab` is valid for writes of its own size, and any bytes are valid for `MaybeUninit`.
+    unsafe {
+        core::ptr::write_bytes(
+            slab.as_mut_ptr().cast::<u8>(),
+            POISON_BYTE,
+            core::mem::size_of_val(&slab),
+        );
+    }
+    slab
+}
+
+/// Make a `Box<[MaybeUninit<u8>]>` of `len` bytes on the heap, which implements [`Slab`] and can
+/// therefore be used with many of the helpers provided by this crate.
+///
+/// This is a simpler alternative to [`HeapSlab`] for when you don't need control over the
+/// alignment of the allocation. Once you've fully initialized it, you may turn it into a
+/// `Box<[u8]>` with [`boxed_slab_assume_init`].
+#[cfg(feature = "std")]
+pub fn boxed_slab(len: usize) -> Box<[MaybeUninit<u8>]> {
+    #[cfg(not(feature = "poison"))]
+    let byte = MaybeUninit::uninit();
+    #[cfg(feature = "poison")]
+    let byte = MaybeUninit::new(POISON_BYTE);
+    core::iter::repeat(byte).take(len).collect()
+}
+
+/// Turn a slab made by [`boxed_slab`] into a `Box<[u8]>`.
+///
+/// # Safety
+///
+/// Every byte of `slab` must have been initialized.
+#[cfg(feature = "std")]
+pub unsafe fn boxed_slab_assume_init(slab: Box<[MaybeUninit<u8>]>) -> Box<[u8]> {
+    // SAFETY: `MaybeUninit<u8>` has the same layout as `u8`, so the allocation is valid for a
+    // `[u8]` of the same length, and if the function-level safety requirements are met, it's
+    // fully initialized.
+    unsafe { Box::from_raw(Box::into_raw(slab) as *mut [u8]) }
+}
+
+/// Turn a `Box<[u8]>` into a slab, for example to reuse its allocation with [`boxed_slab`]-style
+/// helpers.
+#[cfg(feature = "std")]
+pub fn boxed_slab_from_bytes(bytes: Box<[u8]>) -> Box<[MaybeUninit<u8>]> {
+    // SAFETY: `MaybeUninit<u8>` has the same layout as `u8` and is valid for any bytes.
+    unsafe { Box::from_raw(Box::into_raw(bytes) as *mut [MaybeUninit<u8>]) }
+}
+
+// SAFETY: We forward to the boxed slice, which is a single allocation that lives as long as we do,
+// and borrowck will ensure that the borrows that occur on `self` on the relevant methods live long
+// enough since they are native borrows anyway.
+#[cfg(feature = "std")]
+unsafe impl<T> Slab for Box<[MaybeUninit<T>]> {
+    fn base_ptr(&self) -> *const u8 {
+        self.as_ptr().cast()
+    }
+
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.as_mut_ptr().cast()
+    }
+
+    fn size(&self) -> usize {
+        core::mem::size_of_val(&**self)
+    }
+}
 
 /// A raw allocation on the heap which implements [`Slab`] and gets deallocated on [`Drop`].
+///
+/// By default the memory is allocated from the global allocator. With the '`allocator-api2`'
+/// feature, any other allocator implementing the [`allocator-api2`] crate's `Allocator` trait may
+/// be used instead through the `_in` constructors, for example `HeapSlab::new_in`.
+///
+/// [`allocator-api2`]: https://docs.rs/allocator-api2
 #[cfg(feature = "std")]
-pub struct HeapSlab {
+pub struct HeapSlab<#[cfg(feature = "allocator-api2")] A: Allocator = Global> {
     base_ptr: NonNull<u8>,
     layout: Layout,
+    /// Whether the whole allocation is known to be initialized, i.e. it was allocated zeroed and
+    /// no mutable pointer to it has been handed out through the [`Slab`] interface since.
+    known_initialized: bool,
+    #[cfg(feature = "allocator-api2")]
+    alloc: A,
+    #[cfg(not(feature = "allocator-api2"))]
+    alloc: Global,
+}
+
+/// The global allocator, with the part of the `allocator_api2` `Allocator` interface used by
+/// [`HeapSlab`], for when the '`allocator-api2`' feature is disabled.
+#[cfg(all(feature = "std", not(feature = "allocator-api2")))]
+#[derive(Clone, Copy)]
+struct Global;
+
+#[cfg(all(feature = "std", not(feature = "allocator-api2")))]
+impl Global {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, ()> {
+        if layout.size() == 0 {
+            return Err(());
+        }
+        // SAFETY: we just checked that the size of `layout` isn't 0.
+        NonNull::new(unsafe { std::alloc::alloc(layout) }).ok_or(())
+    }
+
+    fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<u8>, ()> {
+        if layout.size() == 0 {
+            return Err(());
+        }
+        // SAFETY: we just checked that the size of `layout` isn't 0.
+        NonNull::new(unsafe { std::alloc::alloc_zeroed(layout) }).ok_or(())
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by `self` with `old_layout`, and `new_layout` must be at least
+    /// as large as `old_layout`.
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, ()> {
+        if new_layout.align() == old_layout.align() {
+            // SAFETY: `ptr` was allocated with `old_layout`, and `new_layout` is a valid layout
+            // with the same alignment and a size of at least `old_layout.size()`, which isn't 0.
+            let new_ptr =
+                unsafe { std::alloc::realloc(ptr.as_ptr(), old_layout, new_layout.size()) };
+            return NonNull::new(new_ptr).ok_or(());
+        }
+
+        let new_ptr = self.allocate(new_layout)?;
+        // SAFETY: the new allocation is at least as large as the old one, and both are distinct
+        // allocations which are valid for `old_layout.size()` bytes.
+        unsafe {
+            core::ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), old_layout.size());
+            self.deallocate(ptr, old_layout);
+        }
+        Ok(new_ptr)
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by `self` with `layout`.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        // SAFETY: same safety requirements
+        unsafe { std::alloc::dealloc(ptr.as_ptr(), layout) }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -682,56 +1614,457 @@ impl HeapSlab {
     ///
     /// # Panics
     ///
-    /// Panics if the size of the given layout is 0.
+    /// Panics if the size of the given layout is 0. If the allocation fails,
+    /// [`std::alloc::handle_alloc_error`] is called. See [`HeapSlab::try_new`] for a fallible version.
     pub fn new(layout: Layout) -> Self {
-        if layout.size() == 0 {
-            panic!("cannot make a heap slab of size 0")
+        Self::unwrap_alloc(Self::try_new(layout), layout)
+    }
+
+    /// Make a new slab space on the heap, returning an error if it could not be allocated. Begins as
+    /// uninitialized. The memory will be be deallocated on drop.
+    ///
+    /// The function will return an error if:
+    /// - the size of the given layout is 0 ([`ErrorKind::InvalidLayout`])
+    /// - the allocator failed to allocate the memory ([`ErrorKind::AllocationFailed`])
+    pub fn try_new(layout: Layout) -> Result<Self, Error> {
+        Self::try_alloc_in(layout, Global, false)
+    }
+
+    /// Make a new slab space on the heap with the layout of a `[T; count]`, returning an error if
+    /// it could not be allocated. Begins as uninitialized. The memory will be be deallocated on drop.
+    ///
+    /// The function will return an error if:
+    /// - the size of a `[T; count]` overflows ([`ErrorKind::InvalidLayout`])
+    /// - see [`HeapSlab::try_new`] for the rest
+    pub fn for_array<T>(count: usize) -> Result<Self, Error> {
+        Self::try_new(Layout::array::<T>(count)?)
+    }
+
+    /// Make a new slab space on the heap, with every byte initialized to 0. The memory will be be
+    /// deallocated on drop.
+    ///
+    /// Since the memory starts out initialized, it can be viewed as bytes without `unsafe` through
+    /// [`HeapSlab::initialized_bytes_mut`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the given layout is 0. If the allocation fails,
+    /// [`std::alloc::handle_alloc_error`] is called. See [`HeapSlab::try_new_zeroed`] for a fallible version.
+    pub fn new_zeroed(layout: Layout) -> Self {
+        Self::unwrap_alloc(Self::try_new_zeroed(layout), layout)
+    }
+
+    /// Make a new slab space on the heap, with every byte initialized to 0, returning an error if
+    /// it could not be allocated. The memory will be be deallocated on drop.
+    ///
+    /// See [`HeapSlab::try_new`] for the errors this may return.
+    pub fn try_new_zeroed(layout: Layout) -> Result<Self, Error> {
+        Self::try_alloc_in(layout, Global, true)
+    }
+
+    /// Decompose the slab into a pointer to its memory and the layout it was allocated with,
+    /// without deallocating it.
+    ///
+    /// The memory may be turned back into a [`HeapSlab`] with [`HeapSlab::from_raw`], for example
+    /// once ownership of it has been handed back across an FFI or plugin boundary. Otherwise,
+    /// it is leaked.
+    pub fn into_raw(self) -> (NonNull<u8>, Layout) {
+        let this = core::mem::ManuallyDrop::new(self);
+        (this.base_ptr, this.layout)
+    }
+
+    /// Reconstruct a slab from a pointer and layout previously returned by [`HeapSlab::into_raw`].
+    ///
+    /// The slab's memory is treated as uninitialized, whatever was written into it before.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` and `layout` must have been returned by a call to [`HeapSlab::into_raw`], and no
+    /// other `HeapSlab` may have been reconstructed from them since.
+    pub unsafe fn from_raw(ptr: NonNull<u8>, layout: Layout) -> Self {
+        Self {
+            base_ptr: ptr,
+            layout,
+            known_initialized: false,
+            alloc: Global,
         }
-        // SAFETY: we just checked size is not 0, and we got the ptr back from alloc so we no it's
-        // not null.
-        let base_ptr = unsafe { NonNull::new_unchecked(std::alloc::alloc(layout)) };
-        Self { base_ptr, layout }
     }
 }
 
-#[cfg(feature = "std")]
-impl Drop for HeapSlab {
-    fn drop(&mut self) {
-        // SAFETY: we know that size isn't 0 since we checked that in new, and unless the user
-        // did something unsafely wrong, this memory won't be used after drop.
-        unsafe { std::alloc::dealloc(self.base_ptr.as_ptr(), self.layout) }
+#[cfg(feature = "allocator-api2")]
+impl<A: Allocator> HeapSlab<A> {
+    /// Make a new slab space allocated from `alloc`. Begins as uninitialized. The memory will be be
+    /// deallocated on drop.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the given layout is 0. If the allocation fails,
+    /// [`std::alloc::handle_alloc_error`] is called. See [`HeapSlab::try_new_in`] for a fallible version.
+    pub fn new_in(layout: Layout, alloc: A) -> Self {
+        Self::unwrap_alloc(Self::try_new_in(layout, alloc), layout)
     }
-}
 
-// SAFETY: We point to a single valid allocation, and the size is valid since it's a valid `Layout`.
-// Our allocation is valid until we are dropped, so our `base_ptr` access is as required
-#[cfg(feature = "std")]
-unsafe impl Slab for HeapSlab {
-    #[inline(always)]
-    fn base_ptr(&self) -> *const u8 {
-        self.base_ptr.as_ptr().cast_const()
+    /// Make a new slab space allocated from `alloc`, returning an error if it could not be
+    /// allocated. Begins as uninitialized. The memory will be be deallocated on drop.
+    ///
+    /// See [`HeapSlab::try_new`] for the errors this may return.
+    pub fn try_new_in(layout: Layout, alloc: A) -> Result<Self, Error> {
+        Self::try_alloc_in(layout, alloc, false)
     }
 
-    #[inline(always)]
-    fn base_ptr_mut(&mut self) -> *mut u8 {
-        self.base_ptr.as_ptr()
+    /// Make a new slab space allocated from `alloc` with the layout of a `[T; count]`, returning an
+    /// error if it could not be allocated. Begins as uninitialized. The memory will be be
+    /// deallocated on drop.
+    ///
+    /// See [`HeapSlab::for_array`] for the errors this may return.
+    pub fn for_array_in<T>(count: usize, alloc: A) -> Result<Self, Error> {
+        Self::try_new_in(Layout::array::<T>(count)?, alloc)
     }
 
-    #[inline(always)]
-    fn size(&self) -> usize {
-        self.layout.size()
+    /// Make a new slab space allocated from `alloc`, with every byte initialized to 0. The memory
+    /// will be be deallocated on drop.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the given layout is 0. If the allocation fails,
+    /// [`std::alloc::handle_alloc_error`] is called. See [`HeapSlab::try_new_zeroed_in`] for a fallible version.
+    pub fn new_zeroed_in(layout: Layout, alloc: A) -> Self {
+        Self::unwrap_alloc(Self::try_new_zeroed_in(layout, alloc), layout)
+    }
+
+    /// Make a new slab space allocated from `alloc`, with every byte initialized to 0, returning an
+    /// error if it could not be allocated. The memory will be be deallocated on drop.
+    ///
+    /// See [`HeapSlab::try_new`] for the errors this may return.
+    pub fn try_new_zeroed_in(layout: Layout, alloc: A) -> Result<Self, Error> {
+        Self::try_alloc_in(layout, alloc, true)
     }
+
+    /// Decompose the slab into a pointer to its memory, the layout it was allocated with, and the
+    /// allocator it was allocated from, without deallocating it.
+    ///
+    /// See [`HeapSlab::into_raw`] for more.
+    pub fn into_raw_with_allocator(self) -> (NonNull<u8>, Layout, A) {
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped or used again, so the allocator is not duplicated.
+        let alloc = unsafe { core::ptr::read(&this.alloc) };
+        (this.base_ptr, this.layout, alloc)
+    }
+
+    /// Reconstruct a slab from a pointer, layout and allocator previously returned by
+    /// [`HeapSlab::into_raw_with_allocator`].
+    ///
+    /// The slab's memory is treated as uninitialized, whatever was written into it before.
+    ///
+    /// # Safety
+    ///
+    /// `ptr`, `layout` and `alloc` must have been returned by a call to
+    /// [`HeapSlab::into_raw_with_allocator`] (or `alloc` must be able to deallocate memory allocated
+    /// by the allocator that was), and no other `HeapSlab` may have been reconstructed from them
+    /// since.
+    pub unsafe fn from_raw_in(ptr: NonNull<u8>, layout: Layout, alloc: A) -> Self {
+        Self {
+            base_ptr: ptr,
+            layout,
+            known_initialized: false,
+            alloc,
+        }
+    }
+
+    /// Get a reference to the allocator the slab was allocated from.
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+}
+
+/// Implements the methods and traits of [`HeapSlab`] which don't depend on whether it is generic
+/// over its allocator, i.e. whether the '`allocator-api2`' feature is enabled. `$alloc` is the
+/// type of the allocator.
+#[cfg(feature = "std")]
+macro_rules! impl_heap_slab {
+    ([$($generics:tt)*] $self_ty:ty, $alloc:ty) => {
+        impl<$($generics)*> $self_ty {
+            fn try_alloc_in(layout: Layout, alloc: $alloc, zeroed: bool) -> Result<Self, Error> {
+                if layout.size() == 0 {
+                    return Err(Error::invalid_layout());
+                }
+                let ptr = if zeroed {
+                    alloc.allocate_zeroed(layout)
+                } else {
+                    alloc.allocate(layout)
+                }
+                .map_err(|_| Error::allocation_failed())?;
+                #[cfg(feature = "poison")]
+                if !zeroed {
+                    // SAFETY: `ptr` was just allocated with `layout`, so it's valid for writes of
+                    // its size.
+                    unsafe {
+                        core::ptr::write_bytes(
+                            ptr.cast::<u8>().as_ptr(),
+                            POISON_BYTE,
+                            layout.size(),
+                        )
+                    };
+                }
+                Ok(Self {
+                    base_ptr: ptr.cast(),
+                    layout,
+                    known_initialized: zeroed,
+                    alloc,
+                })
+            }
+
+            fn unwrap_alloc(result: Result<Self, Error>, layout: Layout) -> Self {
+                match result {
+                    Ok(slab) => slab,
+                    Err(err) if err.kind() == ErrorKind::InvalidLayout => {
+                        panic!("cannot make a heap slab of size 0")
+                    }
+                    Err(_) => std::alloc::handle_alloc_error(layout),
+                }
+            }
+
+            /// Grow the slab to `new_layout`, preserving its existing contents.
+            ///
+            /// The existing bytes keep their offsets, so any [`CopyRecord`] describing data
+            /// previously copied into the slab remains valid. However, the slab may be moved to a
+            /// different address, so any pointers into it are invalidated. The newly added bytes
+            /// begin uninitialized.
+            ///
+            /// The allocator may be able to grow the allocation in place. Otherwise, a new
+            /// allocation is made and the contents are copied into it.
+            ///
+            /// The function will return an error if:
+            /// - `new_layout` is smaller than the current layout ([`ErrorKind::InvalidLayout`])
+            /// - the allocator failed to allocate the memory ([`ErrorKind::AllocationFailed`])
+            ///
+            /// If an error is returned, the slab is left unchanged.
+            pub fn grow(&mut self, new_layout: Layout) -> Result<(), Error> {
+                if new_layout.size() < self.layout.size() {
+                    return Err(Error::invalid_layout());
+                }
+
+                // SAFETY:
+                // - `base_ptr` was allocated by `alloc` with `layout`
+                // - we just checked that `new_layout` is at least as large as `layout`
+                let new_ptr = unsafe { self.alloc.grow(self.base_ptr, self.layout, new_layout) }
+                    .map_err(|_| Error::allocation_failed())?;
+
+                self.base_ptr = new_ptr.cast();
+                self.layout = new_layout;
+                self.known_initialized = false;
+                Ok(())
+            }
+
+            /// View the whole slab as initialized bytes, if it is known to be fully initialized.
+            ///
+            /// This is the case for slabs made with [`HeapSlab::new_zeroed`] until a mutable
+            /// pointer to the memory is taken through the [`Slab`] interface (for example by
+            /// copying data into it with one of this crate's copy functions), since that may have
+            /// written uninitialized padding bytes. Writing through the returned slice keeps the
+            /// slab initialized.
+            #[inline]
+            pub fn initialized_bytes_mut(&mut self) -> Option<&mut [u8]> {
+                if !self.known_initialized {
+                    return None;
+                }
+                // SAFETY: we own the allocation of `layout.size()` bytes, all of which are
+                // initialized, and have unique access to it since we have `&mut self`.
+                Some(unsafe {
+                    core::slice::from_raw_parts_mut(self.base_ptr.as_ptr(), self.layout.size())
+                })
+            }
+        }
+
+        impl<$($generics)*> Drop for $self_ty {
+            fn drop(&mut self) {
+                // SAFETY: we know that size isn't 0 since we checked that in new, the memory was
+                // allocated by `alloc` with `layout`, and unless the user did something unsafely
+                // wrong, this memory won't be used after drop.
+                unsafe { self.alloc.deallocate(self.base_ptr, self.layout) }
+            }
+        }
+
+        // SAFETY: We point to a single valid allocation, and the size is valid since it's a valid
+        // `Layout`. Our allocation is valid until we are dropped, so our `base_ptr` access is as
+        // required
+        unsafe impl<$($generics)*> Slab for $self_ty {
+            #[inline(always)]
+            fn base_ptr(&self) -> *const u8 {
+                self.base_ptr.as_ptr().cast_const()
+            }
+
+            #[inline(always)]
+            fn base_ptr_mut(&mut self) -> *mut u8 {
+                self.known_initialized = false;
+                self.base_ptr.as_ptr()
+            }
+
+            #[inline(always)]
+            fn size(&self) -> usize {
+                self.layout.size()
+            }
+        }
+    };
 }
 
+#[cfg(feature = "allocator-api2")]
+impl_heap_slab!([A: Allocator] HeapSlab<A>, A);
+#[cfg(all(feature = "std", not(feature = "allocator-api2")))]
+impl_heap_slab!([] HeapSlab, Global);
+
+// SAFETY: a `HeapSlab` owns its allocation exclusively, just like a `Box<[MaybeUninit<u8>]>`,
+// so it may be sent to another thread if its allocator may be.
+#[cfg(feature = "allocator-api2")]
+unsafe impl<A: Allocator + Send> Send for HeapSlab<A> {}
+// SAFETY: the memory is only accessed through `&self` by reading, see above.
+#[cfg(feature = "allocator-api2")]
+unsafe impl<A: Allocator + Sync> Sync for HeapSlab<A> {}
+
+// SAFETY: a `HeapSlab` owns its allocation exclusively, just like a `Box<[MaybeUninit<u8>]>`.
+#[cfg(all(feature = "std", not(feature = "allocator-api2")))]
+unsafe impl Send for HeapSlab {}
+// SAFETY: the memory is only accessed through `&self` by reading, see above.
+#[cfg(all(feature = "std", not(feature = "allocator-api2")))]
+unsafe impl Sync for HeapSlab {}
+
 #[cfg(test)]
 mod test {
     use core::ffi::c_void;
     use core::ptr::NonNull;
 
+    #[cfg(feature = "std")]
+    use crate::broadcast_to_offsets;
+    use crate::copy_between_slabs;
+    use crate::copy_between_slabs_with_align;
+    use crate::copy_bytes_to_offset_with_layout;
+    use crate::copy_fields_to_offset;
+    use crate::copy_for_dynamic_offset;
+    use crate::copy_from_exact_iter_to_offset;
+    use crate::copy_from_iter_to_offset_with_align_packed;
+    use crate::copy_from_iter_to_offset_with_align_with_callback;
+    use crate::copy_from_slice_for_dynamic_offset;
     use crate::copy_from_slice_to_offset;
+    use crate::copy_from_slice_to_offset_be;
+    use crate::copy_from_slice_to_offset_streaming;
+    use crate::copy_from_slice_to_offset_unaligned;
+    use crate::copy_from_slice_to_offset_with_align;
+    use crate::copy_from_slice_to_offset_with_align_strict;
+    use crate::copy_from_slice_to_offset_with_hint;
+    #[cfg(feature = "std")]
+    use crate::copy_from_slices_to_offset;
+    #[cfg(feature = "std")]
+    use crate::copy_from_slices_to_offset_with_align;
+    use crate::copy_header_and_slice_to_offset;
+    use crate::copy_iter_records;
+    use crate::copy_to_offset;
+    use crate::copy_to_offset_exact;
+    use crate::copy_to_offset_le;
+    use crate::copy_to_offset_streaming;
+    use crate::copy_to_offset_unaligned;
+    use crate::copy_to_offset_with_align;
+    use crate::copy_to_offset_with_align_exact;
+    use crate::copy_to_offset_with_align_strict;
+    #[cfg(all(feature = "std", feature = "crc32"))]
+    use crate::crc32_range;
+    use crate::diff_ranges;
+    #[cfg(feature = "std")]
+    use crate::dump_range;
+    use crate::fill_at_offset;
+    use crate::gather_read;
+    #[cfg(feature = "std")]
+    use crate::hash_range;
+    #[cfg(feature = "std")]
+    use crate::hex_dump;
     use crate::make_stack_slab;
+    use crate::move_range;
+    use crate::plan_copy;
+    use crate::plan_copy_exact;
+    use crate::plan_copy_for_layout;
+    use crate::plan_copy_for_raw_parts;
+    #[cfg(feature = "capi")]
+    use crate::presser_copy_bytes;
+    #[cfg(feature = "capi")]
+    use crate::presser_plan_offset;
+    use crate::read_at_offset;
+    use crate::read_cstr_at_offset;
+    use crate::read_owned_at_offset;
+    use crate::read_slice_at_offset;
+    use crate::read_unaligned_at_offset;
+    use crate::readback_from_ffi_mut;
+    use crate::readback_header_and_slice_from_ffi;
+    use crate::readback_length_prefixed_from_ffi;
+    use crate::readback_sized_from_ffi;
     use crate::readback_slice_from_ffi;
+    use crate::readback_slice_from_ffi_max_elements;
+    use crate::readback_slice_from_ffi_out_count;
+    use crate::readback_terminated_slice_from_ffi;
+    use crate::repeat_to_offset;
+    use crate::required_size_for_iter;
+    use crate::required_size_for_slice;
+    use crate::scatter_copy;
+    use crate::try_readback_from_ffi;
+    use crate::try_readback_slice_from_ffi;
+    use crate::verify_at_offset;
+    use crate::verify_slice_at_offset;
+    use crate::write_cstr_at_offset;
+    use crate::write_f32_be_at;
+    use crate::write_scalar_at;
+    use crate::write_str_nul_terminated_at_offset;
+    use crate::write_u16_at;
+    use crate::write_u16_le_at;
+    use crate::write_u32_be_at;
+    use crate::write_u64_be_at;
+    use crate::write_u8_be_at;
+    use crate::write_utf16_at_offset;
+    use crate::zero_range;
+    use crate::AlignedStackSlab;
+    #[cfg(feature = "arrayvec")]
+    use crate::ArrayVecSlab;
+    #[cfg(feature = "bytes")]
+    use crate::BytesMutSlab;
+    #[cfg(target_has_atomic = "ptr")]
+    use crate::ConcurrentWriter;
+    use crate::CopyHint;
+    use crate::CopyOptions;
+    use crate::CopyRecord;
+    #[cfg(feature = "std")]
+    use crate::CopyTransaction;
+    use crate::DeviceAlignments;
+    use crate::DynamicBufferKind;
+    use crate::Endianness;
+    use crate::Error;
+    use crate::ErrorKind;
+    use crate::FieldPlacement;
+    use crate::FieldTable;
+    #[cfg(feature = "memmap")]
+    use crate::FileSlab;
+    use crate::GlMappedSlab;
+    #[cfg(feature = "os")]
+    use crate::GuardedSlab;
+    #[cfg(feature = "std")]
+    use crate::HeapSlab;
+    use crate::LayoutPlanner;
+    #[cfg(feature = "std")]
+    use crate::MappedSlab;
+    #[cfg(feature = "capi")]
+    use crate::PresserCopyRecord;
+    #[cfg(feature = "capi")]
+    use crate::PresserStatus;
     use crate::RawAllocation;
+    use crate::Slab;
+    use crate::SlabExt;
+    #[cfg(feature = "smallvec")]
+    use crate::SmallVecSlab;
+    #[cfg(feature = "std")]
+    use crate::StreamingArena;
+    #[cfg(feature = "std")]
+    use crate::VecSlab;
+    #[cfg(feature = "os")]
+    use crate::VirtualSlab;
+    #[cfg(feature = "poison")]
+    use crate::POISON_BYTE;
 
     #[test]
     fn readback_ffi() {
@@ -779,4 +2112,2284 @@ mod test {
             HITS_TO_WRITE.len()
         }
     }
+
+    #[test]
+    fn endianness_copies() {
+        let mut slab = make_stack_slab::<u32, 4>();
+
+        let record = copy_to_offset_le(&0x0102_0304u32, slab.as_mut_slice(), 0).unwrap();
+        assert_eq!(record.start_offset, 0);
+
+        let record =
+            copy_from_slice_to_offset_be(&[0x0506u16, 0x0708], slab.as_mut_slice(), 4).unwrap();
+        assert_eq!(record.end_offset, 8);
+
+        let bytes = unsafe { slab.as_mut_slice().assume_range_initialized_as_bytes(..8) };
+        assert_eq!(bytes, &[4, 3, 2, 1, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn move_overlapping_range() {
+        let mut slab = make_stack_slab::<u8, 8>();
+        copy_from_slice_to_offset(&[1u8, 2, 3, 4, 5, 6, 7, 8], slab.as_mut_slice(), 0).unwrap();
+
+        let record = move_range(slab.as_mut_slice(), 0..6, 2).unwrap();
+        assert_eq!((record.start_offset, record.end_offset), (2, 8));
+
+        let bytes = unsafe { slab.as_mut_slice().assume_initialized_as_bytes() };
+        assert_eq!(bytes, &[1, 2, 1, 2, 3, 4, 5, 6]);
+
+        assert!(move_range(slab.as_mut_slice(), 4..8, 6).is_err());
+    }
+
+    #[test]
+    fn repeat_with_stride() {
+        let mut slab = make_stack_slab::<u16, 8>();
+        zero_range(slab.as_mut_slice(), ..).unwrap();
+
+        let record = repeat_to_offset(&[7u16, 9], 3, Some(6), slab.as_mut_slice(), 0, 1).unwrap();
+        assert_eq!((record.start_offset, record.end_offset), (0, 16));
+
+        let words = unsafe { read_slice_at_offset::<u16, _>(slab.as_mut_slice(), 0, 8) }.unwrap();
+        assert_eq!(words, &[7, 9, 0, 7, 9, 0, 7, 9]);
+
+        assert!(repeat_to_offset(&[7u16, 9], 2, Some(3), slab.as_mut_slice(), 0, 1).is_err());
+    }
+
+    #[test]
+    fn plan_suballocations() {
+        let mut slab = make_stack_slab::<u64, 16>();
+        let mut planner = LayoutPlanner::new(slab.as_mut_slice());
+
+        let vertices = planner.reserve_array::<[f32; 3]>(3, 1).unwrap();
+        let uniforms = planner.reserve_for::<u32>(8).unwrap();
+        assert_eq!((vertices.start_offset, vertices.end_offset), (0, 36));
+        assert_eq!(uniforms.start_offset, 40);
+        assert_eq!(planner.used(), 44);
+
+        assert!(planner.reserve_array::<u8>(128, 1).is_err());
+        assert_eq!(planner.used(), 44);
+
+        let record =
+            copy_to_offset_exact(&5u32, slab.as_mut_slice(), uniforms.start_offset).unwrap();
+        assert_eq!(record.start_offset, uniforms.start_offset);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn transaction_is_all_or_nothing() {
+        let mut slab = make_stack_slab::<u32, 4>();
+        slab.as_mut_slice().initialize_all_zeroed();
+
+        let mut transaction = CopyTransaction::new(slab.as_mut_slice(), 0);
+        transaction.stage(&1u32, 1).unwrap();
+        assert!(transaction.stage_slice(&[2u32; 4], 1).is_err());
+        assert!(transaction.commit().is_err());
+        assert_eq!(
+            unsafe { slab.as_mut_slice().assume_initialized_as_bytes() },
+            &[0; 16]
+        );
+
+        let mut transaction = CopyTransaction::new(slab.as_mut_slice(), 0);
+        transaction.stage(&1u32, 1).unwrap();
+        transaction.stage_slice(&[2u32; 3], 1).unwrap();
+        assert_eq!(transaction.commit(), Ok(16));
+        let words = unsafe { read_slice_at_offset::<u32, _>(slab.as_mut_slice(), 0, 4) }.unwrap();
+        assert_eq!(words, &[1, 2, 2, 2]);
+    }
+
+    #[test]
+    fn scatter_values() {
+        let mut slab = make_stack_slab::<u32, 16>();
+        let slab = slab.as_mut_slice();
+
+        let values = [7u32, 11, 13];
+        let pairs = [(4, &values[0]), (32, &values[1]), (60, &values[2])];
+        scatter_copy(pairs.iter().copied(), slab).unwrap();
+
+        assert_eq!(
+            scatter_copy([(62, &1u32)].iter().copied(), slab),
+            Err(Error::requested_offset_unaligned())
+        );
+        assert_eq!(
+            scatter_copy([(0, &1u32), (64, &1u32)].iter().copied(), slab),
+            Err(Error::out_of_memory(64, 68, 64))
+        );
+
+        // SAFETY: the offsets were all written with a `u32` above.
+        unsafe {
+            assert_eq!(read_slice_at_offset::<u32, _>(slab, 4, 1), Ok(&[7][..]));
+            assert_eq!(read_slice_at_offset::<u32, _>(slab, 32, 1), Ok(&[11][..]));
+            assert_eq!(read_slice_at_offset::<u32, _>(slab, 60, 1), Ok(&[13][..]));
+        }
+    }
+
+    #[test]
+    fn gather_scattered_values() {
+        let mut slab = make_stack_slab::<u32, 16>();
+        let slab = slab.as_mut_slice();
+
+        let values = [7u32, 11, 13];
+        let pairs = [(4, &values[0]), (32, &values[1]), (60, &values[2])];
+        scatter_copy(pairs.iter().copied(), slab).unwrap();
+
+        // SAFETY: the offsets were all written with a `u32` above.
+        let read = unsafe { gather_read::<u32, _, _>(slab, [4, 32, 60]) }.unwrap();
+        assert!(read.copied().eq(values));
+        assert_eq!(
+            unsafe { gather_read::<u32, _, _>(slab, [4, 61]) }.err(),
+            Some(Error::requested_offset_unaligned())
+        );
+    }
+
+    #[test]
+    fn header_and_trailing_items() {
+        #[derive(Clone, Copy)]
+        #[repr(C)]
+        struct Header {
+            count: u16,
+        }
+
+        let mut slab = make_stack_slab::<u64, 4>();
+        let slab = slab.as_mut_slice();
+
+        let (record, items_offset) =
+            copy_header_and_slice_to_offset(&Header { count: 2 }, &[3u64, 4], slab, 1).unwrap();
+        assert_eq!(record.start_offset, 8);
+        assert_eq!(items_offset, 16);
+        assert_eq!(record.end_offset, 32);
+
+        // SAFETY: a `Header` and two `u64`s were just copied to these offsets.
+        unsafe {
+            assert_eq!(read_at_offset::<Header, _>(slab, 8).unwrap().count, 2);
+            assert_eq!(
+                read_slice_at_offset::<u64, _>(slab, 16, 2).unwrap(),
+                &[3, 4]
+            );
+        }
+    }
+
+    #[test]
+    fn string_writers() {
+        let mut slab = make_stack_slab::<u16, 16>();
+        let slab = slab.as_mut_slice();
+
+        let c_str = core::ffi::CStr::from_bytes_with_nul(b"main\0").unwrap();
+        let record = write_cstr_at_offset(c_str, slab, 0).unwrap();
+        assert_eq!(record.end_offset, 5);
+
+        let record = write_str_nul_terminated_at_offset("vs", slab, 5).unwrap();
+        assert_eq!(record.end_offset, 8);
+
+        let record = write_utf16_at_offset("a\u{1F600}", slab, 8, true).unwrap();
+        assert_eq!(record.end_offset, 16);
+        assert_eq!(
+            write_utf16_at_offset("a", slab, 9, false),
+            Err(Error::requested_offset_unaligned())
+        );
+
+        // SAFETY: all of these bytes were just written.
+        unsafe {
+            let bytes = slab.assume_range_initialized_as_bytes(..8);
+            assert_eq!(bytes, b"main\0vs\0");
+            let units = read_slice_at_offset::<u16, _>(slab, 8, 4).unwrap();
+            assert_eq!(units, &[0x61, 0xD83D, 0xDE00, 0]);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn zeroed_heap_slab() {
+        let mut slab = HeapSlab::new_zeroed(core::alloc::Layout::new::<[u32; 4]>());
+        assert_eq!(slab.initialized_bytes_mut().unwrap(), &[0; 16]);
+
+        copy_to_offset_exact(&1u32, &mut slab, 0).unwrap();
+        assert!(slab.initialized_bytes_mut().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn grow_heap_slab() {
+        let mut slab = HeapSlab::for_array::<u32>(2).unwrap();
+        copy_from_slice_to_offset(&[1u32, 2], &mut slab, 0).unwrap();
+
+        slab.grow(core::alloc::Layout::new::<[u32; 8]>()).unwrap();
+        assert_eq!(slab.size(), 32);
+        slab.grow(core::alloc::Layout::new::<[u64; 8]>()).unwrap();
+        assert_eq!(slab.size(), 64);
+        assert_eq!(
+            slab.grow(core::alloc::Layout::new::<u32>()),
+            Err(Error::invalid_layout())
+        );
+
+        // SAFETY: two `u32`s were copied to the start of the slab before growing it.
+        let read = unsafe { read_slice_at_offset::<u32, _>(&slab, 0, 2) }.unwrap();
+        assert_eq!(read, &[1, 2]);
+    }
+
+    /// An allocator which keeps track of how many bytes it has live, and fails once a limit is hit.
+    #[cfg(feature = "allocator-api2")]
+    struct LimitedAlloc {
+        live: core::cell::Cell<usize>,
+        limit: usize,
+    }
+
+    // SAFETY: all allocations are forwarded to the global allocator.
+    #[cfg(feature = "allocator-api2")]
+    unsafe impl allocator_api2::alloc::Allocator for &LimitedAlloc {
+        fn allocate(
+            &self,
+            layout: core::alloc::Layout,
+        ) -> Result<NonNull<[u8]>, allocator_api2::alloc::AllocError> {
+            let live = self.live.get() + layout.size();
+            if live > self.limit {
+                return Err(allocator_api2::alloc::AllocError);
+            }
+            let ptr = allocator_api2::alloc::Global.allocate(layout)?;
+            self.live.set(live);
+            Ok(ptr)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: core::alloc::Layout) {
+            self.live.set(self.live.get() - layout.size());
+            // SAFETY: `ptr` was allocated by `Global` with `layout`
+            unsafe { allocator_api2::alloc::Global.deallocate(ptr, layout) }
+        }
+    }
+
+    #[cfg(feature = "allocator-api2")]
+    #[test]
+    fn heap_slab_in_custom_allocator() {
+        let alloc = LimitedAlloc {
+            live: core::cell::Cell::new(0),
+            limit: 80,
+        };
+
+        let mut slab = HeapSlab::for_array_in::<u32>(2, &alloc).unwrap();
+        assert_eq!(alloc.live.get(), 8);
+        copy_from_slice_to_offset(&[1u32, 2], &mut slab, 0).unwrap();
+
+        slab.grow(core::alloc::Layout::new::<[u32; 16]>()).unwrap();
+        assert_eq!(alloc.live.get(), 64);
+        assert_eq!(
+            slab.grow(core::alloc::Layout::new::<[u32; 32]>()),
+            Err(Error::allocation_failed())
+        );
+        let err = HeapSlab::try_new_zeroed_in(core::alloc::Layout::new::<[u8; 32]>(), &alloc)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::AllocationFailed);
+        assert_eq!(err.bounds(), None);
+
+        let (ptr, layout, alloc_ref) = slab.into_raw_with_allocator();
+        // SAFETY: the parts were just returned by `into_raw_with_allocator`
+        let slab = unsafe { HeapSlab::from_raw_in(ptr, layout, alloc_ref) };
+        // SAFETY: two `u32`s were copied to the start of the slab before growing it.
+        let read = unsafe { read_slice_at_offset::<u32, _>(&slab, 0, 2) }.unwrap();
+        assert_eq!(read, &[1, 2]);
+        assert!(core::ptr::eq(*slab.allocator(), &alloc));
+
+        drop(slab);
+        assert_eq!(alloc.live.get(), 0);
+    }
+
+    #[cfg(feature = "os")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri doesn't support the OS memory APIs
+    fn page_aligned_heap_slabs() {
+        let page_size = crate::page_size();
+        let slab = HeapSlab::new_page_aligned(1).unwrap();
+        assert_eq!(slab.size(), page_size);
+        assert_eq!(crate::addr(slab.base_ptr()) % page_size, 0);
+
+        let slab = HeapSlab::new_huge_page_aligned(crate::HUGE_PAGE_SIZE + 1).unwrap();
+        assert_eq!(slab.size(), 2 * crate::HUGE_PAGE_SIZE);
+        assert_eq!(crate::addr(slab.base_ptr()) % crate::HUGE_PAGE_SIZE, 0);
+    }
+
+    #[cfg(feature = "os")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri doesn't support the OS memory APIs
+    fn guarded_slab_ends_at_guard_page() {
+        let mut slab = GuardedSlab::new(core::alloc::Layout::new::<[u32; 3]>()).unwrap();
+        let end = crate::addr(slab.base_ptr()) + slab.size();
+        assert_eq!(end % crate::page_size(), 0);
+
+        copy_from_slice_to_offset(&[1u32, 2, 3], &mut slab, 0).unwrap();
+        assert_eq!(
+            copy_to_offset_exact(&1u32, &mut slab, 12),
+            Err(Error::out_of_memory(12, 16, 12))
+        );
+    }
+
+    #[cfg(feature = "os")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri doesn't support the OS memory APIs
+    fn virtual_slab_commits_on_demand() {
+        let page_size = crate::page_size();
+        let mut slab = VirtualSlab::new(64 * page_size).unwrap();
+        assert_eq!(slab.size(), 0);
+        let base_ptr = slab.base_ptr();
+
+        let layout = core::alloc::Layout::new::<[u64; 4]>();
+        let record = slab.commit_for(layout, page_size - 8, 1).unwrap();
+        assert_eq!(slab.committed_size(), 2 * page_size);
+        copy_to_offset_exact(&[1u64, 2, 3, 4], &mut slab, record.start_offset).unwrap();
+
+        slab.commit_to(10 * page_size + 1).unwrap();
+        assert_eq!(slab.committed_size(), 11 * page_size);
+        assert_eq!(slab.base_ptr(), base_ptr);
+        assert_eq!(
+            slab.commit_to(65 * page_size),
+            Err(Error::out_of_memory(0, 65 * page_size, 64 * page_size))
+        );
+    }
+
+    #[cfg(feature = "os")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri doesn't support the OS memory APIs
+    fn virtual_slab_copies_commit_lazily() {
+        let page_size = crate::page_size();
+        let mut slab = VirtualSlab::new(4 * page_size).unwrap();
+
+        // the free functions only see committed memory
+        assert_eq!(
+            copy_to_offset(&1u32, &mut slab, 0),
+            Err(Error::out_of_memory(0, 4, 0))
+        );
+
+        let record = slab.copy(&1u32, 0, 1).unwrap();
+        assert_eq!(record.start_offset, 0);
+        assert_eq!(slab.committed_size(), page_size);
+
+        let values = [2u64; 4];
+        let record = slab.copy_slice(&values, 2 * page_size - 4, 1).unwrap();
+        assert_eq!(record.start_offset, 2 * page_size);
+        assert_eq!(slab.committed_size(), 3 * page_size);
+        // SAFETY: `values` was just copied to `record.start_offset`
+        let read = unsafe { read_slice_at_offset::<u64, _>(&slab, record.start_offset, 4) };
+        assert_eq!(read.unwrap(), &values);
+
+        // nothing is committed if the copy doesn't fit in the reserved size
+        assert_eq!(
+            slab.copy(&[0u8; 16], 4 * page_size - 8, 1)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::OutOfMemory
+        );
+        assert_eq!(slab.committed_size(), 3 * page_size);
+    }
+
+    #[cfg(feature = "os")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri doesn't support the OS memory APIs
+    fn virtual_slab_commits_padding_for_large_alignments() {
+        let page_size = crate::page_size();
+        let mut slab = VirtualSlab::new(8 * page_size).unwrap();
+
+        // the copy itself ends just before a page boundary, but its padding doesn't
+        let values = vec![1u8; page_size - 8];
+        let record = slab.copy_slice(&values, 0, 2 * page_size).unwrap();
+        assert_eq!(record.end_offset, record.start_offset + page_size - 8);
+        assert_eq!(
+            record.end_offset_padded,
+            record.start_offset + 2 * page_size
+        );
+        assert_eq!(slab.committed_size(), record.end_offset_padded);
+        // SAFETY: `values` was just copied to `record.start_offset`
+        let read =
+            unsafe { read_slice_at_offset::<u8, _>(&slab, record.start_offset, values.len()) };
+        assert_eq!(read.unwrap(), &values[..]);
+    }
+
+    #[test]
+    fn array_slab() {
+        let mut slab = make_stack_slab::<u32, 4>();
+        let record = copy_from_slice_to_offset(&[1u32, 2], &mut slab, 4).unwrap();
+        assert_eq!(record.end_offset, 12);
+        assert_eq!(slab.size(), 16);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn boxed_slab_roundtrip() {
+        let mut slab = crate::boxed_slab(4);
+        copy_from_slice_to_offset(&[1u8, 2, 3, 4], &mut slab, 0).unwrap();
+        // SAFETY: all 4 bytes were just copied in.
+        let bytes = unsafe { crate::boxed_slab_assume_init(slab) };
+        assert_eq!(&*bytes, &[1, 2, 3, 4]);
+        assert_eq!(crate::boxed_slab_from_bytes(bytes).size(), 4);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn vec_slab_extends_len() {
+        let mut vec = vec![1u32];
+        vec.reserve(8);
+        {
+            let mut slab = VecSlab::new(&mut vec);
+            let record = copy_from_slice_to_offset(&[2u32, 3], &mut slab, 0).unwrap();
+            // SAFETY: two `u32`s were just copied to the start of the slab.
+            unsafe { slab.mark_initialized(record) };
+            let record = crate::copy_to_offset(&4u32, &mut slab, record.end_offset).unwrap();
+            // SAFETY: another `u32` was just copied directly after them.
+            unsafe { slab.mark_initialized(record) };
+            assert_eq!(slab.initialized_count(), 3);
+        }
+        assert_eq!(vec, [1, 2, 3, 4]);
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn bytes_mut_slab_extends_len() {
+        let mut bytes = bytes::BytesMut::with_capacity(8);
+        bytes.extend_from_slice(&[1]);
+        let mut slab = BytesMutSlab::new(&mut bytes);
+        let record = copy_from_slice_to_offset(&[2u8, 3], &mut slab, 0).unwrap();
+        // SAFETY: two bytes were just copied to the start of the slab.
+        unsafe { slab.mark_initialized(record) };
+        assert_eq!(slab.commit(), 2);
+        assert_eq!(&bytes[..], [1, 2, 3]);
+    }
+
+    #[cfg(feature = "arrayvec")]
+    #[test]
+    fn array_vec_slab_extends_len() {
+        let mut vec = arrayvec::ArrayVec::<u32, 4>::new();
+        vec.push(1);
+        let mut slab = ArrayVecSlab::new(&mut vec);
+        assert_eq!(slab.size(), 12);
+        let record = copy_from_slice_to_offset(&[2u32, 3], &mut slab, 0).unwrap();
+        // SAFETY: two `u32`s were just copied to the start of the slab.
+        unsafe { slab.mark_initialized(record) };
+        assert_eq!(slab.commit(), 2);
+        assert_eq!(&vec[..], [1, 2, 3]);
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn small_vec_slab_extends_len() {
+        let mut vec = smallvec::SmallVec::<[u32; 2]>::new();
+        vec.push(1);
+        // move the storage to the heap before making the slab
+        vec.reserve(4);
+        let mut slab = SmallVecSlab::new(&mut vec);
+        let record = copy_from_slice_to_offset(&[2u32, 3], &mut slab, 0).unwrap();
+        // SAFETY: two `u32`s were just copied to the start of the slab.
+        unsafe { slab.mark_initialized(record) };
+        assert_eq!(slab.commit(), 2);
+        assert_eq!(&vec[..], [1, 2, 3]);
+    }
+
+    #[test]
+    fn shared_read_slab() {
+        let mut slab = make_stack_slab::<u32, 2>();
+        copy_from_slice_to_offset(&[5u32, 6], &mut slab, 0).unwrap();
+
+        let raw = RawAllocation::from_raw_parts(NonNull::from(&mut slab).cast(), 8);
+        // SAFETY: `slab` is a valid allocation of 8 bytes which isn't mutated while borrowed.
+        let (a, b) = unsafe { (raw.borrow_as_read_slab(), raw.borrow_as_read_slab()) };
+        // SAFETY: two `u32`s were copied in above.
+        unsafe {
+            assert_eq!(*read_at_offset::<u32, _>(&a, 0).unwrap(), 5);
+            assert_eq!(*read_at_offset::<u32, _>(&b, 4).unwrap(), 6);
+        }
+    }
+
+    #[test]
+    fn raw_slice_view() {
+        let mut slab = make_stack_slab::<u32, 2>();
+        let slice = slab.as_raw_slice();
+        assert_eq!(slice.len(), 8);
+
+        let mut raw = RawAllocation::from_raw_slice(slice);
+        assert_eq!(raw.as_raw_slice(), slice);
+        // SAFETY: `slab` is a valid allocation of 8 bytes which isn't otherwise accessed while
+        // borrowed.
+        let mut borrowed = unsafe { raw.borrow_as_slab() };
+        crate::copy_to_offset(&7u32, &mut borrowed, 4).unwrap();
+
+        // SAFETY: a `u32` was copied in at offset 4 above.
+        assert_eq!(unsafe { slab[1].assume_init() }, 7);
+    }
+
+    #[test]
+    fn raw_allocation_from_c_parts() {
+        let mut slab = make_stack_slab::<u8, 8>();
+        let ptr = slab.base_ptr_mut().cast::<c_void>();
+
+        let raw = RawAllocation::from_c_parts(ptr, 8).unwrap();
+        assert_eq!(raw.base_ptr.as_ptr().cast::<c_void>(), ptr);
+        assert_eq!(raw.size, 8);
+
+        let err = RawAllocation::from_c_parts(core::ptr::null_mut(), 8).err();
+        assert_eq!(err, Some(Error::invalid_layout()));
+        // nothing is done with the allocation, so only the size itself is checked
+        assert!(RawAllocation::from_c_parts(ptr, isize::MAX as usize).is_ok());
+        let err = RawAllocation::from_c_parts(ptr, isize::MAX as usize + 1).err();
+        assert_eq!(err, Some(Error::invalid_layout()));
+    }
+
+    #[test]
+    fn raw_allocation_from_uninit_slice() {
+        let mut buf = [core::mem::MaybeUninit::<u8>::uninit(); 8];
+        let mut slab = RawAllocation::from_uninit_slice(&mut buf[2..]);
+        assert_eq!(slab.size(), 6);
+        let record = copy_from_slice_to_offset(&[1u8, 2, 3], &mut slab, 1).unwrap();
+        assert_eq!(record.range(), 1..4);
+        // SAFETY: the three bytes were copied through the slab above
+        let copied = [3, 4, 5].map(|i| unsafe { buf[i].assume_init() });
+        assert_eq!(copied, [1, 2, 3]);
+
+        let mut empty = RawAllocation::from_uninit_slice(&mut []);
+        assert_eq!(empty.size(), 0);
+        assert!(!empty.base_ptr().is_null());
+        copy_from_slice_to_offset::<u8, _>(&[], &mut empty, 0).unwrap();
+        assert_eq!(
+            copy_to_offset(&1u8, &mut empty, 0),
+            Err(Error::out_of_memory(0, 1, 0))
+        );
+        // SAFETY: reading no elements is always valid.
+        let read = unsafe { read_slice_at_offset::<u8, _>(&empty, 0, 0) };
+        assert_eq!(read.unwrap(), &[]);
+    }
+
+    #[test]
+    fn raw_allocation_split_at() {
+        let mut slab = make_stack_slab::<u32, 4>();
+        let base_ptr = NonNull::new(slab.base_ptr_mut()).unwrap();
+        let raw = || RawAllocation { base_ptr, size: 16 };
+
+        let (first, second) = raw().split_at(0).unwrap();
+        assert_eq!(first.size, 0);
+        assert_eq!(second.size, 16);
+        assert_eq!(second.base_ptr, base_ptr);
+
+        let (first, second) = raw().split_at(16).unwrap();
+        assert_eq!(first.size, 16);
+        assert_eq!(first.base_ptr, base_ptr);
+        assert_eq!(second.size, 0);
+
+        let err = raw().split_at(17).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::OffsetOutOfBounds);
+
+        let (mut first, mut second) = raw().split_at(8).unwrap();
+        // SAFETY: the halves are disjoint parts of `slab`, which isn't otherwise accessed while
+        // they're borrowed.
+        let (mut first, mut second) = unsafe { (first.borrow_as_slab(), second.borrow_as_slab()) };
+        copy_from_slice_to_offset(&[1u32, 2], &mut first, 0).unwrap();
+        copy_from_slice_to_offset(&[3u32, 4], &mut second, 0).unwrap();
+        copy_to_offset(&5u32, &mut second, 8).unwrap_err();
+
+        // SAFETY: every element was written through one of the halves above.
+        let values = slab.map(|v| unsafe { v.assume_init() });
+        assert_eq!(values, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn mapped_slab_flushes_atom_aligned_ranges() {
+        struct NonCoherent {
+            slab: [core::mem::MaybeUninit<u8>; 100],
+            flushed: Vec<core::ops::Range<usize>>,
+        }
+
+        // SAFETY: delegates to the array's implementation.
+        unsafe impl Slab for NonCoherent {
+            fn base_ptr(&self) -> *const u8 {
+                self.slab.base_ptr()
+            }
+
+            fn base_ptr_mut(&mut self) -> *mut u8 {
+                self.slab.base_ptr_mut()
+            }
+
+            fn size(&self) -> usize {
+                self.slab.size()
+            }
+        }
+
+        impl MappedSlab for NonCoherent {
+            fn non_coherent_atom_size(&self) -> usize {
+                64
+            }
+
+            fn flush(&mut self, range: core::ops::Range<usize>) -> Result<(), Error> {
+                self.flushed.push(range);
+                Ok(())
+            }
+
+            fn invalidate(&mut self, _range: core::ops::Range<usize>) -> Result<(), Error> {
+                Ok(())
+            }
+        }
+
+        let mut slab = NonCoherent {
+            slab: [core::mem::MaybeUninit::uninit(); 100],
+            flushed: Vec::new(),
+        };
+        let record = crate::copy_from_slice_to_offset_and_flush(&[1u8; 8], &mut slab, 60).unwrap();
+        assert_eq!(record.range(), 60..68);
+        crate::copy_to_offset_and_flush(&2u8, &mut slab, 70).unwrap();
+        assert_eq!(slab.flushed, [0..100, 64..100]);
+        assert_eq!(slab.atom_aligned_range(0..0).unwrap(), 0..0);
+        assert_eq!(
+            slab.atom_aligned_range(0..101),
+            Err(Error::offset_out_of_bounds(0, 101, 100))
+        );
+    }
+
+    #[test]
+    fn streaming_copies() {
+        let mut slab = make_stack_slab::<u16, 50>();
+        let src: [u8; 70] = core::array::from_fn(|i| i as u8);
+        let record = copy_from_slice_to_offset_streaming(&src, &mut slab, 3).unwrap();
+        assert_eq!(record.range(), 3..73);
+        let record = copy_to_offset_streaming(&0xABCD_u16, &mut slab, 73).unwrap();
+        assert_eq!(record.range(), 74..76);
+
+        // SAFETY: the bytes were copied in above.
+        unsafe {
+            assert_eq!(
+                read_slice_at_offset::<u8, _>(&slab, 3, 70).unwrap(),
+                &src[..]
+            );
+            assert_eq!(*read_at_offset::<u16, _>(&slab, 74).unwrap(), 0xABCD);
+        }
+    }
+
+    #[test]
+    fn hinted_copies() {
+        let src: [u8; 10000] = core::array::from_fn(|i| i as u8);
+        for hint in [CopyHint::Default, CopyHint::Large, CopyHint::Streaming] {
+            let mut slab = make_stack_slab::<u64, 1260>();
+            let record = copy_from_slice_to_offset_with_hint(&src, &mut slab, 1, hint).unwrap();
+            assert_eq!(record.range(), 1..10001);
+
+            // SAFETY: the bytes were copied in above.
+            let copied = unsafe { read_slice_at_offset::<u8, _>(&slab, 1, 10000).unwrap() };
+            assert_eq!(copied, &src[..]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn parallel_copy() {
+        let src: Vec<u32> = (0..1_000_000).collect();
+        let mut slab = crate::boxed_slab(4_000_004);
+        let record = crate::par_copy_from_slice_to_offset(&src, &mut slab, 1).unwrap();
+        assert_eq!(record.range(), 4..4_000_004);
+
+        // SAFETY: the values were copied in above.
+        let copied = unsafe { read_slice_at_offset::<u32, _>(&slab, 4, src.len()).unwrap() };
+        assert_eq!(copied, &src[..]);
+    }
+
+    /// Polls `future` to completion on the current thread, returning its output along with the
+    /// number of times it returned [`Poll::Pending`][core::task::Poll::Pending].
+    #[cfg(all(feature = "async", feature = "std"))]
+    fn poll_to_completion<F: core::future::Future>(future: F) -> (F::Output, usize) {
+        use std::sync::Arc;
+        use std::task::Context;
+        use std::task::Poll;
+        use std::task::Wake;
+        use std::task::Waker;
+
+        struct NoopWaker;
+
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        let mut future = Box::pin(future);
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut pending = 0;
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return (output, pending),
+                Poll::Pending => pending += 1,
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "async", feature = "std"))]
+    fn async_copy_yields_between_chunks() {
+        let src = vec![7u8; 3 * crate::ASYNC_COPY_CHUNK_SIZE + 1];
+        let mut slab = crate::boxed_slab(src.len());
+        let (record, pending) =
+            poll_to_completion(crate::copy_from_slice_to_offset_async(&src, &mut slab, 0));
+        let record = record.unwrap();
+
+        assert_eq!(pending, 3);
+        assert_eq!(record.range(), 0..src.len());
+        // SAFETY: the bytes were copied in above.
+        let copied = unsafe { read_slice_at_offset::<u8, _>(&slab, 0, src.len()).unwrap() };
+        assert_eq!(copied, &src[..]);
+    }
+
+    #[test]
+    #[cfg(feature = "async-io")]
+    fn async_reader_fills_slab() {
+        let mut slab = crate::boxed_slab(16);
+
+        let mut reader: &[u8] = b"hello";
+        let (record, _) = poll_to_completion(crate::copy_from_async_reader_to_offset(
+            &mut reader,
+            &mut slab,
+            4,
+        ));
+        assert_eq!(record.unwrap().range(), 4..9);
+
+        let mut reader: &[u8] = &[1; 32];
+        let (record, _) = poll_to_completion(crate::copy_from_async_reader_to_offset(
+            &mut reader,
+            &mut slab,
+            9,
+        ));
+        assert_eq!(record.unwrap().range(), 9..16);
+        assert_eq!(reader.len(), 25);
+
+        // SAFETY: the bytes were read in above.
+        let read = unsafe { read_slice_at_offset::<u8, _>(&slab, 4, 6).unwrap() };
+        assert_eq!(read, b"hello\x01");
+
+        let mut reader: &[u8] = &[];
+        let (record, _) = poll_to_completion(crate::copy_from_async_reader_to_offset(
+            &mut reader,
+            &mut slab,
+            17,
+        ));
+        assert_eq!(record.unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn write_range_to_writer() {
+        let mut slab = make_stack_slab::<u8, 8>();
+        copy_from_slice_to_offset(b"presser!", &mut slab, 0).unwrap();
+
+        let mut out = Vec::new();
+        // SAFETY: all 8 bytes were initialized above.
+        unsafe {
+            crate::write_range_to(&slab, 2..=4, &mut out).unwrap();
+            crate::write_range_to(&slab, 7.., &mut out).unwrap();
+            let err = crate::write_range_to(&slab, 4..9, &mut out).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        }
+        assert_eq!(out, b"ess!");
+    }
+
+    #[test]
+    #[cfg(feature = "gpu-allocator")]
+    fn unmapped_vulkan_allocation_is_not_a_slab() {
+        let mut allocation = gpu_allocator::vulkan::Allocation::default();
+        assert!(crate::vulkan_allocation_as_slab(&mut allocation).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "ash")]
+    fn vk_mapped_slab_uses_device_alignments() {
+        let limits = ash::vk::PhysicalDeviceLimits {
+            min_uniform_buffer_offset_alignment: 256,
+            min_storage_buffer_offset_alignment: 64,
+            non_coherent_atom_size: 128,
+            ..Default::default()
+        };
+        let mut memory = crate::AlignedStackSlab::<1024, 256>::new();
+        // SAFETY: `memory` is a valid allocation of 1024 bytes which isn't otherwise accessed
+        // while `slab` is alive.
+        let mut slab =
+            unsafe { crate::VkMappedSlab::new(memory.base_ptr_mut().cast(), 1024, &limits) }
+                .unwrap();
+        assert_eq!(slab.alignments().non_coherent_atom_size, 128);
+
+        let uniform = slab.copy_uniform(&1u32, 1).unwrap();
+        assert_eq!(uniform.dynamic_offset, 256);
+        let storage = slab.copy_storage_slice(&[2u32; 4], 260).unwrap();
+        assert_eq!(storage.dynamic_offset, 320);
+    }
+
+    #[test]
+    #[cfg(feature = "wgpu")]
+    fn wgpu_write_only_slab() {
+        let mut bytes = [0u8; 8];
+        // SAFETY: we never read from `slab`, and `u16` has no padding bytes.
+        let mut slab = unsafe { crate::write_only_as_slab(wgpu::WriteOnly::from_mut(&mut bytes)) };
+        crate::copy_to_offset_le(&0x0102u16, &mut slab, 5).unwrap();
+        let gap = if cfg!(feature = "poison") {
+            crate::POISON_BYTE
+        } else {
+            0
+        };
+        assert_eq!(bytes, [0, 0, 0, 0, 0, gap, 2, 1]);
+    }
+
+    #[test]
+    fn gl_flush_args() {
+        let mut memory = make_stack_slab::<u32, 16>();
+        // SAFETY: `memory` is a valid allocation of 64 bytes which isn't otherwise accessed while
+        // `slab` is alive.
+        let mut slab =
+            unsafe { GlMappedSlab::new(memory.base_ptr_mut().cast(), 64, false) }.unwrap();
+        let a = copy_from_slice_to_offset(&[1u32, 2], &mut slab, 4).unwrap();
+        let b = copy_from_slice_to_offset(&[3u32], &mut slab, 20).unwrap();
+        assert_eq!(slab.flush_args(&a), Some((4, 8)));
+        assert_eq!(slab.merged_flush_args([&a, &b]), Some((4, 20)));
+
+        // SAFETY: as above.
+        let coherent =
+            unsafe { GlMappedSlab::new(memory.base_ptr_mut().cast(), 64, true) }.unwrap();
+        assert_eq!(coherent.flush_args(&a), None);
+    }
+
+    #[test]
+    #[cfg(feature = "capi")]
+    fn capi_copy_and_plan() {
+        let mut memory = make_stack_slab::<u32, 4>();
+        let dst = memory.base_ptr_mut().cast::<c_void>();
+        let src = [1u8, 2, 3, 4];
+        let mut record = core::mem::MaybeUninit::<PresserCopyRecord>::uninit();
+
+        // SAFETY: `dst` is valid for writes of 16 bytes, `src` for reads of 4 bytes, and `record`
+        // for writes.
+        let status = unsafe {
+            presser_copy_bytes(dst, 16, 1, src.as_ptr().cast(), 4, 4, record.as_mut_ptr())
+        };
+        assert_eq!(status, PresserStatus::Ok);
+        // SAFETY: the copy succeeded, so `record` was written.
+        let record = unsafe { record.assume_init() };
+        assert_eq!(record.start_offset, 4);
+        assert_eq!(record.end_offset, 8);
+
+        // SAFETY: `out_record` may be null.
+        let status = unsafe { presser_plan_offset(dst, 16, 8, 12, 4, 1, core::ptr::null_mut()) };
+        assert_eq!(status, PresserStatus::OutOfMemory);
+        // SAFETY: as above.
+        let status = unsafe {
+            presser_copy_bytes(dst, 16, 0, core::ptr::null(), 4, 1, core::ptr::null_mut())
+        };
+        assert_eq!(status, PresserStatus::InvalidLayout);
+    }
+
+    #[test]
+    fn slab_ext_methods() {
+        let mut slab = make_stack_slab::<u32, 8>();
+        let a = slab.copy_at(&1u16, 1).unwrap();
+        assert_eq!(a.start_offset, 2);
+        let b = slab.copy_slice_at(&[2u32, 3], a.end_offset).unwrap();
+        assert_eq!(b.start_offset, 4);
+        let c = slab.fill_at(&7u8, 4, b.end_offset, 1).unwrap();
+        assert_eq!(
+            slab.copy_at_exact(&0u32, 3),
+            Err(Error::requested_offset_unaligned())
+        );
+
+        // SAFETY: all of these were copied above.
+        unsafe {
+            assert_eq!(*slab.read_at::<u16>(a.start_offset).unwrap(), 1);
+            assert_eq!(
+                slab.read_slice_at::<u32>(b.start_offset, 2).unwrap(),
+                &[2, 3]
+            );
+            assert_eq!(
+                slab.read_slice_at::<u8>(c.start_offset, 4).unwrap(),
+                &[7; 4]
+            );
+        }
+    }
+
+    #[test]
+    fn copy_options_builder() {
+        let mut slab = make_stack_slab::<u32, 16>();
+        slab.initialize_all_zeroed().fill(0xff);
+
+        let record = CopyOptions::from_slice(&[1u16, 2, 3])
+            .at_least_offset(2)
+            .min_align(4)
+            .zero_padding()
+            .copy_to(&mut slab)
+            .unwrap();
+        assert_eq!(record.start_offset, 4);
+        assert_eq!(record.end_offset, 10);
+        assert_eq!(record.end_offset_padded, 12);
+        // SAFETY: bytes 0..2 were filled above, and 2..12 by the copy and its zeroed padding.
+        let bytes = unsafe { slab.assume_range_initialized_as_bytes(..12) };
+        assert_eq!(&bytes[..4], &[0xff, 0xff, 0, 0]);
+        assert_eq!(&bytes[10..], &[0, 0]);
+
+        let exact = CopyOptions::new(&7u32).at_least_offset(18).exact();
+        assert_eq!(
+            exact.copy_to(&mut slab),
+            Err(Error::requested_offset_unaligned())
+        );
+        let record = CopyOptions::new(&7u32)
+            .at_least_offset(20)
+            .exact()
+            .hint(CopyHint::Streaming)
+            .copy_to(&mut slab)
+            .unwrap();
+        assert_eq!(record.start_offset, 20);
+    }
+
+    #[test]
+    fn error_kind_and_bounds() {
+        let mut slab = make_stack_slab::<u16, 4>();
+        let err = copy_to_offset(&0u32, &mut slab, 6).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OutOfMemory);
+        let bounds = err.bounds().unwrap();
+        assert_eq!(bounds.requested_offset, 6);
+        assert_eq!(bounds.slab_size, 8);
+
+        let err = copy_to_offset_exact(&0u16, &mut slab, 3).unwrap_err();
+        assert_eq!(err, ErrorKind::RequestedOffsetUnaligned.into());
+        assert_eq!(err.bounds(), None);
+
+        #[cfg(feature = "std")]
+        {
+            let io_err = std::io::Error::from(err);
+            assert_eq!(io_err.kind(), std::io::ErrorKind::InvalidInput);
+            let inner = io_err.get_ref().unwrap().downcast_ref::<Error>();
+            assert_eq!(inner, Some(&err));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hex_dump_range() {
+        let mut slab = make_stack_slab::<u8, 24>();
+        slab.initialize_all_zeroed();
+        copy_from_slice_to_offset(b"presser!", &mut slab, 14).unwrap();
+
+        // SAFETY: the whole slab was initialized above
+        let dump = unsafe { dump_range(&slab, 4..24) }.unwrap();
+        assert_eq!(
+            dump,
+            "00000004  00 00 00 00 00 00 00 00  00 00 70 72 65 73 73 65  |..........presse|\n\
+             00000014  72 21 00 00                                       |r!..|"
+        );
+
+        // SAFETY: the whole slab was initialized above
+        let view = unsafe { hex_dump(&slab, 20..) }.unwrap();
+        assert_eq!(format!("{:?}", view), view.to_string());
+
+        // SAFETY: the range is out of bounds, so nothing is read
+        let result = unsafe { hex_dump(&slab, 20..25) };
+        assert!(matches!(result, Err(err) if err.kind() == ErrorKind::OutOfMemory));
+    }
+
+    #[test]
+    fn diff_ranges_finds_first_difference() {
+        let mut a = make_stack_slab::<u8, 16>();
+        let mut b = make_stack_slab::<u8, 16>();
+        a.initialize_all_zeroed()
+            .copy_from_slice(b"presser presses!");
+        b.initialize_all_zeroed()
+            .copy_from_slice(b"xxpresser presse");
+
+        // SAFETY: both slabs were fully initialized above
+        unsafe {
+            assert_eq!(diff_ranges(&a, 0..8, &b, 2..10), Ok(None));
+            assert_eq!(diff_ranges(&a, .., &b, 2..), Ok(Some(14)));
+            assert_eq!(diff_ranges(&a, 0..4, &b, 2..8), Ok(Some(4)));
+            assert_eq!(
+                diff_ranges(&a, 0..4, &b, 8..17),
+                Err(Error::out_of_memory(8, 17, 16))
+            );
+        }
+    }
+
+    #[test]
+    fn verify_placed_values() {
+        #[derive(Clone, Copy, PartialEq)]
+        #[repr(C)]
+        struct Padded {
+            a: u8,
+            b: u32,
+        }
+
+        let mut slab = make_stack_slab::<u32, 8>();
+        let value = Padded { a: 1, b: 2 };
+        let record = copy_to_offset(&value, &mut slab, 0).unwrap();
+        let slice = copy_from_slice_to_offset(&[3u16, 4], &mut slab, record.end_offset).unwrap();
+
+        // SAFETY: the values were placed by the copies above
+        unsafe {
+            assert_eq!(verify_at_offset(&value, &slab, 0), Ok(true));
+            assert_eq!(
+                verify_at_offset(&Padded { a: 1, b: 3 }, &slab, 0),
+                Ok(false)
+            );
+            assert_eq!(
+                verify_slice_at_offset(&[3u16, 4], &slab, slice.start_offset),
+                Ok(true)
+            );
+            assert_eq!(
+                verify_slice_at_offset(&[3u16, 5], &slab, slice.start_offset),
+                Ok(false)
+            );
+            assert_eq!(
+                verify_at_offset(&value, &slab, 2),
+                Err(ErrorKind::RequestedOffsetUnaligned.into())
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_ranges() {
+        use std::hash::Hasher;
+
+        let mut slab = make_stack_slab::<u8, 16>();
+        slab.initialize_all_zeroed();
+        let record = copy_from_slice_to_offset(b"123456789", &mut slab, 3).unwrap();
+
+        let mut expected = std::collections::hash_map::DefaultHasher::new();
+        expected.write(b"123456789");
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        // SAFETY: the whole slab was initialized above
+        unsafe { hash_range(&slab, record.start_offset..record.end_offset, &mut hasher) }.unwrap();
+        assert_eq!(hasher.finish(), expected.finish());
+
+        #[cfg(feature = "crc32")]
+        {
+            // SAFETY: the whole slab was initialized above
+            let crc = unsafe { crc32_range(&slab, 3..12) };
+            assert_eq!(crc, Ok(0xcbf4_3926));
+        }
+    }
+
+    #[cfg(feature = "poison")]
+    #[test]
+    fn poison_uninit_memory() {
+        let mut slab = make_stack_slab::<u32, 2>();
+        // SAFETY: with the `poison` feature, every byte was just filled with `POISON_BYTE`
+        let bytes = unsafe { read_slice_at_offset::<u8, _>(&slab, 0, 8) }.unwrap();
+        assert!(bytes.iter().all(|&b| b == POISON_BYTE));
+
+        slab.initialize_all_zeroed();
+        let record = copy_to_offset(&1u32, &mut slab, 1).unwrap();
+        assert_eq!(record.start_offset, 4);
+        // SAFETY: the whole slab was initialized above
+        let bytes = unsafe { read_slice_at_offset::<u8, _>(&slab, 0, 8) }.unwrap();
+        assert_eq!(&bytes[..4], &[0, POISON_BYTE, POISON_BYTE, POISON_BYTE]);
+
+        #[cfg(feature = "rayon")]
+        {
+            slab.initialize_all_zeroed();
+            let record = crate::par_copy_from_slice_to_offset(&[1u32], &mut slab, 1).unwrap();
+            assert_eq!(record.start_offset, 4);
+            // SAFETY: the whole slab was initialized above
+            let bytes = unsafe { read_slice_at_offset::<u8, _>(&slab, 0, 8) }.unwrap();
+            assert_eq!(&bytes[..4], &[0, POISON_BYTE, POISON_BYTE, POISON_BYTE]);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let slab = HeapSlab::new(core::alloc::Layout::new::<[u8; 16]>());
+            // SAFETY: with the `poison` feature, every byte was just filled with `POISON_BYTE`
+            let bytes = unsafe { read_slice_at_offset::<u8, _>(&slab, 0, 16) }.unwrap();
+            assert!(bytes.iter().all(|&b| b == POISON_BYTE));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn streaming_arena_wraps_and_retires() {
+        let mut arena = StreamingArena::new(make_stack_slab::<u32, 16>());
+
+        let a = arena.copy_slice(&[1u32; 6], 1, 1).unwrap();
+        let b = arena.copy_slice(&[2u32; 6], 1, 2).unwrap();
+        assert_eq!((a.start_offset, b.start_offset), (0, 24));
+        assert_eq!(
+            arena.copy_slice(&[3u32; 6], 1, 3),
+            Err(ErrorKind::OutOfMemory.into())
+        );
+
+        // wraps around once the first allocation is retired, but not into the second one
+        arena.retire(1);
+        let c = arena.copy_slice(&[3u32; 5], 1, 3).unwrap();
+        assert_eq!(c.start_offset, 0);
+        assert!(arena.copy(&4u32, 16, 4).is_err());
+        assert_eq!(arena.copy(&4u32, 1, 4).unwrap().start_offset, 20);
+        assert_eq!(arena.in_flight(), 3);
+
+        arena.retire(4);
+        assert_eq!(arena.in_flight(), 0);
+        assert_eq!(arena.copy_slice(&[5u32; 16], 1, 5).unwrap().start_offset, 0);
+
+        // SAFETY: the whole slab was just initialized with `u32`s
+        let all = unsafe { read_slice_at_offset::<u32, _>(arena.inner(), 0, 16) }.unwrap();
+        assert_eq!(all, &[5; 16]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn streaming_arena_zero_sized_allocation_after_wrapping() {
+        let mut arena = StreamingArena::new(make_stack_slab::<u8, 64>());
+        arena.copy_slice(&[1u8; 24], 1, 1).unwrap();
+        let second = arena.copy_slice(&[2u8; 24], 1, 2).unwrap();
+        arena.retire(1);
+
+        let wrapped = arena.copy_slice(&[3u8; 24], 1, 3).unwrap();
+        assert_eq!(wrapped.start_offset, 0);
+        // lands directly before the second allocation, which must not make it look unwrapped
+        assert_eq!(arena.copy_slice::<u8>(&[], 1, 4).unwrap().start_offset, 24);
+        assert_eq!(
+            arena.copy_slice(&[4u8; 24], 1, 5),
+            Err(ErrorKind::OutOfMemory.into())
+        );
+
+        // SAFETY: the second allocation was initialized with `u8`s
+        let read = unsafe { read_slice_at_offset::<u8, _>(arena.inner(), second.start_offset, 24) };
+        assert_eq!(read.unwrap(), &[2; 24]);
+
+        // retiring a fence which is older than all in-flight allocations does nothing
+        arena.retire(1);
+        assert_eq!(arena.in_flight(), 3);
+
+        // once everything from before the wrap is retired, the free space is after the head again
+        arena.retire(2);
+        assert_eq!(arena.copy_slice(&[4u8; 24], 1, 5).unwrap().start_offset, 24);
+        assert_eq!(arena.copy_slice(&[5u8; 16], 1, 6).unwrap().start_offset, 48);
+        assert_eq!(
+            arena.copy_slice(&[6u8; 1], 1, 7),
+            Err(ErrorKind::OutOfMemory.into())
+        );
+
+        // and wrapping again works like the first time
+        arena.retire(3);
+        assert_eq!(arena.copy_slice(&[6u8; 24], 1, 7).unwrap().start_offset, 0);
+        assert_eq!(
+            arena.copy_slice(&[7u8; 1], 1, 8),
+            Err(ErrorKind::OutOfMemory.into())
+        );
+        arena.retire(8);
+        assert_eq!(arena.in_flight(), 0);
+        assert_eq!(arena.copy_slice(&[8u8; 64], 1, 9).unwrap().start_offset, 0);
+    }
+
+    #[test]
+    fn read_owned_value() {
+        let mut slab = make_stack_slab::<u32, 4>();
+        let record = copy_to_offset(&7u32, &mut slab, 4).unwrap();
+
+        // SAFETY: a `u32` was copied to `record.start_offset` above
+        let value = unsafe { read_owned_at_offset::<u32, _>(&slab, record.start_offset) }.unwrap();
+        // `slab` isn't borrowed by `value`, so it can be written to again
+        copy_to_offset(&8u32, &mut slab, record.start_offset).unwrap();
+        assert_eq!(value, 7);
+
+        // SAFETY: the function fails before reading anything
+        let unaligned = unsafe { read_owned_at_offset::<u32, _>(&slab, 2) };
+        assert_eq!(unaligned, Err(ErrorKind::RequestedOffsetUnaligned.into()));
+    }
+
+    #[test]
+    fn read_unaligned_value() {
+        let mut slab = make_stack_slab::<u32, 4>();
+        slab.initialize_all_zeroed();
+        copy_from_slice_to_offset(&[0x01u8, 0x02, 0x03, 0x04, 0x05], &mut slab, 1).unwrap();
+
+        // SAFETY: the whole slab was initialized above
+        unsafe {
+            assert_eq!(
+                read_unaligned_at_offset::<[u8; 2], _>(&slab, 3),
+                Ok([0x03, 0x04])
+            );
+            assert_eq!(
+                read_unaligned_at_offset::<u32, _>(&slab, 1).map(u32::from_le),
+                Ok(0x0403_0201)
+            );
+            assert_eq!(
+                read_unaligned_at_offset::<u32, _>(&slab, 13),
+                Err(Error::out_of_memory(13, 17, 16))
+            );
+        }
+    }
+
+    #[test]
+    fn copy_unaligned_packed() {
+        let mut slab = make_stack_slab::<u32, 8>();
+        let positions = [[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+        let a = copy_to_offset_unaligned(&7u8, &mut slab, 0).unwrap();
+        let b = copy_from_slice_to_offset_unaligned(&positions, &mut slab, a.end_offset).unwrap();
+        let c = copy_to_offset_unaligned(&8u32, &mut slab, b.end_offset).unwrap();
+        assert_eq!((b.start_offset, b.end_offset, c.start_offset), (1, 25, 25));
+        assert_eq!(
+            copy_to_offset_unaligned(&8u32, &mut slab, 29),
+            Err(Error::out_of_memory(29, 33, 32))
+        );
+
+        // SAFETY: the values were copied to these offsets above
+        unsafe {
+            assert_eq!(read_unaligned_at_offset::<u8, _>(&slab, 0), Ok(7));
+            assert_eq!(
+                read_unaligned_at_offset::<[f32; 3], _>(&slab, 13),
+                Ok([4.0, 5.0, 6.0])
+            );
+            assert_eq!(read_unaligned_at_offset::<u32, _>(&slab, 25), Ok(8));
+        }
+    }
+
+    #[test]
+    fn try_as_ffi_buffers() {
+        let mut slab = make_stack_slab::<u8, 16>();
+        let base = slab.base_ptr();
+
+        let (ptr, len) = slab.try_as_ffi_buffer(4..12).unwrap();
+        assert_eq!((ptr.cast::<u8>(), len), (base.wrapping_add(4), 8));
+        let (ptr, len) = slab.try_as_ffi_readback_buffer(10..).unwrap();
+        assert_eq!(
+            (ptr.cast::<u8>().cast_const(), len),
+            (base.wrapping_add(10), 6)
+        );
+
+        assert_eq!(
+            slab.try_as_ffi_buffer(8..20).map(|(_, len)| len),
+            Err(Error::out_of_memory(8, 20, 16))
+        );
+        assert!(slab.try_as_ffi_readback_buffer(17..).is_err());
+    }
+
+    #[test]
+    fn try_readback_from_ffi_fallible() {
+        #[derive(Debug, PartialEq)]
+        enum FfiError {
+            Status(i32),
+            Presser(Error),
+        }
+        impl From<Error> for FfiError {
+            fn from(err: Error) -> Self {
+                Self::Presser(err)
+            }
+        }
+
+        let mut slab = make_stack_slab::<u32, 4>();
+        // SAFETY: the closure writes a valid `u32` whenever it returns `Ok`
+        unsafe {
+            let value = try_readback_from_ffi::<u32, _, FfiError, _>(&mut slab, |ptr| {
+                ptr.cast::<u32>().write(5);
+                Ok(())
+            });
+            assert_eq!(value, Ok(&5));
+
+            let failed =
+                try_readback_from_ffi::<u32, _, _, _>(&mut slab, |_| Err(FfiError::Status(-1)));
+            assert_eq!(failed, Err(FfiError::Status(-1)));
+
+            let too_many =
+                try_readback_slice_from_ffi::<u32, _, FfiError, _>(&mut slab, |_, _| Ok(5));
+            assert_eq!(
+                too_many,
+                Err(FfiError::Presser(Error::out_of_memory(0, 20, 16)))
+            );
+        }
+    }
+
+    #[test]
+    fn readback_sized_two_calls() {
+        // stands in for a C function which reports the number of items when given a null pointer
+        unsafe fn enumerate(out: *mut u16, capacity: usize) -> usize {
+            let items = [1u16, 2, 3];
+            if !out.is_null() {
+                for (i, item) in items.iter().take(capacity).enumerate() {
+                    // SAFETY: `out` has room for `capacity` items
+                    unsafe { out.add(i).write(*item) };
+                }
+            }
+            items.len()
+        }
+
+        let mut slab = make_stack_slab::<u16, 4>();
+        // SAFETY: `enumerate` writes as many items as it returns, up to the capacity it's given
+        unsafe {
+            let items = readback_sized_from_ffi::<u16, _, _, _>(
+                &mut slab,
+                || enumerate(core::ptr::null_mut(), 0),
+                |ptr, capacity| enumerate(ptr.cast(), capacity),
+            );
+            assert_eq!(items, Ok(&[1, 2, 3][..]));
+
+            let too_many =
+                readback_sized_from_ffi::<u16, _, _, _>(&mut slab, || 5, |_, _| unreachable!());
+            assert_eq!(too_many, Err(Error::out_of_memory(0, 10, 8)));
+
+            let overrun = readback_sized_from_ffi::<u16, _, _, _>(&mut slab, || 1, |_, _| 2);
+            assert_eq!(overrun, Err(Error::out_of_memory(0, 4, 8)));
+        }
+    }
+
+    #[test]
+    fn readback_out_count() {
+        let mut slab = make_stack_slab::<u32, 4>();
+        // SAFETY: three `u32`s are written and reported through the count
+        let values = unsafe {
+            readback_slice_from_ffi_out_count::<u32, _, u32, _>(&mut slab, |ptr, size, count| {
+                assert_eq!(size, 12);
+                let ptr = ptr.cast::<u32>();
+                for i in 0..3 {
+                    ptr.add(i).write(i as u32 * 10);
+                }
+                *count = 3;
+            })
+        };
+        assert_eq!(values, Ok(&[0, 10, 20][..]));
+
+        // SAFETY: nothing is read since the count doesn't fit
+        let too_many = unsafe {
+            readback_slice_from_ffi_out_count::<u32, _, usize, _>(&mut slab, |_, _, count| {
+                *count = 5;
+            })
+        };
+        assert_eq!(too_many, Err(Error::out_of_memory(0, 20, 16)));
+    }
+
+    #[test]
+    fn readback_max_elements() {
+        let mut slab = make_stack_slab::<u8, 15>();
+        let offset = slab.base_ptr().align_offset(core::mem::align_of::<u32>());
+        let expected_max = (15 - offset) / 4;
+
+        // SAFETY: as many `u32`s as fit are written
+        let values = unsafe {
+            readback_slice_from_ffi_max_elements::<u32, _, _>(&mut slab, |ptr, max_elements| {
+                assert_eq!(max_elements, expected_max);
+                let ptr = ptr.cast::<u32>();
+                for i in 0..max_elements {
+                    ptr.add(i).write(7);
+                }
+                max_elements
+            })
+        };
+        assert_eq!(values.map(<[u32]>::len), Ok(expected_max));
+
+        // SAFETY: nothing is read since the length is greater than the maximum
+        let too_many = unsafe {
+            readback_slice_from_ffi_max_elements::<u32, _, _>(&mut slab, |_, max_elements| {
+                max_elements + 1
+            })
+        };
+        assert_eq!(too_many.map_err(|e| e.kind()), Err(ErrorKind::OutOfMemory));
+    }
+
+    #[test]
+    fn readback_mut_fixup() {
+        let mut slab = make_stack_slab::<u32, 2>();
+        // SAFETY: a `u32` is written to the given pointer
+        let value = unsafe {
+            readback_from_ffi_mut::<u32, _, _>(&mut slab, |ptr| {
+                ptr.cast::<u32>().write(0x1234_5678u32.to_be());
+            })
+        }
+        .unwrap();
+        *value = u32::from_be(*value);
+        assert_eq!(*value, 0x1234_5678);
+    }
+
+    #[test]
+    fn readback_terminated_slice() {
+        let mut slab = make_stack_slab::<u64, 4>();
+        // SAFETY: two handles followed by the zero sentinel are written
+        let handles = unsafe {
+            readback_terminated_slice_from_ffi::<u64, _, _>(&mut slab, 0, |ptr, max_elements| {
+                assert_eq!(max_elements, 4);
+                let ptr = ptr.cast::<u64>();
+                ptr.write(5);
+                ptr.add(1).write(9);
+                ptr.add(2).write(0);
+            })
+        };
+        assert_eq!(handles, Ok(&[5, 9][..]));
+
+        // SAFETY: every element is written, none of them is the sentinel
+        let unterminated = unsafe {
+            readback_terminated_slice_from_ffi::<u64, _, _>(&mut slab, 0, |ptr, max_elements| {
+                for i in 0..max_elements {
+                    ptr.cast::<u64>().add(i).write(1);
+                }
+            })
+        };
+        assert_eq!(
+            unterminated.map_err(|e| e.kind()),
+            Err(ErrorKind::OutOfMemory)
+        );
+    }
+
+    #[test]
+    fn readback_length_prefixed() {
+        #[derive(Clone, Copy)]
+        #[repr(C)]
+        struct Header {
+            flags: u16,
+            count: u16,
+        }
+
+        let mut slab = make_stack_slab::<u64, 4>();
+        // SAFETY: a header is written, followed by as many `u64`s as it reports
+        let (header, elements) = unsafe {
+            readback_length_prefixed_from_ffi::<Header, u64, _, _, _>(
+                &mut slab,
+                |header| header.count as usize,
+                |ptr, size| {
+                    assert_eq!(size, 32);
+                    ptr.cast::<Header>().write(Header { flags: 1, count: 2 });
+                    let elements = ptr.cast::<u8>().add(8).cast::<u64>();
+                    elements.write(10);
+                    elements.add(1).write(20);
+                },
+            )
+        }
+        .unwrap();
+        assert_eq!(header.flags, 1);
+        assert_eq!(elements, &[10, 20]);
+
+        // SAFETY: nothing past the header is read since the count doesn't fit
+        let too_many = unsafe {
+            readback_length_prefixed_from_ffi::<Header, u64, _, _, _>(
+                &mut slab,
+                |header| header.count as usize,
+                |ptr, _| ptr.cast::<Header>().write(Header { flags: 0, count: 4 }),
+            )
+        };
+        assert_eq!(too_many.map(|_| ()), Err(Error::out_of_memory(0, 40, 32)));
+    }
+
+    #[test]
+    fn read_cstr() {
+        let mut slab = make_stack_slab::<u8, 16>();
+        copy_from_slice_to_offset(b"albedo\0normal\0ao", &mut slab, 0).unwrap();
+
+        // SAFETY: every byte of the slab was initialized above
+        unsafe {
+            let name = read_cstr_at_offset(&slab, 7).unwrap();
+            assert_eq!(name.to_bytes(), b"normal");
+            assert_eq!(read_cstr_at_offset(&slab, 6).unwrap().to_bytes(), b"");
+            assert_eq!(
+                read_cstr_at_offset(&slab, 14).map_err(|e| e.kind()),
+                Err(ErrorKind::OutOfMemory)
+            );
+            assert_eq!(
+                read_cstr_at_offset(&slab, 17).map_err(|e| e.kind()),
+                Err(ErrorKind::OffsetOutOfBounds)
+            );
+        }
+    }
+
+    #[test]
+    fn readback_flexible_array_member() {
+        #[derive(Clone, Copy)]
+        #[repr(C)]
+        struct Header {
+            kind: u8,
+            count: u8,
+        }
+
+        let mut slab = make_stack_slab::<u32, 4>();
+        // SAFETY: a header is written, followed by the returned number of `u32`s
+        let (header, elements) = unsafe {
+            readback_header_and_slice_from_ffi::<Header, u32, _, _>(
+                &mut slab,
+                |ptr, max_elements| {
+                    assert_eq!(max_elements, 3);
+                    ptr.cast::<Header>().write(Header { kind: 7, count: 3 });
+                    let elements = ptr.cast::<u8>().add(4).cast::<u32>();
+                    for i in 0..3 {
+                        elements.add(i).write(i as u32);
+                    }
+                    3
+                },
+            )
+        }
+        .unwrap();
+        assert_eq!((header.kind, header.count), (7, 3));
+        assert_eq!(elements, &[0, 1, 2]);
+
+        // SAFETY: nothing past the header is read since the count doesn't fit
+        let too_many = unsafe {
+            readback_header_and_slice_from_ffi::<Header, u32, _, _>(&mut slab, |ptr, _| {
+                ptr.cast::<Header>().write(Header { kind: 0, count: 4 });
+                4
+            })
+        };
+        assert_eq!(too_many.map(|_| ()), Err(Error::out_of_memory(0, 20, 16)));
+    }
+
+    #[test]
+    fn zero_sized_and_empty_copies() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[repr(align(8))]
+        struct Marker;
+
+        let mut slab = make_stack_slab::<u64, 2>();
+
+        // zero-sized copies get an aligned, empty record, even at the very end of the slab
+        let record = copy_to_offset(&Marker, &mut slab, 3).unwrap();
+        assert_eq!((record.start_offset, record.end_offset), (8, 8));
+        assert!(record.is_empty());
+        let record = copy_to_offset_exact(&(), &mut slab, 16).unwrap();
+        assert_eq!((record.start_offset, record.end_offset), (16, 16));
+        assert_eq!(
+            copy_to_offset(&Marker, &mut slab, 17).map_err(|e| e.kind()),
+            Err(ErrorKind::OffsetOutOfBounds)
+        );
+
+        // as do empty slices
+        let record = copy_from_slice_to_offset::<u32, _>(&[], &mut slab, 5).unwrap();
+        assert_eq!((record.start_offset, record.end_offset), (8, 8));
+        let record = copy_from_slice_to_offset(&[Marker; 4], &mut slab, 0).unwrap();
+        assert!(record.is_empty());
+
+        // empty iterators copy nothing at all
+        let empty = copy_from_iter_to_offset_with_align_with_callback(
+            core::iter::empty::<u32>(),
+            &mut slab,
+            0,
+            1,
+            |_| unreachable!(),
+        );
+        assert_eq!(empty, Ok(None));
+        let packed =
+            copy_from_iter_to_offset_with_align_packed(core::iter::empty::<u32>(), &mut slab, 0, 1);
+        assert_eq!(packed, Ok(None));
+        let zsts = copy_from_iter_to_offset_with_align_with_callback(
+            [(); 3].into_iter(),
+            &mut slab,
+            16,
+            1,
+            |record| assert_eq!((record.start_offset, record.end_offset), (16, 16)),
+        );
+        assert!(zsts.unwrap().unwrap().is_empty());
+
+        // SAFETY: zero-sized values and empty slices are always initialized
+        unsafe {
+            assert_eq!(read_at_offset::<Marker, _>(&slab, 16), Ok(&Marker));
+            assert_eq!(
+                read_slice_at_offset::<Marker, _>(&slab, 8, 100),
+                Ok(&[Marker; 100][..])
+            );
+            assert_eq!(read_slice_at_offset::<u64, _>(&slab, 16, 0), Ok(&[][..]));
+
+            // unless the length can't be bounded by the slab
+            let terminated = readback_terminated_slice_from_ffi::<Marker, _, _>(
+                &mut slab,
+                Marker,
+                |_, _| unreachable!(),
+            );
+            assert_eq!(terminated, Err(ErrorKind::ZeroSizedType.into()));
+        }
+    }
+
+    #[test]
+    fn strict_alignment() {
+        let mut slab = make_stack_slab::<u8, 64>();
+
+        let record = copy_to_offset_with_align_strict(&1u8, &mut slab, 1, 16).unwrap();
+        assert_eq!((crate::addr(slab.base_ptr()) + record.start_offset) % 16, 0);
+        for stride in [0, 12, 24] {
+            assert_eq!(
+                copy_to_offset_with_align_strict(&1u8, &mut slab, 0, stride),
+                Err(ErrorKind::InvalidAlignment.into())
+            );
+            assert_eq!(
+                copy_from_slice_to_offset_with_align_strict(&[1u8; 4], &mut slab, 0, stride),
+                Err(ErrorKind::InvalidAlignment.into())
+            );
+            assert_eq!(
+                CopyOptions::new(&1u8)
+                    .min_align(stride)
+                    .strict_align()
+                    .copy_to(&mut slab),
+                Err(ErrorKind::InvalidAlignment.into())
+            );
+        }
+    }
+
+    #[test]
+    fn copy_bytes_with_runtime_layout() {
+        let mut slab = make_stack_slab::<u64, 4>();
+        let layout = core::alloc::Layout::from_size_align(12, 8).unwrap();
+        let bytes = [7u8; 12];
+
+        let record = copy_bytes_to_offset_with_layout(&bytes, layout, &mut slab, 1).unwrap();
+        assert_eq!(
+            (
+                record.start_offset,
+                record.end_offset,
+                record.end_offset_padded
+            ),
+            (8, 20, 24)
+        );
+        // SAFETY: the bytes were copied there above
+        let copied = unsafe { read_slice_at_offset::<u8, _>(&slab, 8, 12) };
+        assert_eq!(copied, Ok(&bytes[..]));
+
+        assert_eq!(
+            copy_bytes_to_offset_with_layout(&bytes[..4], layout, &mut slab, 0),
+            Err(ErrorKind::InvalidLayout.into())
+        );
+        assert_eq!(
+            copy_bytes_to_offset_with_layout(&bytes, layout, &mut slab, 24).map_err(|e| e.kind()),
+            Err(ErrorKind::OutOfMemory)
+        );
+    }
+
+    #[test]
+    fn copy_reflected_fields() {
+        let fields = [
+            FieldPlacement {
+                src_field_offset: 4,
+                src_size: 4,
+                dst_offset: 8,
+            },
+            FieldPlacement {
+                src_field_offset: 0,
+                src_size: 2,
+                dst_offset: 0,
+            },
+        ];
+        let table = FieldTable::new(&fields).unwrap();
+        assert_eq!((table.src_size(), table.dst_size()), (8, 12));
+
+        let mut slab = make_stack_slab::<u8, 16>();
+        zero_range(&mut slab, ..).unwrap();
+        let src = [1, 2, 3, 4, 5, 6, 7, 8];
+        let record = copy_fields_to_offset(&src, &table, &mut slab, 4).unwrap();
+        assert_eq!((record.start_offset, record.end_offset), (4, 16));
+        // SAFETY: the whole slab was zeroed above
+        let packed = unsafe { read_slice_at_offset::<u8, _>(&slab, 4, 12) };
+        assert_eq!(packed, Ok(&[1, 2, 0, 0, 0, 0, 0, 0, 5, 6, 7, 8][..]));
+
+        assert_eq!(
+            copy_fields_to_offset(&src[..6], &table, &mut slab, 0),
+            Err(Error::out_of_memory(0, 8, 6))
+        );
+        assert_eq!(
+            copy_fields_to_offset(&src, &table, &mut slab, 5),
+            Err(Error::out_of_memory(5, 17, 16))
+        );
+        assert_eq!(
+            FieldTable::new(&[FieldPlacement {
+                src_field_offset: usize::MAX,
+                src_size: 1,
+                dst_offset: 0,
+            }]),
+            Err(ErrorKind::InvalidLayout.into())
+        );
+    }
+
+    /// An empty slab which returns a null base pointer, as allowed by the `Slab` contract.
+    struct NullSlab;
+
+    // SAFETY: the slab is empty, in which case its base pointer may be null.
+    unsafe impl Slab for NullSlab {
+        fn base_ptr(&self) -> *const u8 {
+            core::ptr::null()
+        }
+
+        fn base_ptr_mut(&mut self) -> *mut u8 {
+            core::ptr::null_mut()
+        }
+
+        fn size(&self) -> usize {
+            0
+        }
+    }
+
+    #[test]
+    fn null_empty_slab() {
+        let mut slab = NullSlab;
+        assert!(slab.as_maybe_uninit_bytes().is_empty());
+        assert!(slab.as_maybe_uninit_bytes_mut().is_empty());
+        assert!(slab.initialize_all_zeroed().is_empty());
+        assert_eq!(slab.try_as_ffi_buffer(..).unwrap().1, 0);
+        assert!(slab.has_room_for::<()>(0, 1));
+        assert!(!slab.has_room_for::<u8>(0, 1));
+
+        copy_from_slice_to_offset::<u8, _>(&[], &mut slab, 0).unwrap();
+        assert_eq!(
+            copy_to_offset(&1u8, &mut slab, 0),
+            Err(Error::out_of_memory(0, 1, 0))
+        );
+        // SAFETY: reading no elements is always valid.
+        let read = unsafe { read_slice_at_offset::<u8, _>(&slab, 0, 0) };
+        assert_eq!(read.unwrap(), &[]);
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    #[test]
+    fn concurrent_writer_on_empty_slab() {
+        let mut slab = NullSlab;
+        let writer = ConcurrentWriter::new(&mut slab);
+        assert_eq!(writer.copy_slice::<u8>(&[], 1).unwrap().start_offset, 0);
+        assert_eq!(writer.copy(&1u8, 1), Err(Error::out_of_memory(0, 1, 0)));
+        assert_eq!(writer.finish(), 0);
+    }
+
+    #[cfg(all(target_has_atomic = "ptr", feature = "std"))]
+    #[test]
+    fn concurrent_writer_claims_disjoint_ranges() {
+        let mut slab = make_stack_slab::<u64, 64>();
+        let writer = ConcurrentWriter::new(&mut slab);
+
+        let mut records = std::thread::scope(|s| {
+            let threads = (0..4u32)
+                .map(|thread| {
+                    let writer = &writer;
+                    s.spawn(move || {
+                        (0..4)
+                            .map(|i| {
+                                let value = [thread * 16 + i; 3];
+                                (value, writer.copy_slice(&value, 8).unwrap())
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            threads
+                .into_iter()
+                .flat_map(|thread| thread.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(writer.finish(), 15 * 16 + 12);
+
+        records.sort_by_key(|(_, record)| record.start_offset);
+        for pair in records.windows(2) {
+            assert!(pair[0].1.end_offset <= pair[1].1.start_offset);
+        }
+        for (value, record) in records {
+            assert_eq!(record.start_offset % 8, 0);
+            // SAFETY: `value` was copied to `record.start_offset`.
+            let read = unsafe { read_slice_at_offset::<u32, _>(&slab, record.start_offset, 3) };
+            assert_eq!(read.unwrap(), &value);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn broadcast_to_borrowed_slabs() {
+        let mut stack = make_stack_slab::<u32, 4>();
+        let mut heap = HeapSlab::new(core::alloc::Layout::new::<[u8; 8]>());
+        let mut bytes = [core::mem::MaybeUninit::<u8>::uninit(); 9];
+        let mut dsts = [
+            stack.as_maybe_uninit_bytes_mut(),
+            heap.as_maybe_uninit_bytes_mut(),
+            &mut bytes[1..],
+        ];
+
+        let records = broadcast_to_offsets(&0x0102_0304u32, &mut dsts, 1, 1).unwrap();
+        for (dst, record) in dsts.iter().zip(&records) {
+            assert!(record.start_offset >= 1);
+            // SAFETY: a `u32` was just copied to `record.start_offset`
+            let read = unsafe { read_at_offset::<u32, _>(&**dst, record.start_offset) };
+            assert_eq!(read.unwrap(), &0x0102_0304);
+        }
+
+        // doesn't fit in the heap slab, so nothing is written to any of them
+        assert_eq!(
+            broadcast_to_offsets(&0u64, &mut dsts, 8, 1)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::OutOfMemory
+        );
+        // SAFETY: a `u32` was copied to `records[0].start_offset` above
+        let read = unsafe { read_at_offset::<u32, _>(&*dsts[0], records[0].start_offset) };
+        assert_eq!(read.unwrap(), &0x0102_0304);
+
+        // borrowed slabs of any type work too
+        let mut slabs = [
+            &mut heap,
+            &mut HeapSlab::new(core::alloc::Layout::new::<u32>()),
+        ];
+        assert_eq!(
+            broadcast_to_offsets(&1u32, &mut slabs, 0, 4).unwrap().len(),
+            2
+        );
+    }
+
+    /// An iterator which reports a length of `len`, but yields `yielded` items.
+    struct MisreportedLen {
+        len: usize,
+        yielded: core::ops::Range<u32>,
+    }
+
+    impl Iterator for MisreportedLen {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<u32> {
+            self.yielded.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+    }
+
+    impl ExactSizeIterator for MisreportedLen {}
+
+    #[test]
+    fn exact_iter_copies() {
+        let mut slab = make_stack_slab::<u32, 4>();
+        copy_from_slice_to_offset(&[7u32; 4], &mut slab, 0).unwrap();
+
+        // doesn't fit, so nothing is written at all
+        assert_eq!(
+            copy_from_exact_iter_to_offset(0..4u32, &mut slab, 4),
+            Err(Error::out_of_memory(4, 20, 16))
+        );
+        // SAFETY: the whole slab was initialized with `u32`s
+        let read = unsafe { read_slice_at_offset::<u32, _>(&slab, 0, 4) }.unwrap();
+        assert_eq!(read, &[7; 4]);
+
+        let record = copy_from_exact_iter_to_offset(1..4u32, &mut slab, 4).unwrap();
+        assert_eq!(record.end_offset, 16);
+
+        // too many items: the ones past the reported length are ignored
+        let long = MisreportedLen {
+            len: 2,
+            yielded: 10..20,
+        };
+        let record = copy_from_exact_iter_to_offset(long, &mut slab, 0).unwrap();
+        assert_eq!((record.start_offset, record.end_offset), (0, 8));
+
+        // too few items: the record only covers the ones which were copied
+        let short = MisreportedLen {
+            len: 4,
+            yielded: 20..22,
+        };
+        let record = copy_from_exact_iter_to_offset(short, &mut slab, 0).unwrap();
+        assert_eq!((record.end_offset, record.end_offset_padded), (8, 8));
+
+        // SAFETY: the whole slab was initialized with `u32`s
+        let read = unsafe { read_slice_at_offset::<u32, _>(&slab, 0, 4) }.unwrap();
+        assert_eq!(read, &[20, 21, 2, 3]);
+    }
+
+    #[test]
+    fn dynamic_offsets() {
+        let alignments = DeviceAlignments {
+            min_uniform_offset: 64,
+            min_storage_offset: 16,
+            non_coherent_atom_size: 32,
+        };
+        assert_eq!(alignments.offset_alignment(DynamicBufferKind::Uniform), 64);
+        assert_eq!(
+            alignments.align_offset(1, DynamicBufferKind::Uniform),
+            Some(64)
+        );
+        assert_eq!(
+            alignments.align_offset(64, DynamicBufferKind::Uniform),
+            Some(64)
+        );
+        assert_eq!(
+            alignments.align_offset(17, DynamicBufferKind::Storage),
+            Some(32)
+        );
+        assert_eq!(
+            alignments.align_offset(usize::MAX, DynamicBufferKind::Storage),
+            None
+        );
+        // a limit of 0 is treated as no requirement
+        let unaligned = DeviceAlignments {
+            min_storage_offset: 0,
+            ..alignments
+        };
+        assert_eq!(
+            unaligned.align_offset(3, DynamicBufferKind::Storage),
+            Some(3)
+        );
+
+        let mut slab = AlignedStackSlab::<256, 64>::new();
+        let uniform =
+            copy_for_dynamic_offset(&1u32, &mut slab, 4, &alignments, DynamicBufferKind::Uniform)
+                .unwrap();
+        assert_eq!(uniform.record.start_offset, 64);
+        assert_eq!(uniform.dynamic_offset, 64);
+
+        let storage = copy_from_slice_for_dynamic_offset(
+            &[2u32, 3, 4],
+            &mut slab,
+            uniform.record.end_offset,
+            &alignments,
+            DynamicBufferKind::Storage,
+        )
+        .unwrap();
+        assert_eq!(storage.dynamic_offset, 80);
+        assert_eq!(storage.record.end_offset, 92);
+
+        // the aligned offset must also be aligned for `T` itself
+        assert_eq!(
+            copy_for_dynamic_offset(&5u64, &mut slab, 3, &unaligned, DynamicBufferKind::Storage)
+                .unwrap_err(),
+            Error::requested_offset_unaligned()
+        );
+        assert_eq!(
+            copy_for_dynamic_offset(
+                &[0u8; 64],
+                &mut slab,
+                200,
+                &alignments,
+                DynamicBufferKind::Uniform
+            )
+            .unwrap_err()
+            .kind(),
+            ErrorKind::OutOfMemory
+        );
+
+        assert_eq!(
+            alignments.atom_aligned_range(storage.record.range(), 100),
+            Ok(64..96)
+        );
+        assert_eq!(alignments.atom_aligned_range(70..75, 80), Ok(64..80));
+        assert_eq!(
+            alignments
+                .atom_aligned_range(70..90, 80)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::OffsetOutOfBounds
+        );
+    }
+
+    #[cfg(all(feature = "poison", feature = "std"))]
+    #[test]
+    fn broadcast_poisons_alignment_gaps() {
+        let mut a = make_stack_slab::<u32, 2>();
+        let mut b = make_stack_slab::<u32, 2>();
+        a.initialize_all_zeroed();
+        b.initialize_all_zeroed();
+        let mut dsts = [&mut a, &mut b];
+
+        let records = broadcast_to_offsets(&1u32, &mut dsts[..], 1, 1).unwrap();
+        for (dst, record) in dsts.iter().zip(&records) {
+            assert_eq!(record.start_offset, 4);
+            // SAFETY: the whole slab was initialized above
+            let bytes = unsafe { read_slice_at_offset::<u8, _>(&**dst, 0, 4) }.unwrap();
+            assert_eq!(bytes, &[0, POISON_BYTE, POISON_BYTE, POISON_BYTE]);
+        }
+    }
+
+    #[test]
+    fn scalar_writers() {
+        let mut slab = make_stack_slab::<u8, 16>();
+        slab.initialize_all_zeroed();
+
+        let next = write_u16_le_at(0x0102, &mut slab, 1).unwrap();
+        assert_eq!(next, 3);
+        let next = write_u32_be_at(0x0304_0506, &mut slab, next).unwrap();
+        assert_eq!(next, 7);
+        let next = write_scalar_at(-2i16, &mut slab, next, Endianness::Little).unwrap();
+        assert_eq!(next, 9);
+        let next = write_f32_be_at(1.0, &mut slab, next).unwrap();
+        assert_eq!(next, 13);
+        let next = write_u16_at(0x0708, &mut slab, next).unwrap();
+        assert_eq!(next, 15);
+        // SAFETY: the whole slab was initialized above
+        let bytes = unsafe { read_slice_at_offset::<u8, _>(&slab, 0, 16) }.unwrap();
+        assert_eq!(
+            bytes[..13],
+            [0, 2, 1, 3, 4, 5, 6, 0xfe, 0xff, 0x3f, 0x80, 0, 0]
+        );
+        assert_eq!(bytes[13..15], 0x0708u16.to_ne_bytes());
+
+        // a write ending exactly at the end of the slab fits
+        assert_eq!(write_u8_be_at(9, &mut slab, 15), Ok(16));
+        assert_eq!(
+            write_u16_le_at(0, &mut slab, 15),
+            Err(Error::out_of_memory(15, 17, 16))
+        );
+        assert_eq!(
+            write_u64_be_at(0, &mut slab, 17).unwrap_err().kind(),
+            ErrorKind::OffsetOutOfBounds
+        );
+    }
+
+    #[test]
+    fn fill_copies() {
+        let mut slab = make_stack_slab::<u32, 8>();
+
+        let record = fill_at_offset(&7u32, 3, &mut slab, 1, 1).unwrap();
+        assert_eq!(record.range(), 4..16);
+        // SAFETY: three `u32`s were just written at `record.start_offset`
+        let filled = unsafe { read_slice_at_offset::<u32, _>(&slab, 4, 3) }.unwrap();
+        assert_eq!(filled, &[7, 7, 7]);
+
+        let record = fill_at_offset(&9u16, 2, &mut slab, 17, 4).unwrap();
+        assert_eq!(record.range(), 20..24);
+        // SAFETY: two `u16`s were just written at `record.start_offset`
+        let filled = unsafe { read_slice_at_offset::<u16, _>(&slab, 20, 2) }.unwrap();
+        assert_eq!(filled, &[9, 9]);
+
+        let record = fill_at_offset(&1u32, 0, &mut slab, 32, 1).unwrap();
+        assert!(record.is_empty());
+        assert_eq!(record.start_offset, 32);
+
+        assert_eq!(
+            fill_at_offset(&1u32, 5, &mut slab, 16, 1),
+            Err(Error::out_of_memory(16, 36, 32))
+        );
+    }
+
+    #[test]
+    fn copies_between_slabs() {
+        use core::ops::Bound;
+
+        let mut src = make_stack_slab::<u8, 8>();
+        copy_from_slice_to_offset(&[0u8, 1, 2, 3, 4, 5, 6, 7], &mut src, 0).unwrap();
+        let mut dst = make_stack_slab::<u32, 4>();
+
+        let mut check = |range: (Bound<usize>, Bound<usize>), expected: &[u8]| {
+            let record = copy_between_slabs(&src, range, &mut dst, 1).unwrap();
+            assert_eq!(record.range(), 1..1 + expected.len());
+            // SAFETY: the bytes were just copied to `record.start_offset`
+            let copied = unsafe { read_slice_at_offset::<u8, _>(&dst, 1, expected.len()) };
+            assert_eq!(copied.unwrap(), expected);
+        };
+        check((Bound::Included(2), Bound::Included(4)), &[2, 3, 4]);
+        check((Bound::Excluded(1), Bound::Excluded(4)), &[2, 3]);
+        check((Bound::Unbounded, Bound::Excluded(2)), &[0, 1]);
+        check((Bound::Included(5), Bound::Unbounded), &[5, 6, 7]);
+        check(
+            (Bound::Unbounded, Bound::Unbounded),
+            &[0, 1, 2, 3, 4, 5, 6, 7],
+        );
+        check((Bound::Included(8), Bound::Unbounded), &[]);
+
+        // the source range is out of bounds
+        assert_eq!(
+            copy_between_slabs(&src, 4..9, &mut dst, 0),
+            Err(Error::out_of_memory(4, 9, 8))
+        );
+        assert_eq!(
+            copy_between_slabs(&src, 9.., &mut dst, 0)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::OffsetOutOfBounds
+        );
+        // the destination range is out of bounds
+        assert_eq!(
+            copy_between_slabs(&src, .., &mut dst, 9),
+            Err(Error::out_of_memory(9, 17, 16))
+        );
+
+        let record = copy_between_slabs_with_align(&src, 1..=3, &mut dst, 5, 4).unwrap();
+        assert_eq!(record.range(), 8..11);
+        // SAFETY: the bytes were just copied to `record.start_offset`
+        let copied = unsafe { read_slice_at_offset::<u8, _>(&dst, 8, 3) }.unwrap();
+        assert_eq!(copied, &[1, 2, 3]);
+        assert_eq!(
+            copy_between_slabs_with_align(&src, .., &mut dst, 10, 4),
+            Err(Error::out_of_memory(10, 20, 16))
+        );
+    }
+
+    #[test]
+    fn plans_match_copies() {
+        let mut slab = make_stack_slab::<u32, 16>();
+
+        let plan = plan_copy::<u32, _>(&slab, 1, 1);
+        assert_eq!(plan, copy_to_offset_with_align(&1u32, &mut slab, 1, 1));
+        assert_eq!(plan.unwrap().range(), 4..8);
+
+        let plan = plan_copy_exact::<u16, _>(&slab, 10, 2);
+        assert_eq!(
+            plan,
+            copy_to_offset_with_align_exact(&1u16, &mut slab, 10, 2)
+        );
+        let plan = plan_copy_exact::<u32, _>(&slab, 10, 1);
+        assert_eq!(plan, Err(Error::requested_offset_unaligned()));
+        assert_eq!(
+            plan,
+            copy_to_offset_with_align_exact(&1u32, &mut slab, 10, 1)
+        );
+
+        let layout = core::alloc::Layout::array::<u16>(3).unwrap();
+        let plan = plan_copy_for_layout(&slab, layout, 13, 4);
+        let record = copy_from_slice_to_offset_with_align(&[1u16, 2, 3], &mut slab, 13, 4);
+        assert_eq!(plan, record);
+        assert_eq!(plan.unwrap().end_offset_padded, 24);
+        let raw_plan = plan_copy_for_raw_parts(crate::addr(slab.base_ptr()), 64, layout, 13, 4);
+        assert_eq!(raw_plan, plan);
+
+        // a copy which doesn't fit is planned to fail the same way
+        let plan = plan_copy::<u32, _>(&slab, 62, 1);
+        assert_eq!(plan, Err(Error::out_of_memory(62, 68, 64)));
+        assert_eq!(plan, copy_to_offset_with_align(&1u32, &mut slab, 62, 1));
+    }
+
+    #[test]
+    fn required_sizes() {
+        let mut slab = make_stack_slab::<u32, 8>();
+
+        let required = required_size_for_slice::<u16>(3, 4).unwrap();
+        assert_eq!(required, 8);
+        let values = [1u16, 2, 3];
+        let bytes = slab.as_maybe_uninit_bytes_mut();
+        let record = copy_from_slice_to_offset_with_align(&values, &mut bytes[..required], 0, 4);
+        assert_eq!(record.unwrap().end_offset_padded, required);
+        assert_eq!(
+            copy_from_slice_to_offset_with_align(&values, &mut bytes[..required - 1], 0, 4),
+            Err(Error::out_of_memory(0, 8, 7))
+        );
+
+        #[cfg(feature = "std")]
+        {
+            let required = required_size_for_iter::<u16>(3, 4).unwrap();
+            assert_eq!(required, 12);
+            let records =
+                crate::copy_from_iter_to_offset_with_align(values.into_iter(), bytes, 0, 4);
+            assert_eq!(records.unwrap()[2].end_offset_padded, required);
+        }
+
+        assert_eq!(
+            required_size_for_slice::<u64>(usize::MAX / 4, 1),
+            Err(Error::invalid_layout())
+        );
+        assert_eq!(
+            required_size_for_iter::<u64>(usize::MAX / 4, 1),
+            Err(Error::invalid_layout())
+        );
+    }
+
+    #[test]
+    fn copy_record_helpers() {
+        let a = CopyRecord {
+            start_offset: 2,
+            end_offset: 5,
+            end_offset_padded: 6,
+        };
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.padded_len(), 4);
+        assert_eq!(a.range(), 2..5);
+        assert!(!a.is_empty());
+
+        assert_eq!(a.next_offset_for::<u8>(1), Some(5));
+        assert_eq!(a.next_offset_for::<u32>(1), Some(8));
+        assert_eq!(a.next_offset_for::<u8>(3), Some(8));
+        assert_eq!(a.next_offset_for::<u16>(16), Some(16));
+        let at_end = CopyRecord {
+            start_offset: 0,
+            end_offset: usize::MAX - 1,
+            end_offset_padded: usize::MAX - 1,
+        };
+        assert_eq!(at_end.next_offset_for::<u8>(1), Some(usize::MAX - 1));
+        assert_eq!(at_end.next_offset_for::<u32>(1), None);
+
+        let adjacent = CopyRecord {
+            start_offset: 6,
+            end_offset: 8,
+            end_offset_padded: 8,
+        };
+        let merged = CopyRecord {
+            start_offset: 2,
+            end_offset: 8,
+            end_offset_padded: 8,
+        };
+        assert_eq!(a.merge(adjacent), merged);
+        assert_eq!(adjacent.merge(a), merged);
+
+        // the gap between the records is included
+        let apart = CopyRecord {
+            start_offset: 12,
+            end_offset: 14,
+            end_offset_padded: 16,
+        };
+        let merged = CopyRecord {
+            start_offset: 2,
+            end_offset: 14,
+            end_offset_padded: 16,
+        };
+        assert_eq!(a.merge(apart), merged);
+        assert_eq!(apart.merge(a), merged);
+        assert_eq!(merged.len(), 12);
+        assert_eq!(merged.padded_len(), 14);
+    }
+
+    #[test]
+    fn lazy_iter_copies() {
+        let mut slab = make_stack_slab::<u32, 4>();
+        let mut copies = copy_iter_records([1u16, 2, 3, 4, 5].into_iter(), &mut slab, 1, 4);
+        assert_eq!(copies.next_offset(), 1);
+
+        let mut starts = [0; 3];
+        for start in &mut starts {
+            let record = copies.next().unwrap().unwrap();
+            assert_eq!(record.len(), 2);
+            assert_eq!(copies.next_offset(), record.end_offset);
+            *start = record.start_offset;
+        }
+        assert_eq!(starts, [4, 8, 12]);
+
+        // the slab runs out with items left, so the error is yielded once and the iterator ends
+        assert_eq!(copies.next(), Some(Err(Error::out_of_memory(14, 20, 16))));
+        assert_eq!(copies.next_offset(), 14);
+        assert_eq!(copies.size_hint(), (0, Some(0)));
+        assert_eq!(copies.next(), None);
+
+        for (start, value) in starts.into_iter().zip(1u16..) {
+            // SAFETY: the values were copied to these offsets above
+            assert_eq!(
+                unsafe { read_at_offset::<u16, _>(&slab, start) },
+                Ok(&value)
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn copies_from_slices() {
+        let mut slab = make_stack_slab::<u32, 8>();
+
+        let (record, offsets) =
+            copy_from_slices_to_offset(&[&[1u16, 2][..], &[], &[3], &[4, 5]], &mut slab, 1)
+                .unwrap();
+        assert_eq!(record.range(), 2..12);
+        assert_eq!(offsets, [2, 6, 6, 8]);
+        // SAFETY: five `u16`s were just copied to `record.start_offset`
+        let copied = unsafe { read_slice_at_offset::<u16, _>(&slab, 2, 5) }.unwrap();
+        assert_eq!(copied, &[1, 2, 3, 4, 5]);
+
+        let (record, offsets) =
+            copy_from_slices_to_offset_with_align::<u8, _>(&[&[], &[]], &mut slab, 13, 4).unwrap();
+        assert_eq!(record.range(), 16..16);
+        assert_eq!(offsets, [16, 16]);
+
+        let (record, offsets) =
+            copy_from_slices_to_offset_with_align(&[&[6u8][..], &[7, 8]], &mut slab, 13, 4)
+                .unwrap();
+        assert_eq!(record.range(), 16..19);
+        assert_eq!(offsets, [16, 17]);
+
+        // the slices fit on their own, but not one after another
+        assert_eq!(
+            copy_from_slices_to_offset(&[&[0u32; 4][..], &[0; 4]], &mut slab, 4),
+            Err(Error::out_of_memory(4, 36, 32))
+        );
+    }
+
+    #[cfg(feature = "memmap")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri doesn't support memory-mapping files
+    fn file_slab_round_trip() {
+        let path = std::env::temp_dir().join(format!("presser-file-slab-{}", std::process::id()));
+
+        // SAFETY: the file was just created by this test and isn't accessed while it's mapped.
+        let mut slab = unsafe { FileSlab::create(&path, 16) }.unwrap();
+        assert_eq!(slab.size(), 16);
+        let record = copy_from_slice_to_offset(&[1u32, 2, 3], &mut slab, 1).unwrap();
+        assert_eq!(record.range(), 4..16);
+        slab.flush().unwrap();
+        drop(slab);
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 16);
+
+        // SAFETY: the file isn't accessed while it's mapped.
+        let slab = unsafe { FileSlab::open(&path) }.unwrap();
+        // SAFETY: three `u32`s were written to the file at offset 4 above.
+        let values = unsafe { read_slice_at_offset::<u32, _>(&slab, 4, 3) };
+        assert_eq!(values.unwrap(), &[1, 2, 3]);
+        drop(slab);
+
+        std::fs::remove_file(&path).unwrap();
+    }
 }
@@ -0,0 +1,144 @@
+use super::*;
+
+/// An extension of [`Slab`] for mapped memory which may not be host-coherent, i.e. memory for
+/// which host writes must be explicitly flushed before they're visible to the device, and device
+/// writes must be explicitly invalidated before they're visible to the host.
+///
+/// In Vulkan, [`flush`][MappedSlab::flush] and [`invalidate`][MappedSlab::invalidate] correspond
+/// to `vkFlushMappedMemoryRanges` and `vkInvalidateMappedMemoryRanges` respectively. For
+/// host-coherent memory, both can simply do nothing.
+///
+/// You will usually want to call the provided methods, like [`flush_record`][MappedSlab::flush_record],
+/// rather than the required ones, since they take care of expanding the range to the
+/// [`non_coherent_atom_size`][MappedSlab::non_coherent_atom_size] for you. This assumes that the
+/// slab itself starts at an offset within the underlying memory object that is a multiple of the
+/// atom size, and that the end of the slab is either atom-aligned or the end of the memory object.
+pub trait MappedSlab: Slab {
+    /// The alignment, in bytes, required of the offsets and sizes of flushed and invalidated
+    /// ranges, except that a range may always end at the end of the slab.
+    ///
+    /// `nonCoherentAtomSize` in Vulkan. Host-coherent memory may simply return 1.
+    fn non_coherent_atom_size(&self) -> usize;
+
+    /// Make host writes to `range` of `self` visible to the device.
+    ///
+    /// When called through the provided methods of this trait, `range` is always non-empty,
+    /// within bounds of `self`, and aligned as described in
+    /// [`non_coherent_atom_size`][MappedSlab::non_coherent_atom_size].
+    fn flush(&mut self, range: Range<usize>) -> Result<(), Error>;
+
+    /// Make device writes to `range` of `self` visible to the host.
+    ///
+    /// When called through the provided methods of this trait, `range` is always non-empty,
+    /// within bounds of `self`, and aligned as described in
+    /// [`non_coherent_atom_size`][MappedSlab::non_coherent_atom_size].
+    fn invalidate(&mut self, range: Range<usize>) -> Result<(), Error>;
+
+    /// Expands `range` outwards to the nearest multiples of
+    /// [`non_coherent_atom_size`][MappedSlab::non_coherent_atom_size], clamping its end to the
+    /// size of `self`.
+    ///
+    /// Returns an error if `range` is out of bounds of `self`.
+    #[inline]
+    fn atom_aligned_range(&self, range: Range<usize>) -> Result<Range<usize>, Error> {
+        align_range_to_atoms(range, self.non_coherent_atom_size(), self.size())
+    }
+
+    /// Flush `range` of `self`, expanded to atom boundaries. Does nothing if `range` is empty.
+    ///
+    /// See [`atom_aligned_range`][MappedSlab::atom_aligned_range] for more.
+    #[inline]
+    fn flush_range(&mut self, range: Range<usize>) -> Result<(), Error> {
+        let range = self.atom_aligned_range(range)?;
+        if range.is_empty() {
+            return Ok(());
+        }
+        self.flush(range)
+    }
+
+    /// Invalidate `range` of `self`, expanded to atom boundaries. Does nothing if `range` is
+    /// empty.
+    ///
+    /// See [`atom_aligned_range`][MappedSlab::atom_aligned_range] for more.
+    #[inline]
+    fn invalidate_range(&mut self, range: Range<usize>) -> Result<(), Error> {
+        let range = self.atom_aligned_range(range)?;
+        if range.is_empty() {
+            return Ok(());
+        }
+        self.invalidate(range)
+    }
+
+    /// Flush exactly the bytes written by the copy described by `record`, expanded to atom
+    /// boundaries.
+    #[inline]
+    fn flush_record(&mut self, record: &CopyRecord) -> Result<(), Error> {
+        self.flush_range(record.range())
+    }
+}
+
+/// Copies `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, then flushes the written bytes.
+///
+/// See [`copy_to_offset`] and [`MappedSlab::flush_record`] for more.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_to_offset_and_flush<T: Copy, S: MappedSlab + ?Sized>(
+    src: &T,
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    let record = copy_to_offset(src, dst, start_offset)?;
+    dst.flush_record(&record)?;
+    Ok(record)
+}
+
+/// Copies from `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, then flushes the written bytes.
+///
+/// See [`copy_from_slice_to_offset`] and [`MappedSlab::flush_record`] for more.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_from_slice_to_offset_and_flush<T: Copy, S: MappedSlab + ?Sized>(
+    src: &[T],
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    let record = copy_from_slice_to_offset(src, dst, start_offset)?;
+    dst.flush_record(&record)?;
+    Ok(record)
+}
+
+/// Expands `range` outwards to the nearest multiples of `atom`, clamping its end to `size`.
+///
+/// Returns an error if `range` is out of bounds of `size`.
+#[inline]
+pub(crate) fn align_range_to_atoms(
+    range: Range<usize>,
+    atom: usize,
+    size: usize,
+) -> Result<Range<usize>, Error> {
+    if range.start > range.end || range.end > size {
+        return Err(Error::offset_out_of_bounds(
+            range.start,
+            range.start.max(range.end),
+            size,
+        ));
+    }
+
+    let atom = atom.max(1);
+    let start = range.start - range.start % atom;
+    let end = round_up_to_multiple(range.end, atom).map_or(size, |end| end.min(size));
+
+    Ok(start..end)
+}
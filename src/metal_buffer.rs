@@ -0,0 +1,92 @@
+use super::*;
+
+use metal::BufferRef;
+use metal::MTLStorageMode;
+use metal::NSRange;
+
+/// A [`Slab`] over the CPU-accessible [`contents()`][BufferRef::contents] of a Metal buffer.
+///
+/// For buffers with [`MTLStorageMode::Managed`] storage, the CPU and GPU have separate copies of
+/// the buffer's memory, and Metal must be told which ranges the CPU modified with
+/// `didModifyRange:` before the GPU uses them. [`did_modify`][MetalBufferSlab::did_modify] does
+/// exactly that for the range written by a copy, and does nothing for other storage modes.
+pub struct MetalBufferSlab<'a> {
+    buffer: &'a BufferRef,
+    base_ptr: NonNull<u8>,
+    size: usize,
+}
+
+impl<'a> MetalBufferSlab<'a> {
+    /// Create a new [`MetalBufferSlab`] over the whole contents of `buffer`.
+    ///
+    /// Returns [`ErrorKind::InvalidLayout`] if the buffer has no CPU-accessible contents (i.e. it
+    /// uses [`MTLStorageMode::Private`] or [`MTLStorageMode::Memoryless`] storage), or if its
+    /// length doesn't fit in an `isize`.
+    ///
+    /// # Safety
+    ///
+    /// For as long as the returned value is alive, the contents of `buffer` must uphold all the
+    /// requirements of the [`Slab`] trait. In particular, they must not be accessed other than
+    /// through the returned value, neither by the CPU (for example through another
+    /// [`MetalBufferSlab`] of the same buffer) nor by the GPU.
+    ///
+    /// Also see the [top-level safety documentation][`crate#safety`].
+    pub unsafe fn new(buffer: &'a BufferRef) -> Result<Self, Error> {
+        let base_ptr =
+            NonNull::new(buffer.contents().cast::<u8>()).ok_or(Error::invalid_layout())?;
+        let size = usize::try_from(buffer.length()).map_err(|_| Error::invalid_layout())?;
+        if size > isize::MAX as usize {
+            return Err(Error::invalid_layout());
+        }
+
+        Ok(Self {
+            buffer,
+            base_ptr,
+            size,
+        })
+    }
+
+    /// The buffer whose contents `self` represents.
+    #[inline]
+    pub fn buffer(&self) -> &'a BufferRef {
+        self.buffer
+    }
+
+    /// Notify Metal that the bytes written by the copy described by `record` were modified, if
+    /// the buffer uses [`MTLStorageMode::Managed`] storage.
+    #[inline]
+    pub fn did_modify(&self, record: &CopyRecord) {
+        self.did_modify_range(record.range());
+    }
+
+    /// Notify Metal that the bytes of `self` within `range` were modified, if the buffer uses
+    /// [`MTLStorageMode::Managed`] storage. Does nothing if `range` is empty.
+    #[inline]
+    pub fn did_modify_range(&self, range: Range<usize>) {
+        if range.is_empty() || self.buffer.storage_mode() != MTLStorageMode::Managed {
+            return;
+        }
+
+        self.buffer
+            .did_modify_range(NSRange::new(range.start as u64, range.len() as u64));
+    }
+}
+
+// SAFETY: So long as the safety requirements of `MetalBufferSlab::new` are met, this is also safe
+// since it's just a basic pass-thru of info.
+unsafe impl<'a> Slab for MetalBufferSlab<'a> {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.base_ptr.as_ptr() as *const u8
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.base_ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.size
+    }
+}
@@ -0,0 +1,173 @@
+use super::*;
+
+/// A builder for a copy of a value or slice into a [`Slab`], collecting the options that are
+/// otherwise spread over the `_exact`/`_with_align`/`_with_hint` variants of the copy functions.
+///
+/// ```
+/// # use presser::*;
+/// let mut slab = make_stack_slab::<u8, 256>();
+/// let record = CopyOptions::new(&42u32)
+///     .at_least_offset(10)
+///     .min_align(64)
+///     .zero_padding()
+///     .copy_to(&mut slab)?;
+/// assert!(record.start_offset >= 10);
+/// # Ok::<(), presser::Error>(())
+/// ```
+///
+/// Executing the copy with [`copy_to`][CopyOptions::copy_to] places the data exactly where the
+/// equivalent copy function would, and returns the same [`CopyRecord`].
+#[derive(Debug, Clone, Copy)]
+#[must_use = "a `CopyOptions` does nothing until `copy_to` is called"]
+pub struct CopyOptions<'s, T> {
+    src: &'s [T],
+    start_offset: usize,
+    min_alignment: usize,
+    strict_align: bool,
+    exact: bool,
+    zero_padding: bool,
+    hint: CopyHint,
+}
+
+impl<'s, T: Copy> CopyOptions<'s, T> {
+    /// Start building a copy of `src`, by default at a minimum offset of 0 with no extra
+    /// alignment.
+    #[inline]
+    pub fn new(src: &'s T) -> Self {
+        Self::from_slice(core::slice::from_ref(src))
+    }
+
+    /// Start building a copy of all elements of `src`, laid out as a `[T]`, by default at a
+    /// minimum offset of 0 with no extra alignment.
+    #[inline]
+    pub fn from_slice(src: &'s [T]) -> Self {
+        Self {
+            src,
+            start_offset: 0,
+            min_alignment: 1,
+            strict_align: false,
+            exact: false,
+            zero_padding: false,
+            hint: CopyHint::Default,
+        }
+    }
+
+    /// Place the copy at a minimum location of `start_offset` bytes past the start of the slab.
+    /// The actual start may be later to satisfy alignment requirements, unless
+    /// [`exact`][CopyOptions::exact] is also set.
+    #[inline]
+    pub fn at_least_offset(mut self, start_offset: usize) -> Self {
+        self.start_offset = start_offset;
+        self
+    }
+
+    /// Align the copy to at least `min_alignment` (rounded up to a power of two), on top of the
+    /// alignment of `T`.
+    #[inline]
+    pub fn min_align(mut self, min_alignment: usize) -> Self {
+        self.min_alignment = min_alignment;
+        self
+    }
+
+    /// Require the alignment given to [`min_align`][CopyOptions::min_align] to be a power of two,
+    /// failing with [`ErrorKind::InvalidAlignment`] rather than rounding it up to one.
+    #[inline]
+    pub fn strict_align(mut self) -> Self {
+        self.strict_align = true;
+        self
+    }
+
+    /// Require the copy to start *exactly* at the offset given to
+    /// [`at_least_offset`][CopyOptions::at_least_offset], failing with
+    /// [`ErrorKind::RequestedOffsetUnaligned`] if that offset doesn't satisfy the alignment
+    /// requirements.
+    #[inline]
+    pub fn exact(mut self) -> Self {
+        self.exact = true;
+        self
+    }
+
+    /// Fill the padding around the copy with zero bytes: both the bytes between the requested
+    /// start offset and the actual start of the copy, and the bytes between
+    /// [`end_offset`][CopyRecord::end_offset] and
+    /// [`end_offset_padded`][CopyRecord::end_offset_padded].
+    ///
+    /// This leaves all of `start_offset..end_offset_padded` initialized, so the range can
+    /// later be viewed as bytes, for example to hash it or pass it to an API taking a `&[u8]`.
+    /// Padding bytes *within* `T` are still copied as-is and may be uninitialized.
+    #[inline]
+    pub fn zero_padding(mut self) -> Self {
+        self.zero_padding = true;
+        self
+    }
+
+    /// Use the copy strategy selected by `hint`. See [`CopyHint`].
+    #[inline]
+    pub fn hint(mut self, hint: CopyHint) -> Self {
+        self.hint = hint;
+        self
+    }
+
+    /// Perform the copy into `dst`.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe on its own, however it is very possible to do unsafe
+    /// things if you read the copied data in the wrong way. See the
+    /// [crate-level Safety documentation][`crate#safety`] for more.
+    pub fn copy_to<S: Slab + ?Sized>(&self, dst: &mut S) -> Result<CopyRecord, Error> {
+        if self.strict_align {
+            validate_alignment(self.min_alignment)?;
+        }
+
+        let layout = Layout::for_value(self.src);
+        let offsets = compute_and_validate_offsets(
+            &*dst,
+            self.start_offset,
+            layout,
+            self.min_alignment,
+            self.exact,
+        )?;
+
+        if !self.zero_padding {
+            poison_alignment_gap(dst, self.start_offset, &offsets);
+        }
+
+        let base_ptr = dst.base_non_null().as_ptr();
+
+        if self.zero_padding {
+            // SAFETY: `start_offset <= offsets.start` and `offsets.end <= offsets.end_padded`,
+            // and both ranges were checked to be within the bounds of `dst`.
+            unsafe {
+                core::ptr::write_bytes(
+                    base_ptr.add(self.start_offset),
+                    0,
+                    offsets.start - self.start_offset,
+                );
+                core::ptr::write_bytes(
+                    base_ptr.add(offsets.end),
+                    0,
+                    offsets.end_padded - offsets.end,
+                );
+            }
+        }
+
+        // SAFETY:
+        // - src is valid as we have a reference to it
+        // - dst is valid so long as requirements for `slab` were met, i.e.
+        // we have unique access to the region described and that it is valid for the duration
+        // of 'a.
+        // - checked that copy stays within bounds of our allocation
+        // - the two regions can't overlap as we have unique access to `dst`
+        unsafe {
+            copy_bytes_with_hint(
+                self.src.as_ptr().cast::<u8>(),
+                base_ptr.add(offsets.start),
+                layout.size(),
+                self.hint,
+            );
+        }
+
+        Ok(offsets.into())
+    }
+}
@@ -0,0 +1,208 @@
+use super::*;
+
+/// The size of a huge page (also known as a large page) on the common x86-64 and AArch64
+/// configurations of Linux and Windows: 2 MiB.
+pub const HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+
+/// Get the size of a virtual memory page on the current platform, in bytes.
+///
+/// This is queried from the operating system on Unix-like platforms and Windows. On other
+/// platforms, 4096 is returned.
+pub fn page_size() -> usize {
+    imp::page_size()
+}
+
+/// Hint to the operating system that the given page-aligned region of memory should be backed
+/// by huge pages if possible.
+pub(crate) fn advise_huge_pages(ptr: NonNull<u8>, len: usize) {
+    imp::advise_huge_pages(ptr, len);
+}
+
+/// Reserve `len` bytes of address space, which is inaccessible until committed with [`commit`].
+///
+/// Returns `None` if the address space could not be reserved, or if this is not supported on the
+/// current platform.
+pub(crate) fn reserve(len: usize) -> Option<NonNull<u8>> {
+    imp::reserve(len)
+}
+
+/// Make `len` bytes starting at `ptr` readable and writable, returning whether it succeeded.
+///
+/// # Safety
+///
+/// `ptr` must be page-aligned, and the range must be within a region returned by [`reserve`]
+/// which has not yet been released.
+pub(crate) unsafe fn commit(ptr: NonNull<u8>, len: usize) -> bool {
+    // SAFETY: same safety requirements
+    unsafe { imp::commit(ptr, len) }
+}
+
+/// Release a whole region of `len` bytes previously returned by [`reserve`].
+///
+/// # Safety
+///
+/// `ptr` and `len` must describe exactly a region returned by [`reserve`], which may not be used
+/// afterwards.
+pub(crate) unsafe fn release(ptr: NonNull<u8>, len: usize) {
+    // SAFETY: same safety requirements
+    unsafe { imp::release(ptr, len) }
+}
+
+#[cfg(unix)]
+mod imp {
+    use core::ptr::NonNull;
+
+    pub(super) fn page_size() -> usize {
+        // SAFETY: `sysconf` has no safety requirements.
+        let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+        usize::try_from(size).unwrap_or(4096)
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub(super) fn advise_huge_pages(ptr: NonNull<u8>, len: usize) {
+        // SAFETY: `madvise` only reads the range's mapping metadata; `MADV_HUGEPAGE` doesn't change
+        // the contents of the memory. Failure (e.g. transparent huge pages being disabled) is fine
+        // to ignore as this is only a hint.
+        unsafe {
+            libc::madvise(ptr.as_ptr().cast(), len, libc::MADV_HUGEPAGE);
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub(super) fn advise_huge_pages(_ptr: NonNull<u8>, _len: usize) {}
+
+    pub(super) fn reserve(len: usize) -> Option<NonNull<u8>> {
+        // SAFETY: an anonymous mapping at an address of the kernel's choosing doesn't affect any
+        // existing memory.
+        let ptr = unsafe {
+            libc::mmap(
+                core::ptr::null_mut(),
+                len,
+                libc::PROT_NONE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_NORESERVE,
+                -1,
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            None
+        } else {
+            NonNull::new(ptr.cast())
+        }
+    }
+
+    pub(super) unsafe fn commit(ptr: NonNull<u8>, len: usize) -> bool {
+        // SAFETY: the caller guarantees the range is part of a mapping we own.
+        unsafe { libc::mprotect(ptr.as_ptr().cast(), len, libc::PROT_READ | libc::PROT_WRITE) == 0 }
+    }
+
+    pub(super) unsafe fn release(ptr: NonNull<u8>, len: usize) {
+        // SAFETY: the caller guarantees this is exactly a mapping we own and won't use again.
+        unsafe {
+            libc::munmap(ptr.as_ptr().cast(), len);
+        }
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use core::ptr::NonNull;
+
+    use windows_sys::Win32::System::Memory::VirtualAlloc;
+    use windows_sys::Win32::System::Memory::VirtualFree;
+    use windows_sys::Win32::System::Memory::MEM_COMMIT;
+    use windows_sys::Win32::System::Memory::MEM_RELEASE;
+    use windows_sys::Win32::System::Memory::MEM_RESERVE;
+    use windows_sys::Win32::System::Memory::PAGE_NOACCESS;
+    use windows_sys::Win32::System::Memory::PAGE_READWRITE;
+    use windows_sys::Win32::System::SystemInformation::GetSystemInfo;
+    use windows_sys::Win32::System::SystemInformation::SYSTEM_INFO;
+
+    pub(super) fn page_size() -> usize {
+        // SAFETY: `SYSTEM_INFO` is plain old data, and `GetSystemInfo` fills it in.
+        let info = unsafe {
+            let mut info = core::mem::zeroed::<SYSTEM_INFO>();
+            GetSystemInfo(&mut info);
+            info
+        };
+        info.dwPageSize as usize
+    }
+
+    // Large pages on Windows can only be allocated with `VirtualAlloc` by processes holding
+    // `SeLockMemoryPrivilege`, so there's nothing to do for heap memory.
+    pub(super) fn advise_huge_pages(_ptr: NonNull<u8>, _len: usize) {}
+
+    pub(super) fn reserve(len: usize) -> Option<NonNull<u8>> {
+        // SAFETY: reserving address space at an address of the system's choosing doesn't affect
+        // any existing memory.
+        let ptr = unsafe { VirtualAlloc(core::ptr::null(), len, MEM_RESERVE, PAGE_NOACCESS) };
+        NonNull::new(ptr.cast())
+    }
+
+    pub(super) unsafe fn commit(ptr: NonNull<u8>, len: usize) -> bool {
+        // SAFETY: the caller guarantees the range is part of a reservation we own.
+        let ptr = unsafe { VirtualAlloc(ptr.as_ptr().cast(), len, MEM_COMMIT, PAGE_READWRITE) };
+        !ptr.is_null()
+    }
+
+    pub(super) unsafe fn release(ptr: NonNull<u8>, _len: usize) {
+        // SAFETY: the caller guarantees this is exactly a reservation we own and won't use again.
+        unsafe {
+            VirtualFree(ptr.as_ptr().cast(), 0, MEM_RELEASE);
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod imp {
+    use core::ptr::NonNull;
+
+    pub(super) fn page_size() -> usize {
+        4096
+    }
+
+    pub(super) fn advise_huge_pages(_ptr: NonNull<u8>, _len: usize) {}
+
+    pub(super) fn reserve(_len: usize) -> Option<NonNull<u8>> {
+        None
+    }
+
+    pub(super) unsafe fn commit(_ptr: NonNull<u8>, _len: usize) -> bool {
+        false
+    }
+
+    pub(super) unsafe fn release(_ptr: NonNull<u8>, _len: usize) {}
+}
+
+impl HeapSlab {
+    /// Make a new slab space on the heap, aligned to the start of a virtual memory page and
+    /// with its size rounded up to a multiple of the page size. Begins as uninitialized. The memory
+    /// will be be deallocated on drop.
+    ///
+    /// Page-aligned memory is required to register host memory with some GPU and RDMA APIs.
+    ///
+    /// See [`HeapSlab::try_new`] for the errors this may return.
+    pub fn new_page_aligned(size: usize) -> Result<Self, Error> {
+        let page_size = page_size();
+        let size = round_up_to_multiple(size, page_size).ok_or(Error::invalid_layout())?;
+        Self::try_new(Layout::from_size_align(size, page_size)?)
+    }
+
+    /// Make a new slab space on the heap, aligned to the start of a huge page and with its size
+    /// rounded up to a multiple of [`HUGE_PAGE_SIZE`]. Begins as uninitialized. The memory will be
+    /// be deallocated on drop.
+    ///
+    /// On Linux, the operating system is additionally asked to back the memory with transparent
+    /// huge pages, which reduces TLB pressure when streaming through large staging buffers. This is
+    /// only a hint and is silently ignored if transparent huge pages are not available. On other
+    /// platforms (including Windows, where large pages require special privileges and a dedicated
+    /// allocation API), the memory is only aligned.
+    ///
+    /// See [`HeapSlab::try_new`] for the errors this may return.
+    pub fn new_huge_page_aligned(size: usize) -> Result<Self, Error> {
+        let size = round_up_to_multiple(size, HUGE_PAGE_SIZE).ok_or(Error::invalid_layout())?;
+        let slab = Self::try_new(Layout::from_size_align(size, HUGE_PAGE_SIZE)?)?;
+        advise_huge_pages(slab.base_ptr, size);
+        Ok(slab)
+    }
+}
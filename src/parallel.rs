@@ -0,0 +1,93 @@
+use super::*;
+
+use rayon::prelude::*;
+
+/// The minimum number of bytes copied by each rayon task in [`par_copy_from_slice_to_offset`].
+///
+/// Splitting any finer than this costs more in scheduling overhead than it gains in bandwidth.
+const MIN_PAR_CHUNK_BYTES: usize = 1 << 20;
+
+/// Copies from `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, splitting the copy across the rayon thread
+/// pool.
+///
+/// Each task copies a disjoint chunk of `src` into the matching disjoint chunk of `dst`. Sources
+/// smaller than a couple of megabytes are copied on the current thread, since a single core can
+/// saturate the memory bandwidth available to it for those.
+///
+/// The placement of the copy and the returned [`CopyRecord`] are the same as for
+/// [`copy_from_slice_to_offset`].
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+pub fn par_copy_from_slice_to_offset<T: Copy + Send + Sync, S: Slab + ?Sized>(
+    src: &[T],
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    let layout = Layout::for_value(src);
+    let offsets = compute_and_validate_offsets(&*dst, start_offset, layout, 1, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
+
+    if layout.size() < 2 * MIN_PAR_CHUNK_BYTES {
+        // SAFETY:
+        // - src is valid as we have a reference to it
+        // - dst is valid so long as requirements for `slab` were met, i.e.
+        // we have unique access to the region described and that it is valid for the duration
+        // of 'a.
+        // - checked that copy stays within bounds of our allocation
+        // - the two regions can't overlap as we have unique access to `dst`
+        unsafe {
+            core::ptr::copy_nonoverlapping(src.as_ptr(), dst_ptr, src.len());
+        }
+        return Ok(offsets.into());
+    }
+
+    let dst_ptr = SendPtr(dst_ptr);
+
+    let chunk_len = (MIN_PAR_CHUNK_BYTES / core::mem::size_of::<T>().max(1)).max(1);
+    src.par_chunks(chunk_len)
+        .enumerate()
+        .for_each(|(i, chunk)| {
+            // SAFETY:
+            // - src is valid as we have a reference to it
+            // - dst is valid so long as requirements for `slab` were met, i.e.
+            // we have unique access to the region described and that it is valid for the duration
+            // of 'a.
+            // - checked that the whole copy stays within bounds of our allocation, and each chunk
+            // is written to the same position in `dst` as it has in `src`, so the chunks don't
+            // overlap each other
+            // - the two regions can't overlap as we have unique access to `dst`
+            unsafe {
+                let chunk_dst = dst_ptr.get().add(i * chunk_len);
+                core::ptr::copy_nonoverlapping(chunk.as_ptr(), chunk_dst, chunk.len());
+            }
+        });
+
+    Ok(offsets.into())
+}
+
+/// A pointer which may be shared between the tasks of a parallel copy.
+#[derive(Clone, Copy)]
+struct SendPtr<T>(*mut T);
+
+impl<T> SendPtr<T> {
+    /// Get the wrapped pointer. Going through a method makes closures capture the whole wrapper,
+    /// rather than just the (non-`Send`) pointer field.
+    #[inline(always)]
+    fn get(self) -> *mut T {
+        self.0
+    }
+}
+
+// SAFETY: only used to write disjoint regions of `dst` from different threads, see
+// `par_copy_from_slice_to_offset`.
+unsafe impl<T: Send> Send for SendPtr<T> {}
+// SAFETY: see above.
+unsafe impl<T: Send> Sync for SendPtr<T> {}
@@ -0,0 +1,165 @@
+use super::*;
+
+use core::ffi::c_int;
+use core::ffi::c_uint;
+
+/// Flags controlling how a [`PinnedSlab`] is allocated.
+///
+/// These have the same values for `cudaHostAlloc` and `hipHostMalloc`, and may be combined with
+/// `|`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PinnedFlags(u32);
+
+impl PinnedFlags {
+    /// The default, page-locked host memory only usable from the current device context.
+    pub const DEFAULT: Self = Self(0);
+
+    /// The memory is considered pinned by all device contexts, not just the current one.
+    pub const PORTABLE: Self = Self(1);
+
+    /// The memory is mapped into the device address space, so kernels may access it directly.
+    pub const MAPPED: Self = Self(2);
+
+    /// The memory is write-combined: faster to transfer to the device, but very slow to read
+    /// from the CPU. See also [`CopyHint::Streaming`].
+    pub const WRITE_COMBINED: Self = Self(4);
+
+    /// The raw flag bits.
+    #[inline]
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+}
+
+impl core::ops::BitOr for PinnedFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// The GPU compute runtime a [`PinnedSlab`] was allocated with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Runtime {
+    #[cfg(feature = "cuda")]
+    Cuda,
+    #[cfg(feature = "hip")]
+    Hip,
+}
+
+/// A [`Slab`] of page-locked ("pinned") host memory, allocated through the CUDA or HIP runtime
+/// and freed again when dropped.
+///
+/// Pinned memory can be transferred to and from the device with DMA, which makes it the fastest
+/// staging memory for compute uploads and readbacks.
+///
+/// The respective runtime library (`cudart` or `amdhip64`) is linked when the `cuda` or `hip`
+/// feature is enabled.
+pub struct PinnedSlab {
+    ptr: NonNull<u8>,
+    size: usize,
+    runtime: Runtime,
+}
+
+impl PinnedSlab {
+    /// Allocate `size` bytes of pinned host memory with `cudaHostAlloc`.
+    ///
+    /// Returns [`ErrorKind::InvalidLayout`] if `size` is 0, or [`ErrorKind::AllocationFailed`] if
+    /// the allocation fails for any reason. The CUDA runtime error can be retrieved with
+    /// `cudaGetLastError`.
+    #[cfg(feature = "cuda")]
+    pub fn new_cuda(size: usize, flags: PinnedFlags) -> Result<Self, Error> {
+        Self::alloc(size, flags, Runtime::Cuda)
+    }
+
+    /// Allocate `size` bytes of pinned host memory with `hipHostMalloc`.
+    ///
+    /// Returns [`ErrorKind::InvalidLayout`] if `size` is 0, or [`ErrorKind::AllocationFailed`] if
+    /// the allocation fails for any reason. The HIP runtime error can be retrieved with
+    /// `hipGetLastError`.
+    #[cfg(feature = "hip")]
+    pub fn new_hip(size: usize, flags: PinnedFlags) -> Result<Self, Error> {
+        Self::alloc(size, flags, Runtime::Hip)
+    }
+
+    fn alloc(size: usize, flags: PinnedFlags, runtime: Runtime) -> Result<Self, Error> {
+        if size == 0 || size > isize::MAX as usize {
+            return Err(Error::invalid_layout());
+        }
+
+        let mut ptr = core::ptr::null_mut();
+        // SAFETY: `ptr` is valid for writes, and the runtime validates `size` and `flags`.
+        let result = unsafe {
+            match runtime {
+                #[cfg(feature = "cuda")]
+                Runtime::Cuda => ffi::cudaHostAlloc(&mut ptr, size, flags.bits()),
+                #[cfg(feature = "hip")]
+                Runtime::Hip => ffi::hipHostMalloc(&mut ptr, size, flags.bits()),
+            }
+        };
+        if result != 0 {
+            return Err(Error::allocation_failed());
+        }
+        let ptr = NonNull::new(ptr.cast::<u8>()).ok_or(Error::allocation_failed())?;
+
+        Ok(Self { ptr, size, runtime })
+    }
+}
+
+impl Drop for PinnedSlab {
+    fn drop(&mut self) {
+        // SAFETY: `self.ptr` was allocated by the same runtime, and is never used again.
+        unsafe {
+            match self.runtime {
+                #[cfg(feature = "cuda")]
+                Runtime::Cuda => ffi::cudaFreeHost(self.ptr.as_ptr().cast()),
+                #[cfg(feature = "hip")]
+                Runtime::Hip => ffi::hipHostFree(self.ptr.as_ptr().cast()),
+            };
+        }
+    }
+}
+
+// SAFETY: The memory was allocated by us with at least `size` bytes and is only accessible
+// through `self`. It's freed only when `self` is dropped.
+unsafe impl Slab for PinnedSlab {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr() as *const u8
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.size
+    }
+}
+
+// SAFETY: `PinnedSlab` uniquely owns its memory, which isn't tied to any thread.
+unsafe impl Send for PinnedSlab {}
+// SAFETY: `PinnedSlab` only hands out access to its memory according to normal borrowing rules.
+unsafe impl Sync for PinnedSlab {}
+
+mod ffi {
+    use super::*;
+
+    #[cfg(feature = "cuda")]
+    #[link(name = "cudart")]
+    extern "C" {
+        pub(super) fn cudaHostAlloc(ptr: *mut *mut c_void, size: usize, flags: c_uint) -> c_int;
+        pub(super) fn cudaFreeHost(ptr: *mut c_void) -> c_int;
+    }
+
+    #[cfg(feature = "hip")]
+    #[link(name = "amdhip64")]
+    extern "C" {
+        pub(super) fn hipHostMalloc(ptr: *mut *mut c_void, size: usize, flags: c_uint) -> c_int;
+        pub(super) fn hipHostFree(ptr: *mut c_void) -> c_int;
+    }
+}
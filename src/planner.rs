@@ -0,0 +1,116 @@
+use super::*;
+
+/// Plans the suballocation of multiple resources inside a single [`Slab`], before any data is
+/// copied into it.
+///
+/// This supports the common "one big allocation, many resources" pattern: declare the layout of
+/// each resource (for example a vertex buffer, index buffer, uniform block, and readback region)
+/// in turn, and the planner hands back validated, non-overlapping offsets for each of them. You
+/// may then copy into the slab at exactly those offsets, for example with
+/// [`copy_from_slice_to_offset_with_align_exact`].
+///
+/// The planner only remembers the base address and size of the slab it was created for, it does
+/// not borrow it. The planned offsets are only valid for that same slab (or another slab with the
+/// same base address alignment and at least the same size).
+#[derive(Debug, Clone)]
+pub struct LayoutPlanner {
+    base_addr: usize,
+    size: usize,
+    cursor: usize,
+}
+
+impl LayoutPlanner {
+    /// Create a new planner for `slab`, starting at the beginning of it.
+    #[inline]
+    pub fn new<S: Slab + ?Sized>(slab: &S) -> Self {
+        Self {
+            base_addr: addr(slab.read_base_non_null().as_ptr()),
+            size: slab.size(),
+            cursor: 0,
+        }
+    }
+
+    /// Create a new planner for `slab`, before which no resource will be placed.
+    #[inline]
+    pub fn starting_at<S: Slab + ?Sized>(slab: &S, start_offset: usize) -> Self {
+        Self {
+            cursor: start_offset,
+            ..Self::new(slab)
+        }
+    }
+
+    /// Reserve space for a resource with layout `layout` after all previously reserved
+    /// resources, aligned to at least `min_alignment`.
+    ///
+    /// - `min_alignment` is the minimum alignment that you are requesting the resource be aligned to.
+    ///   The resource may be aligned greater than `min_alignment` depending on the alignment of
+    ///   `layout` (the actual alignment will be the greater of the two between `layout.align()` and
+    ///   `min_align.next_power_of_two()`).
+    ///
+    /// Returns a [`CopyRecord`] describing where the resource will live. If the resource doesn't
+    /// fit in the slab, an error is returned and nothing is reserved.
+    #[inline]
+    pub fn reserve(&mut self, layout: Layout, min_alignment: usize) -> Result<CopyRecord, Error> {
+        let offsets = compute_and_validate_offsets_for_raw_parts(
+            self.base_addr,
+            self.size,
+            self.cursor,
+            layout,
+            min_alignment,
+            false,
+        )?;
+        self.cursor = offsets.end;
+        Ok(offsets.into())
+    }
+
+    /// Reserve space for a `T`. See [`reserve`][LayoutPlanner::reserve] for more.
+    #[inline]
+    pub fn reserve_for<T>(&mut self, min_alignment: usize) -> Result<CopyRecord, Error> {
+        self.reserve(Layout::new::<T>(), min_alignment)
+    }
+
+    /// Reserve space for a `[T; count]`. See [`reserve`][LayoutPlanner::reserve] for more.
+    #[inline]
+    pub fn reserve_array<T>(
+        &mut self,
+        count: usize,
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error> {
+        self.reserve(Layout::array::<T>(count)?, min_alignment)
+    }
+
+    /// Reserve space for each of `requirements`, given as pairs of the resource's layout and
+    /// minimum alignment, in order.
+    ///
+    /// Returns a vector of [`CopyRecord`]s, one for each requirement. Either all resources are
+    /// reserved, or an error is returned and none are.
+    #[cfg(feature = "std")]
+    pub fn reserve_all(
+        &mut self,
+        requirements: &[(Layout, usize)],
+    ) -> Result<Vec<CopyRecord>, Error> {
+        let start_cursor = self.cursor;
+        let records = requirements
+            .iter()
+            .map(|&(layout, min_alignment)| self.reserve(layout, min_alignment))
+            .collect::<Result<Vec<_>, _>>();
+        if records.is_err() {
+            self.cursor = start_cursor;
+        }
+        records
+    }
+
+    /// The offset directly after the end of the last reserved resource, i.e. the number of bytes
+    /// at the beginning of the slab which have been planned for (including alignment padding).
+    #[inline]
+    pub fn used(&self) -> usize {
+        self.cursor
+    }
+
+    /// The number of bytes left unplanned at the end of the slab, not accounting for any
+    /// alignment padding the next reservation may need.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.size.saturating_sub(self.cursor)
+    }
+}
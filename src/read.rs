@@ -24,13 +24,45 @@ pub unsafe fn readback_from_ffi<'a, T, S, F>(slab: &'a mut S, fill_slab: F) -> R
 where
     S: Slab + ?Sized,
     F: FnOnce(*mut c_void),
+{
+    // SAFETY: same safety requirements
+    unsafe {
+        try_readback_from_ffi(slab, |ptr| {
+            fill_slab(ptr);
+            Ok(())
+        })
+    }
+}
+
+/// Like [`readback_from_ffi`], but `fill_slab` may fail, for example when the ffi function
+/// returns an error status.
+///
+/// If `fill_slab` returns an error, it's passed through and nothing is read. Errors of this crate
+/// are converted into the same error type `E`, for example [`std::io::Error`] or an error type of
+/// your own implementing `From<presser::Error>`.
+///
+/// # Safety
+///
+/// If `fill_slab` returns `Ok`, it must have **fully-initialized** a **valid**\* `T` at the given
+/// pointer.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+pub unsafe fn try_readback_from_ffi<'a, T, S, E, F>(
+    slab: &'a mut S,
+    fill_slab: F,
+) -> Result<&'a T, E>
+where
+    S: Slab + ?Sized,
+    E: From<Error>,
+    F: FnOnce(*mut c_void) -> Result<(), E>,
 {
     let t_layout = Layout::new::<T>();
     let offsets = compute_and_validate_offsets(slab, 0, t_layout, 1, false)?;
     // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
-    let ptr = unsafe { slab.base_ptr_mut().add(offsets.start) }.cast::<c_void>();
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offsets.start) }.cast::<c_void>();
 
-    fill_slab(ptr);
+    fill_slab(ptr)?;
 
     let ptr = ptr.cast::<T>().cast_const();
 
@@ -43,6 +75,43 @@ where
     Ok(unsafe { &*ptr })
 }
 
+/// Like [`readback_from_ffi`], but returns a mutable reference to the `T`, for example to fix up
+/// its endianness or handles right after the ffi call while still holding the unique borrow of
+/// `slab`.
+///
+/// # Safety
+///
+/// You must during the execution of `fill_slab` **fully-initialize** a **valid**\* `T`
+/// at the given pointer.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+pub unsafe fn readback_from_ffi_mut<'a, T, S, F>(
+    slab: &'a mut S,
+    fill_slab: F,
+) -> Result<&'a mut T, Error>
+where
+    S: Slab + ?Sized,
+    F: FnOnce(*mut c_void),
+{
+    let t_layout = Layout::new::<T>();
+    let offsets = compute_and_validate_offsets(slab, 0, t_layout, 1, false)?;
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offsets.start) }.cast::<c_void>();
+
+    fill_slab(ptr);
+
+    let ptr = ptr.cast::<T>();
+
+    // SAFETY:
+    // - `ptr` is properly aligned, checked by us
+    // - `slab` contains enough space for `T` at `ptr`, checked by us
+    // - if the function-level safety guarantees are met, then:
+    //     - `ptr` contains a previously-placed `T`
+    //     - we have mutable access to all of `slab`, which includes `ptr`.
+    Ok(unsafe { &mut *ptr })
+}
+
 /// Helper to read back data from an ffi function which expects a pointer into which it will write
 /// a slice (in C language, an array) of `T`s.
 ///
@@ -70,19 +139,47 @@ pub unsafe fn readback_slice_from_ffi<'a, T, S, F>(
 where
     S: Slab + ?Sized,
     F: FnOnce(*mut c_void, usize) -> usize,
+{
+    // SAFETY: same safety requirements
+    unsafe {
+        try_readback_slice_from_ffi(slab, |ptr, writable_size| Ok(fill_slab(ptr, writable_size)))
+    }
+}
+
+/// Like [`readback_slice_from_ffi`], but `fill_slab` may fail, for example when the ffi function
+/// returns an error status.
+///
+/// If `fill_slab` returns an error, it's passed through and nothing is read. Errors of this crate
+/// are converted into the same error type `E`. See [`try_readback_from_ffi`] for more.
+///
+/// # Safety
+///
+/// If `fill_slab` returns `Ok`, it must have **fully-initialized** a **valid**\* slice of `T`
+/// beginning at the given pointer and with length greater than or equal to the length it returned.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+pub unsafe fn try_readback_slice_from_ffi<'a, T, S, E, F>(
+    slab: &'a mut S,
+    fill_slab: F,
+) -> Result<&'a [T], E>
+where
+    S: Slab + ?Sized,
+    E: From<Error>,
+    F: FnOnce(*mut c_void, usize) -> Result<usize, E>,
 {
     let t_layout = Layout::new::<T>();
     let offsets = compute_and_validate_offsets(slab, 0, t_layout, 1, false)?;
     // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
-    let ptr = unsafe { slab.base_ptr_mut().add(offsets.start) }.cast::<c_void>();
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offsets.start) }.cast::<c_void>();
 
     let writable_size = slab.size() - offsets.end_padded;
-    let written_n_of_ts = fill_slab(ptr, writable_size);
+    let written_n_of_ts = fill_slab(ptr, writable_size)?;
 
-    let layout_claimed_written = Layout::array::<T>(written_n_of_ts)?;
+    let layout_claimed_written = Layout::array::<T>(written_n_of_ts).map_err(Error::from)?;
     let end_offset = offsets.start + layout_claimed_written.size();
     if end_offset > slab.size() {
-        return Err(Error::OutOfMemory);
+        return Err(Error::out_of_memory(0, end_offset, slab.size()).into());
     }
 
     let ptr = ptr.cast::<T>().cast_const();
@@ -96,6 +193,353 @@ where
     Ok(unsafe { core::slice::from_raw_parts(ptr, written_n_of_ts) })
 }
 
+/// Like [`readback_slice_from_ffi`], but the space available for writing is given to `fill_slab`
+/// as a number of `T`s rather than of bytes, as most C APIs taking an output array expect.
+///
+/// `fill_slab` is a function which takes as parameters first an aligned (for T)
+/// [`*mut c_void`](c_void) and second the maximum number of `T`s which fit in `slab` after it.
+/// It must then write a slice of `T`s into the given pointer and return the length, in units of
+/// `T`, of the slice it wrote. If this is greater than the maximum it was given, an error is
+/// returned.
+///
+/// # Safety
+///
+/// You must during the execution of `fill_slab` **fully-initialize** a **valid**\* slice of `T`
+/// beginning at the given pointer and with length greater than or equal to the length you return
+/// from that function.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+pub unsafe fn readback_slice_from_ffi_max_elements<'a, T, S, F>(
+    slab: &'a mut S,
+    fill_slab: F,
+) -> Result<&'a [T], Error>
+where
+    S: Slab + ?Sized,
+    F: FnOnce(*mut c_void, usize) -> usize,
+{
+    let offsets = compute_and_validate_offsets(slab, 0, Layout::new::<[T; 0]>(), 1, false)?;
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offsets.start) }.cast::<c_void>();
+
+    let max_elements = (slab.size() - offsets.start)
+        .checked_div(core::mem::size_of::<T>())
+        .unwrap_or(usize::MAX);
+    let written_n_of_ts = fill_slab(ptr, max_elements);
+
+    if written_n_of_ts > max_elements {
+        let claimed_size = Layout::array::<T>(written_n_of_ts)?.size();
+        return Err(Error::out_of_memory(
+            0,
+            offsets.start.saturating_add(claimed_size),
+            slab.size(),
+        ));
+    }
+
+    let ptr = ptr.cast::<T>().cast_const();
+
+    // SAFETY:
+    // - `ptr` is properly aligned, checked by us
+    // - `slab` contains enough space for `[T; written_n_of_ts]` at `ptr`, checked by us
+    // - if the function-level safety guarantees are met, then:
+    //     - `ptr` contains `written_n_of_ts` previously-placed `T`s
+    //     - we have mutable access to all of `slab`, which includes `ptr`.
+    Ok(unsafe { core::slice::from_raw_parts(ptr, written_n_of_ts) })
+}
+
+/// Like [`readback_slice_from_ffi`], but for ffi functions which write the number of `T`s they
+/// wrote through an out-parameter rather than returning it.
+///
+/// `fill_slab` is a function which takes as parameters first an aligned (for T)
+/// [`*mut c_void`](c_void), second the number of bytes left in `slab` available for writing and
+/// third a reference to a count of type `N`, usually `usize` or `u32`, which starts out as
+/// `N::default()`. It must then write a slice of `T`s into the given pointer and the length, in
+/// units of `T`, of the slice it wrote into the count.
+///
+/// The function will return an error if the written count doesn't fit into `slab` or can't be
+/// converted into a `usize`.
+///
+/// # Safety
+///
+/// You must during the execution of `fill_slab` **fully-initialize** a **valid**\* slice of `T`
+/// beginning at the given pointer and with length greater than or equal to the count you write.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+pub unsafe fn readback_slice_from_ffi_out_count<'a, T, S, N, F>(
+    slab: &'a mut S,
+    fill_slab: F,
+) -> Result<&'a [T], Error>
+where
+    S: Slab + ?Sized,
+    N: Copy + Default + TryInto<usize>,
+    F: FnOnce(*mut c_void, usize, &mut N),
+{
+    let mut count = N::default();
+    // SAFETY: same safety requirements
+    unsafe {
+        try_readback_slice_from_ffi(slab, |ptr, writable_size| {
+            fill_slab(ptr, writable_size, &mut count);
+            count
+                .try_into()
+                .map_err(|_| Error::new(ErrorKind::OutOfMemory))
+        })
+    }
+}
+
+/// Helper to read back data from an ffi function which writes a slice (in C language, an array)
+/// of `T`s terminated by a `sentinel` value, such as a zero handle.
+///
+/// `fill_slab` is a function which takes as parameters first an aligned (for T)
+/// [`*mut c_void`](c_void) and second the maximum number of `T`s which fit in `slab` after it,
+/// including the sentinel. It must then write a slice of `T`s followed by the sentinel into the
+/// given pointer.
+///
+/// The length of the slice is then found by scanning for the sentinel, without going past the
+/// maximum number of `T`s, and the returned slice doesn't include the sentinel. If no sentinel is
+/// found, an error of kind [`ErrorKind::OutOfMemory`] is returned. Since the length of a slice of
+/// zero-sized `T`s can't be bounded by `slab`, an error of kind [`ErrorKind::ZeroSizedType`] is
+/// returned for those without calling `fill_slab`.
+///
+/// # Safety
+///
+/// You must during the execution of `fill_slab` **fully-initialize** **valid**\* `T`s beginning
+/// at the given pointer, up to and including the first `T` equal to `sentinel`. If you don't write
+/// a sentinel within the maximum number of `T`s you were given, all of them must be
+/// **fully-initialized** and **valid** instead.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+pub unsafe fn readback_terminated_slice_from_ffi<'a, T, S, F>(
+    slab: &'a mut S,
+    sentinel: T,
+    fill_slab: F,
+) -> Result<&'a [T], Error>
+where
+    T: PartialEq,
+    S: Slab + ?Sized,
+    F: FnOnce(*mut c_void, usize),
+{
+    if core::mem::size_of::<T>() == 0 {
+        return Err(Error::zero_sized_type());
+    }
+
+    let offsets = compute_and_validate_offsets(slab, 0, Layout::new::<[T; 0]>(), 1, false)?;
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offsets.start) }.cast::<c_void>();
+
+    let max_elements = (slab.size() - offsets.start) / core::mem::size_of::<T>();
+    fill_slab(ptr, max_elements);
+
+    let ptr = ptr.cast::<T>().cast_const();
+
+    // SAFETY:
+    // - `ptr` is properly aligned, checked by us
+    // - `slab` contains enough space for `[T; max_elements]` at `ptr`, checked by us, and the
+    // scan stops before `max_elements`
+    // - if the function-level safety guarantees are met, every `T` up to and including the
+    // first sentinel, or all of them if there is none, was previously placed
+    let len = (0..max_elements)
+        .find(|&i| unsafe { *ptr.add(i) == sentinel })
+        .ok_or(Error::new(ErrorKind::OutOfMemory))?;
+
+    // SAFETY:
+    // - `ptr` is properly aligned, checked by us
+    // - `slab` contains enough space for `[T; len]` at `ptr`, checked by us
+    // - if the function-level safety guarantees are met, then:
+    //     - `ptr` contains `len` previously-placed `T`s
+    //     - we have mutable access to all of `slab`, which includes `ptr`.
+    Ok(unsafe { core::slice::from_raw_parts(ptr, len) })
+}
+
+/// Helper to read back data from an ffi function which is called twice: once to query the number
+/// of `T`s it will write, and then again to write them into a pointer, as is common in C APIs.
+///
+/// `query_len` is a function which must return the number of `T`s that will be written, usually
+/// by calling the ffi function with a null pointer. If a slice of that many `T`s doesn't fit into
+/// `slab`, an error is returned and `fill_slab` is not called.
+///
+/// `fill_slab` is a function which takes as parameters first an aligned (for T)
+/// [`*mut c_void`](c_void) and second the number of `T`s returned by `query_len`, which is the
+/// capacity of the buffer behind the pointer. It must then write a slice of `T`s into the given
+/// pointer and return the length, in units of `T`, of the slice it wrote. If this is greater than
+/// the capacity it was given, an error is returned.
+///
+/// The `*mut c_void` pointer given to `fill_slab` will be as close to the beginning of `slab` as
+/// possible while upholding the alignment requirements of `T`.
+///
+/// # Safety
+///
+/// You must during the execution of `fill_slab` **fully-initialize** a **valid**\* slice of `T`
+/// beginning at the given pointer and with length greater than or equal to the length you return
+/// from that function, and must not write past the capacity it was given.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+pub unsafe fn readback_sized_from_ffi<'a, T, S, Q, F>(
+    slab: &'a mut S,
+    query_len: Q,
+    fill_slab: F,
+) -> Result<&'a [T], Error>
+where
+    S: Slab + ?Sized,
+    Q: FnOnce() -> usize,
+    F: FnOnce(*mut c_void, usize) -> usize,
+{
+    let capacity = query_len();
+    let layout = Layout::array::<T>(capacity)?;
+    let offsets = compute_and_validate_offsets(slab, 0, layout, 1, false)?;
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offsets.start) }.cast::<c_void>();
+
+    let written_n_of_ts = fill_slab(ptr, capacity);
+    if written_n_of_ts > capacity {
+        let claimed_size = Layout::array::<T>(written_n_of_ts)?.size();
+        return Err(Error::out_of_memory(
+            0,
+            offsets.start.saturating_add(claimed_size),
+            slab.size(),
+        ));
+    }
+
+    let ptr = ptr.cast::<T>().cast_const();
+
+    // SAFETY:
+    // - `ptr` is properly aligned, checked by us
+    // - `slab` contains enough space for `[T; capacity]` at `ptr`, checked by us, and
+    // `written_n_of_ts <= capacity`
+    // - if the function-level safety guarantees are met, then:
+    //     - `ptr` contains `written_n_of_ts` previously-placed `T`s
+    //     - we have mutable access to all of `slab`, which includes `ptr`.
+    Ok(unsafe { core::slice::from_raw_parts(ptr, written_n_of_ts) })
+}
+
+/// Helper to read back data from an ffi function which writes a header `H` containing a count,
+/// followed directly by that many `T`s, as laid out by a C struct ending in an array.
+///
+/// `count_of` is a function which must return the number of `T`s following the header, usually
+/// by reading it from one of its fields.
+///
+/// `fill_slab` is a function which takes as parameters first a [`*mut c_void`](c_void) aligned
+/// for both `H` and `T` and second the number of bytes left in `slab` available for writing. It
+/// must then write the header and the `T`s into the given pointer. The `T`s are placed directly
+/// after the header, padded to the alignment of `T`.
+///
+/// The function will return an error if the header or the number of `T`s it reports doesn't fit
+/// into `slab`, in the latter case after calling `fill_slab`.
+///
+/// # Safety
+///
+/// You must during the execution of `fill_slab` **fully-initialize** a **valid**\* `H` at the
+/// given pointer and as many **valid** `T`s after it as reported by `count_of`.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+pub unsafe fn readback_length_prefixed_from_ffi<'a, H, T, S, C, F>(
+    slab: &'a mut S,
+    count_of: C,
+    fill_slab: F,
+) -> Result<(&'a H, &'a [T]), Error>
+where
+    S: Slab + ?Sized,
+    C: FnOnce(&H) -> usize,
+    F: FnOnce(*mut c_void, usize),
+{
+    let header_layout = Layout::new::<H>().align_to(core::mem::align_of::<T>())?;
+    let offsets = compute_and_validate_offsets(slab, 0, header_layout, 1, false)?;
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offsets.start) }.cast::<c_void>();
+
+    fill_slab(ptr, slab.size() - offsets.start);
+
+    // SAFETY:
+    // - `ptr` is properly aligned for `H`, checked by us
+    // - `slab` contains enough space for `H` at `ptr`, checked by us
+    // - if the function-level safety guarantees are met, `ptr` contains a previously-placed `H`
+    let header = unsafe { &*ptr.cast::<H>().cast_const() };
+    let count = count_of(header);
+
+    // SAFETY: the header was placed at `ptr` and if the function-level safety guarantees are
+    // met, so were `count` `T`s after it
+    unsafe { header_and_slice_at(ptr.cast_const(), offsets.start, slab.size(), count) }
+}
+
+/// Helper to read back data from an ffi function which writes a C struct ending in a flexible
+/// array member, that is a header `H` followed directly by a variable number of `T`s.
+///
+/// `fill_slab` is a function which takes as parameters first a [`*mut c_void`](c_void) aligned
+/// for both `H` and `T` and second the maximum number of `T`s which fit in `slab` after the
+/// header. It must then write the header and the `T`s into the given pointer and return the
+/// number of `T`s it wrote. If that doesn't fit into `slab`, an error is returned.
+///
+/// The `T`s are expected directly after the header, padded to the alignment of `T`. Note that
+/// this uses the size of `H` including its trailing padding, which can be more than the offset of
+/// the flexible array member in C if `T` is less aligned than `H`. In that case, use an `H`
+/// without trailing padding, for example by wrapping some of its fields in [`Unalign`].
+///
+/// # Safety
+///
+/// You must during the execution of `fill_slab` **fully-initialize** a **valid**\* `H` at the
+/// given pointer and as many **valid** `T`s after it as you return from that function.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+pub unsafe fn readback_header_and_slice_from_ffi<'a, H, T, S, F>(
+    slab: &'a mut S,
+    fill_slab: F,
+) -> Result<(&'a H, &'a [T]), Error>
+where
+    S: Slab + ?Sized,
+    F: FnOnce(*mut c_void, usize) -> usize,
+{
+    let header_layout = Layout::new::<H>().align_to(core::mem::align_of::<T>())?;
+    let offsets = compute_and_validate_offsets(slab, 0, header_layout, 1, false)?;
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offsets.start) }.cast::<c_void>();
+
+    let (_, elements_offset) = Layout::new::<H>().extend(Layout::new::<[T; 0]>())?;
+    let max_elements = (slab.size() - offsets.start)
+        .saturating_sub(elements_offset)
+        .checked_div(core::mem::size_of::<T>())
+        .unwrap_or(usize::MAX);
+    let count = fill_slab(ptr, max_elements);
+
+    // SAFETY: if the function-level safety guarantees are met, the header was placed at `ptr`,
+    // followed by `count` `T`s
+    unsafe { header_and_slice_at(ptr.cast_const(), offsets.start, slab.size(), count) }
+}
+
+/// Gets the header `H` at `ptr`, which is `start` bytes into a slab of `slab_size` bytes, and the
+/// `count` `T`s directly after it, padded to the alignment of `T`.
+///
+/// # Safety
+///
+/// `ptr` must be aligned for both `H` and `T`, and contain a previously-placed `H` followed by
+/// `count` previously-placed `T`s, if they fit into the slab.
+unsafe fn header_and_slice_at<'a, H, T>(
+    ptr: *const c_void,
+    start: usize,
+    slab_size: usize,
+    count: usize,
+) -> Result<(&'a H, &'a [T]), Error> {
+    let (layout, elements_offset) = Layout::new::<H>().extend(Layout::array::<T>(count)?)?;
+    let end = start.saturating_add(layout.size());
+    if end > slab_size {
+        return Err(Error::out_of_memory(0, end, slab_size));
+    }
+
+    // SAFETY:
+    // - `ptr` is properly aligned for `H` and `T`, and `elements_offset` is aligned for `T`
+    // - the slab contains enough space for the header and `[T; count]`, checked by us
+    // - the header and elements were previously placed, guaranteed by the caller
+    unsafe {
+        let header = &*ptr.cast::<H>();
+        let elements =
+            core::slice::from_raw_parts(ptr.cast::<u8>().add(elements_offset).cast::<T>(), count);
+        Ok((header, elements))
+    }
+}
+
 /// Gets a shared reference to a `T` within `slab` at `offset`.
 ///
 /// - `offset` is the offset, in bytes, after the start of `slab` at which a `T` is placed.
@@ -112,7 +556,7 @@ where
 /// \* Validity is a complex topic not to be taken lightly.
 /// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
 #[inline]
-pub unsafe fn read_at_offset<'a, T, S: Slab + ?Sized>(
+pub unsafe fn read_at_offset<'a, T, S: ReadSlab + ?Sized>(
     slab: &'a S,
     offset: usize,
 ) -> Result<&'a T, Error> {
@@ -120,7 +564,7 @@ pub unsafe fn read_at_offset<'a, T, S: Slab + ?Sized>(
     let offsets = compute_and_validate_offsets(slab, offset, t_layout, 1, true)?;
 
     // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
-    let ptr = unsafe { slab.base_ptr().add(offsets.start) }.cast::<T>();
+    let ptr = unsafe { slab.read_base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
 
     // SAFETY:
     // - `ptr` is properly aligned, checked by us
@@ -147,13 +591,13 @@ pub unsafe fn read_at_offset<'a, T, S: Slab + ?Sized>(
 /// \* Validity is a complex topic not to be taken lightly.
 /// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
 #[inline]
-pub unsafe fn read_at_offset_unchecked<'a, T, S: Slab + ?Sized>(
+pub unsafe fn read_at_offset_unchecked<'a, T, S: ReadSlab + ?Sized>(
     slab: &'a S,
     offset: usize,
 ) -> &'a T {
     // SAFETY: if offset is within the slab as guaranteed by function-level safety, this is
     // safe since a slab's size must be < isize::MAX
-    let ptr = unsafe { slab.base_ptr().add(offset) }.cast::<T>();
+    let ptr = unsafe { slab.read_base_non_null().as_ptr().add(offset) }.cast::<T>();
 
     // SAFETY:
     // - we have shared access to all of `slab`, which includes `ptr`.
@@ -164,6 +608,119 @@ pub unsafe fn read_at_offset_unchecked<'a, T, S: Slab + ?Sized>(
     unsafe { &*ptr }
 }
 
+/// Reads a copy of the `T` within `slab` at `offset`.
+///
+/// Unlike [`read_at_offset`], this doesn't keep a reference into `slab` alive, so `slab` may be
+/// mutably used again right away.
+///
+/// - `offset` is the offset, in bytes, after the start of `slab` at which a `T` is placed.
+///
+/// The function will return an error if:
+/// - `offset` within `slab` is not properly aligned for `T`
+/// - `offset` is out of bounds of the `slab`
+/// - `offset + size_of::<T>` is out of bounds of the `slab`
+///
+/// # Safety
+///
+/// You must have previously **fully-initialized** a **valid**\* `T` at the given offset into `slab`.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+#[inline]
+pub unsafe fn read_owned_at_offset<T: Copy, S: ReadSlab + ?Sized>(
+    slab: &S,
+    offset: usize,
+) -> Result<T, Error> {
+    let t_layout = Layout::new::<T>();
+    let offsets = compute_and_validate_offsets(slab, offset, t_layout, 1, true)?;
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let ptr = unsafe { slab.read_base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
+
+    // SAFETY:
+    // - `ptr` is properly aligned, checked by us
+    // - `slab` contains enough space for `T` at `ptr`, checked by us
+    // - if the function-level safety guarantees are met, `ptr` contains a previously-placed `T`
+    // - `T: Copy`, so reading it out doesn't duplicate any ownership.
+    Ok(unsafe { core::ptr::read(ptr) })
+}
+
+/// Reads a copy of the `T` within `slab` at `offset`, which doesn't need to be aligned for `T`.
+///
+/// This is useful for reading data from tightly packed layouts, such as file formats or FFI
+/// structs, where [`read_owned_at_offset`] would fail with
+/// [`ErrorKind::RequestedOffsetUnaligned`].
+///
+/// - `offset` is the offset, in bytes, after the start of `slab` at which a `T` is placed.
+///
+/// The function will return an error if:
+/// - `offset` is out of bounds of the `slab`
+/// - `offset + size_of::<T>` is out of bounds of the `slab`
+///
+/// # Safety
+///
+/// You must have previously **fully-initialized** a **valid**\* `T` at the given offset into `slab`.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+#[inline]
+pub unsafe fn read_unaligned_at_offset<T: Copy, S: ReadSlab + ?Sized>(
+    slab: &S,
+    offset: usize,
+) -> Result<T, Error> {
+    let layout = Layout::from_size_align(core::mem::size_of::<T>(), 1)?;
+    let offsets = compute_and_validate_offsets(slab, offset, layout, 1, true)?;
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let ptr = unsafe { slab.read_base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
+
+    // SAFETY:
+    // - `read_unaligned` has no alignment requirements
+    // - `slab` contains enough space for `T` at `ptr`, checked by us
+    // - if the function-level safety guarantees are met, `ptr` contains a previously-placed `T`
+    // - `T: Copy`, so reading it out doesn't duplicate any ownership.
+    Ok(unsafe { core::ptr::read_unaligned(ptr) })
+}
+
+/// Gets a shared reference to the NUL-terminated C string within `slab` at `offset`.
+///
+/// - `offset` is the offset, in bytes, after the start of `slab` at which the string begins.
+///
+/// The function will return an error if:
+/// - `offset` is out of bounds of the `slab`
+/// - there is no NUL byte between `offset` and the end of `slab`, in which case the error is of
+///   kind [`ErrorKind::OutOfMemory`]
+///
+/// # Safety
+///
+/// You must have previously **fully-initialized** the bytes at the given offset into `slab` up
+/// to and including the first NUL byte, or up to the end of `slab` if there is none.
+#[inline]
+pub unsafe fn read_cstr_at_offset<'a, S: ReadSlab + ?Sized>(
+    slab: &'a S,
+    offset: usize,
+) -> Result<&'a core::ffi::CStr, Error> {
+    let range = validate_range(slab, offset..)?;
+
+    // SAFETY: `validate_range` checked that `offset` is within the slab.
+    let ptr = unsafe { slab.read_base_non_null().as_ptr().add(range.start) };
+
+    // SAFETY:
+    // - the scan stays within `range`, which is within the slab
+    // - if the function-level safety guarantees are met, every byte up to and including the
+    // first NUL byte, or all of them if there is none, was previously initialized
+    let nul = (0..range.len())
+        .find(|&i| unsafe { *ptr.add(i) } == 0)
+        .ok_or(Error::new(ErrorKind::OutOfMemory))?;
+
+    // SAFETY:
+    // - the `nul + 1` bytes at `ptr` are within the slab and initialized, see above
+    // - they end with the only NUL byte among them, since it's the first one
+    Ok(unsafe {
+        core::ffi::CStr::from_bytes_with_nul_unchecked(core::slice::from_raw_parts(ptr, nul + 1))
+    })
+}
+
 /// Gets a mutable reference to a `T` within `slab` at `offset`.
 ///
 /// - `offset` is the offset, in bytes, after the start of `slab` at which a `T` is placed.
@@ -196,7 +753,7 @@ pub unsafe fn read_at_offset_mut<'a, T, S: Slab + ?Sized>(
     let offsets = compute_and_validate_offsets(slab, offset, t_layout, 1, true)?;
 
     // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
-    let ptr = unsafe { slab.base_ptr_mut().add(offsets.start) }.cast::<T>();
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
 
     // SAFETY:
     // - `ptr` is properly aligned, checked by us
@@ -219,7 +776,7 @@ pub unsafe fn read_at_offset_mut<'a, T, S: Slab + ?Sized>(
 /// - `offset` is within bounds of the `slab`
 /// - `offset + size_of::<T>` is within bounds of the `slab`
 /// - You must have previously **fully-initialized** a **valid** `T` at the given offset into `slab`. If you want to fill an uninitialized
-/// buffer with data, you should instead use any of the copy helper functions or one of the `maybe_uninit_mut` read functions.
+///   buffer with data, you should instead use any of the copy helper functions or one of the `maybe_uninit_mut` read functions.
 ///
 /// **Note that *if you write through the returned reference***, any *padding bytes* within the layout of `T`
 /// (which for a `repr(Rust)` type is arbitrary and unknown) must thereafter be considered *uninitialized*
@@ -237,7 +794,7 @@ pub unsafe fn read_at_offset_mut_unchecked<'a, T, S: Slab + ?Sized>(
 ) -> &'a mut T {
     // SAFETY: if offset is within the slab as guaranteed by function-level safety, this is
     // safe since a slab's size must be < isize::MAX
-    let ptr = unsafe { slab.base_ptr_mut().add(offset) }.cast::<T>();
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offset) }.cast::<T>();
 
     // SAFETY:
     // - we have mutable access to all of `slab`, which includes `ptr`.
@@ -280,7 +837,7 @@ pub fn get_maybe_uninit_at_offset_mut<'a, T, S: Slab + ?Sized>(
     let offsets = compute_and_validate_offsets(slab, offset, t_layout, 1, true)?;
 
     // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
-    let ptr = unsafe { slab.base_ptr_mut().add(offsets.start) }.cast::<MaybeUninit<T>>();
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offsets.start) }.cast::<MaybeUninit<T>>();
 
     // SAFETY:
     // - `ptr` is properly aligned, checked by us
@@ -320,7 +877,7 @@ pub unsafe fn get_maybe_uninit_at_offset_mut_unchecked<'a, T, S: Slab + ?Sized>(
 ) -> &'a mut MaybeUninit<T> {
     // SAFETY: if offset is within the slab as guaranteed by function-level safety, this is
     // safe since a slab's size must be < isize::MAX
-    let ptr = unsafe { slab.base_ptr_mut().add(offset) }.cast::<MaybeUninit<T>>();
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offset) }.cast::<MaybeUninit<T>>();
 
     // SAFETY:
     // - we have mutable access to all of `slab`, which includes `ptr`.
@@ -347,19 +904,19 @@ pub unsafe fn get_maybe_uninit_at_offset_mut_unchecked<'a, T, S: Slab + ?Sized>(
 /// \* Validity is a complex topic not to be taken lightly.
 /// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
 #[inline]
-pub unsafe fn read_slice_at_offset<'a, T, S: Slab + ?Sized>(
+pub unsafe fn read_slice_at_offset<'a, T, S: ReadSlab + ?Sized>(
     slab: &'a S,
     offset: usize,
     len: usize,
 ) -> Result<&'a [T], Error> {
     let t_layout = match Layout::array::<T>(len) {
         Ok(layout) => layout,
-        Err(_) => return Err(Error::InvalidLayout),
+        Err(_) => return Err(Error::invalid_layout()),
     };
     let offsets = compute_and_validate_offsets(slab, offset, t_layout, 1, true)?;
 
     // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
-    let ptr = unsafe { slab.base_ptr().add(offsets.start) }.cast::<T>();
+    let ptr = unsafe { slab.read_base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
 
     // SAFETY:
     // - `ptr` is properly aligned, checked by us
@@ -388,14 +945,14 @@ pub unsafe fn read_slice_at_offset<'a, T, S: Slab + ?Sized>(
 /// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
 /// - See also safety docs of [`core::slice::from_raw_parts`].
 #[inline]
-pub unsafe fn read_slice_at_offset_unchecked<'a, T, S: Slab + ?Sized>(
+pub unsafe fn read_slice_at_offset_unchecked<'a, T, S: ReadSlab + ?Sized>(
     slab: &'a S,
     offset: usize,
     len: usize,
 ) -> &'a [T] {
     // SAFETY: if offset is within the slab as guaranteed by function-level safety, this is
     // safe since a slab's size must be < isize::MAX
-    let ptr = unsafe { slab.base_ptr().add(offset) }.cast::<T>();
+    let ptr = unsafe { slab.read_base_non_null().as_ptr().add(offset) }.cast::<T>();
 
     // SAFETY:
     // - we have shared access to all of `slab`, which includes `ptr`.
@@ -438,12 +995,12 @@ pub unsafe fn read_slice_at_offset_mut<'a, T, S: Slab + ?Sized>(
 ) -> Result<&'a mut [T], Error> {
     let t_layout = match Layout::array::<T>(len) {
         Ok(layout) => layout,
-        Err(_) => return Err(Error::InvalidLayout),
+        Err(_) => return Err(Error::invalid_layout()),
     };
     let offsets = compute_and_validate_offsets(slab, offset, t_layout, 1, true)?;
 
     // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
-    let ptr = unsafe { slab.base_ptr_mut().add(offsets.start) }.cast::<T>();
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
 
     // SAFETY:
     // - `ptr` is properly aligned, checked by us
@@ -467,7 +1024,7 @@ pub unsafe fn read_slice_at_offset_mut<'a, T, S: Slab + ?Sized>(
 /// - `offset` is within bounds of the `slab`
 /// - `offset + size_of::<T> * len` is within bounds of the `slab`
 /// - You must have previously **fully-initialized** a **valid** a `[T; len]` at the given offset into `slab`. If you want to fill an uninitialized
-/// buffer with data, you should instead use any of the copy helper functions or one of the `maybe_uninit_mut` read functions.
+///   buffer with data, you should instead use any of the copy helper functions or one of the `maybe_uninit_mut` read functions.
 /// - See also safety docs of [`core::slice::from_raw_parts_mut`].
 ///
 /// **Note that *if you write through the returned reference***, any *padding bytes* within the layout of `T`
@@ -487,7 +1044,7 @@ pub unsafe fn read_slice_at_offset_mut_unchecked<'a, T, S: Slab + ?Sized>(
 ) -> &'a mut [T] {
     // SAFETY: if offset is within the slab as guaranteed by function-level safety, this is
     // safe since a slab's size must be < isize::MAX
-    let ptr = unsafe { slab.base_ptr_mut().add(offset) }.cast::<T>();
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offset) }.cast::<T>();
 
     // SAFETY:
     // - we have shared access to all of `slab`, which includes `ptr`.
@@ -530,12 +1087,12 @@ pub fn get_maybe_uninit_slice_at_offset_mut<'a, T, S: Slab + ?Sized>(
 ) -> Result<&'a mut [MaybeUninit<T>], Error> {
     let t_layout = match Layout::array::<T>(len) {
         Ok(layout) => layout,
-        Err(_) => return Err(Error::InvalidLayout),
+        Err(_) => return Err(Error::invalid_layout()),
     };
     let offsets = compute_and_validate_offsets(slab, offset, t_layout, 1, true)?;
 
     // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
-    let ptr = unsafe { slab.base_ptr_mut().add(offsets.start) }.cast::<MaybeUninit<T>>();
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offsets.start) }.cast::<MaybeUninit<T>>();
 
     // SAFETY:
     // - `ptr` is properly aligned, checked by us
@@ -578,7 +1135,7 @@ pub unsafe fn get_maybe_uninit_slice_at_offset_mut_unchecked<'a, T, S: Slab + ?S
 ) -> &'a mut [MaybeUninit<T>] {
     // SAFETY: if offset is within the slab as guaranteed by function-level safety, this is
     // safe since a slab's size must be < isize::MAX
-    let ptr = unsafe { slab.base_ptr_mut().add(offset) }.cast::<MaybeUninit<T>>();
+    let ptr = unsafe { slab.base_non_null().as_ptr().add(offset) }.cast::<MaybeUninit<T>>();
 
     // SAFETY:
     // - we have shared access to all of `slab`, which includes `ptr`.
@@ -587,3 +1144,232 @@ pub unsafe fn get_maybe_uninit_slice_at_offset_mut_unchecked<'a, T, S: Slab + ?S
     //     - `slab` contains enough space for the slice's layout, checked by us
     unsafe { core::slice::from_raw_parts_mut(ptr, len) }
 }
+
+/// Gets shared references to a `T` within `slab` at each of `offsets`.
+///
+/// - `offsets` are the offsets, in bytes, after the start of `slab` at which a `T` is placed, for
+///   example the `start_offset`s of the [`CopyRecord`]s returned when copying them in. `offsets` is
+///   iterated twice, hence the `Clone` requirement on its iterator.
+///
+/// Every offset is validated before the returned iterator is created, so it never fails part way
+/// through. The function will return an error if, for any offset:
+/// - `offset` within `slab` is not properly aligned for `T`
+/// - `offset` is out of bounds of the `slab`
+/// - `offset + size_of::<T>` is out of bounds of the `slab`
+///
+/// # Safety
+///
+/// You must have previously **fully-initialized** a **valid**\* `T` at each of the given offsets
+/// into `slab`, and `offsets` must yield the same offsets each time it is iterated.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+#[inline]
+pub unsafe fn gather_read<'a, T, S, I>(
+    slab: &'a S,
+    offsets: I,
+) -> Result<impl Iterator<Item = &'a T> + 'a, Error>
+where
+    T: 'a,
+    S: ReadSlab + ?Sized,
+    I: IntoIterator<Item = usize>,
+    I::IntoIter: Clone + 'a,
+{
+    let offsets = offsets.into_iter();
+    let t_layout = Layout::new::<T>();
+
+    for offset in offsets.clone() {
+        compute_and_validate_offsets(slab, offset, t_layout, 1, true)?;
+    }
+
+    // SAFETY:
+    // - every offset has been checked to be in bounds and properly aligned for `T`, and function-level
+    // safety requires `offsets` to yield the same offsets again.
+    // - if the function-level safety guarantees are met, each offset contains a previously-placed `T`
+    Ok(offsets.map(move |offset| unsafe { read_at_offset_unchecked(slab, offset) }))
+}
+
+/// Writes the bytes of `slab` within `range` to `writer`, without copying them into an
+/// intermediate buffer first.
+///
+/// This is useful for dumping readback buffers to files or sockets.
+///
+/// The function will return an error of kind [`InvalidInput`][std::io::ErrorKind::InvalidInput],
+/// wrapping the respective [`Error`], if `range` is out of bounds of `slab`. Otherwise, any error
+/// returned by `writer` is passed through.
+///
+/// # Safety
+///
+/// You must have previously **fully-initialized** all bytes of `slab` within `range`.
+#[cfg(feature = "std")]
+pub unsafe fn write_range_to<S, R, W>(slab: &S, range: R, writer: &mut W) -> std::io::Result<()>
+where
+    S: ReadSlab + ?Sized,
+    R: RangeBounds<usize>,
+    W: std::io::Write + ?Sized,
+{
+    let range = validate_range(slab, range)?;
+
+    // SAFETY:
+    // - `range` is within bounds of `slab`, checked by us
+    // - `u8` has no alignment requirements
+    // - if the function-level safety guarantees are met, all bytes within `range` are initialized
+    // - we have shared access to all of `slab`, which includes `range`.
+    let bytes = unsafe { read_slice_at_offset_unchecked::<u8, S>(slab, range.start, range.len()) };
+    writer.write_all(bytes)
+}
+
+/// Compares the bytes of `slab_a` within `range_a` with the bytes of `slab_b` within `range_b`,
+/// without copying either side into an intermediate buffer first.
+///
+/// Returns the first offset, relative to the start of both ranges, at which they differ, or
+/// `None` if they are equal. If one range is a prefix of the other but they have different
+/// lengths, the returned offset is the length of the shorter range.
+///
+/// This is useful for validating data after a round-trip through a device, by comparing the
+/// readback buffer with the uploaded one.
+///
+/// The function will return an error if either range is out of bounds of its slab.
+///
+/// # Safety
+///
+/// You must have previously **fully-initialized** all bytes of `slab_a` within `range_a` and
+/// all bytes of `slab_b` within `range_b`.
+pub unsafe fn diff_ranges<A, RA, B, RB>(
+    slab_a: &A,
+    range_a: RA,
+    slab_b: &B,
+    range_b: RB,
+) -> Result<Option<usize>, Error>
+where
+    A: ReadSlab + ?Sized,
+    RA: RangeBounds<usize>,
+    B: ReadSlab + ?Sized,
+    RB: RangeBounds<usize>,
+{
+    let range_a = validate_range(slab_a, range_a)?;
+    let range_b = validate_range(slab_b, range_b)?;
+
+    // SAFETY:
+    // - both ranges are within bounds of their slabs, checked by us
+    // - `u8` has no alignment requirements
+    // - if the function-level safety guarantees are met, all bytes within both ranges are
+    // initialized
+    // - we have shared access to all of both slabs, which includes the ranges.
+    let (a, b) = unsafe {
+        (
+            read_slice_at_offset_unchecked::<u8, A>(slab_a, range_a.start, range_a.len()),
+            read_slice_at_offset_unchecked::<u8, B>(slab_b, range_b.start, range_b.len()),
+        )
+    };
+
+    let first_difference = a.iter().zip(b).position(|(a, b)| a != b);
+    Ok(first_difference.or_else(|| (a.len() != b.len()).then(|| a.len().min(b.len()))))
+}
+
+/// Checks whether the `T` within `slab` at `offset` is equal to `expected`.
+///
+/// The values are compared with [`PartialEq`], so padding bytes within `T` are never compared,
+/// and types with padding can be verified as well.
+///
+/// - `offset` is the offset, in bytes, after the start of `slab` at which a `T` is placed.
+///
+/// The function will return an error in the same cases as [`read_at_offset`].
+///
+/// # Safety
+///
+/// You must have previously **fully-initialized** a **valid**\* `T` at the given offset into `slab`.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+#[inline]
+pub unsafe fn verify_at_offset<T: PartialEq, S: ReadSlab + ?Sized>(
+    expected: &T,
+    slab: &S,
+    offset: usize,
+) -> Result<bool, Error> {
+    // SAFETY: same safety requirements
+    let actual = unsafe { read_at_offset::<T, S>(slab, offset) }?;
+    Ok(actual == expected)
+}
+
+/// Checks whether the `[T; expected.len()]` within `slab` at `offset` is equal to `expected`.
+///
+/// The elements are compared with [`PartialEq`], so padding bytes within `T` are never compared,
+/// and types with padding can be verified as well.
+///
+/// - `offset` is the offset, in bytes, after the start of `slab` at which the first `T` is placed.
+///
+/// The function will return an error in the same cases as [`read_slice_at_offset`].
+///
+/// # Safety
+///
+/// You must have previously **fully-initialized** a **valid**\* `[T; expected.len()]` at the
+/// given offset into `slab`.
+///
+/// \* Validity is a complex topic not to be taken lightly.
+/// See [this rust reference page](https://doc.rust-lang.org/reference/behavior-considered-undefined.html) for more details.
+#[inline]
+pub unsafe fn verify_slice_at_offset<T: PartialEq, S: ReadSlab + ?Sized>(
+    expected: &[T],
+    slab: &S,
+    offset: usize,
+) -> Result<bool, Error> {
+    // SAFETY: same safety requirements
+    let actual = unsafe { read_slice_at_offset::<T, S>(slab, offset, expected.len()) }?;
+    Ok(actual == expected)
+}
+
+/// Feeds the bytes of `slab` within `range` into `hasher`, without copying them into an
+/// intermediate buffer first.
+///
+/// This is useful for taking a cheap fingerprint of data right after copying it, for example
+/// to detect identical blocks between frames. Only the bytes are hashed, not the length of
+/// `range`.
+///
+/// The function will return an error if `range` is out of bounds of `slab`, in which case
+/// nothing is fed into `hasher`.
+///
+/// # Safety
+///
+/// You must have previously **fully-initialized** all bytes of `slab` within `range`. Note that
+/// padding bytes, both between copies and within types, may be uninitialized.
+/// [`CopyOptions::zero_padding`] can be used to initialize the former.
+pub unsafe fn hash_range<S, R, H>(slab: &S, range: R, hasher: &mut H) -> Result<(), Error>
+where
+    S: ReadSlab + ?Sized,
+    R: RangeBounds<usize>,
+    H: core::hash::Hasher + ?Sized,
+{
+    let range = validate_range(slab, range)?;
+
+    // SAFETY:
+    // - `range` is within bounds of `slab`, checked by us
+    // - `u8` has no alignment requirements
+    // - if the function-level safety guarantees are met, all bytes within `range` are initialized
+    // - we have shared access to all of `slab`, which includes `range`.
+    let bytes = unsafe { read_slice_at_offset_unchecked::<u8, S>(slab, range.start, range.len()) };
+    hasher.write(bytes);
+    Ok(())
+}
+
+/// Computes the CRC-32 (as used by zlib, PNG, etc.) of the bytes of `slab` within `range`,
+/// without copying them into an intermediate buffer first.
+///
+/// The function will return an error if `range` is out of bounds of `slab`.
+///
+/// # Safety
+///
+/// You must have previously **fully-initialized** all bytes of `slab` within `range`. See
+/// [`hash_range`] for more.
+#[cfg(feature = "crc32")]
+pub unsafe fn crc32_range<S, R>(slab: &S, range: R) -> Result<u32, Error>
+where
+    S: ReadSlab + ?Sized,
+    R: RangeBounds<usize>,
+{
+    let mut hasher = crc32fast::Hasher::new();
+    // SAFETY: same safety requirements
+    unsafe { hash_range(slab, range, &mut hasher) }?;
+    Ok(hasher.finalize())
+}
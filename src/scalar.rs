@@ -0,0 +1,84 @@
+use super::*;
+
+/// Writes `value` into `dst` at *exactly* `offset` bytes past the start of `dst`, with the
+/// byte order given by `endianness`, returning the offset directly after the written value.
+///
+/// Unlike the copy functions, this does *not* align the destination offset: `offset` does not
+/// need to be aligned for `T`. This makes it handy for patching individual fields of an
+/// already-built buffer (for example a file or packet header) without having to construct and
+/// copy a whole struct. Since the returned offset is directly after the written value,
+/// sequential writes can be chained.
+///
+/// The function will return an error if:
+/// - `offset` is out of bounds of `dst`
+/// - `offset + size_of::<T>()` is out of bounds of `dst`
+#[inline]
+pub fn write_scalar_at<T: EndianScalar, S: Slab + ?Sized>(
+    value: T,
+    dst: &mut S,
+    offset: usize,
+    endianness: Endianness,
+) -> Result<usize, Error> {
+    let layout = Layout::from_size_align(core::mem::size_of::<T>(), 1)?;
+    let offsets = compute_and_validate_offsets(&*dst, offset, layout, 1, true)?;
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<T>();
+
+    // SAFETY:
+    // - dst is valid so long as requirements for `slab` were met, i.e.
+    // we have unique access to the region described and that it is valid for the duration
+    // of 'a.
+    // - checked that write stays within bounds of our allocation
+    // - `write_unaligned` has no alignment requirements
+    unsafe {
+        dst_ptr.write_unaligned(value.to_endianness(endianness));
+    }
+
+    Ok(offsets.end)
+}
+
+macro_rules! scalar_writers {
+    ($($t:ty => $native:ident, $le:ident, $be:ident;)*) => {
+        $(
+            #[doc = concat!("Writes a `", stringify!($t), "` in native byte order at *exactly* `offset` bytes past the start of `dst`.")]
+            ///
+            /// Returns the offset directly after the written value. See [`write_scalar_at`] for more.
+            #[inline]
+            pub fn $native<S: Slab + ?Sized>(value: $t, dst: &mut S, offset: usize) -> Result<usize, Error> {
+                write_scalar_at(value, dst, offset, Endianness::Native)
+            }
+
+            #[doc = concat!("Writes a `", stringify!($t), "` in little-endian byte order at *exactly* `offset` bytes past the start of `dst`.")]
+            ///
+            /// Returns the offset directly after the written value. See [`write_scalar_at`] for more.
+            #[inline]
+            pub fn $le<S: Slab + ?Sized>(value: $t, dst: &mut S, offset: usize) -> Result<usize, Error> {
+                write_scalar_at(value, dst, offset, Endianness::Little)
+            }
+
+            #[doc = concat!("Writes a `", stringify!($t), "` in big-endian byte order at *exactly* `offset` bytes past the start of `dst`.")]
+            ///
+            /// Returns the offset directly after the written value. See [`write_scalar_at`] for more.
+            #[inline]
+            pub fn $be<S: Slab + ?Sized>(value: $t, dst: &mut S, offset: usize) -> Result<usize, Error> {
+                write_scalar_at(value, dst, offset, Endianness::Big)
+            }
+        )*
+    };
+}
+
+scalar_writers! {
+    u8 => write_u8_at, write_u8_le_at, write_u8_be_at;
+    u16 => write_u16_at, write_u16_le_at, write_u16_be_at;
+    u32 => write_u32_at, write_u32_le_at, write_u32_be_at;
+    u64 => write_u64_at, write_u64_le_at, write_u64_be_at;
+    u128 => write_u128_at, write_u128_le_at, write_u128_be_at;
+    i8 => write_i8_at, write_i8_le_at, write_i8_be_at;
+    i16 => write_i16_at, write_i16_le_at, write_i16_be_at;
+    i32 => write_i32_at, write_i32_le_at, write_i32_be_at;
+    i64 => write_i64_at, write_i64_le_at, write_i64_be_at;
+    i128 => write_i128_at, write_i128_le_at, write_i128_be_at;
+    f32 => write_f32_at, write_f32_le_at, write_f32_be_at;
+    f64 => write_f64_at, write_f64_le_at, write_f64_be_at;
+}
@@ -0,0 +1,324 @@
+use super::*;
+
+/// A growable container of `Item`s with contiguous storage and spare capacity after its
+/// initialized elements, such as a `Vec`.
+///
+/// Implemented for `Vec<T>` (with the `std` feature), and for the containers of some other crates
+/// behind features of the same name. See [`SpareCapacitySlab`].
+///
+/// # Safety
+///
+/// - `spare_capacity_ptr` must return a pointer to the start of the container's spare capacity,
+///   directly after its initialized items, which must be valid for reads and writes of
+///   `capacity() - len()` items. It must stay valid until `set_len` is called or the container is
+///   mutated through some other means than the methods of this trait.
+/// - `capacity` must not change unless the container is mutated through some other means than
+///   the methods of this trait.
+/// - `set_len` must set the number of initialized items, which are at the start of the storage.
+pub unsafe trait SpareCapacity {
+    /// The type of item stored in the container.
+    type Item;
+
+    /// The number of initialized items in the container.
+    fn len(&self) -> usize;
+
+    /// Whether the container has no initialized items.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The total number of items the container has storage for.
+    fn capacity(&self) -> usize;
+
+    /// A pointer to the start of the container's spare capacity, directly after its initialized
+    /// items.
+    fn spare_capacity_ptr(&mut self) -> *mut Self::Item;
+
+    /// Set the number of initialized items in the container.
+    ///
+    /// # Safety
+    ///
+    /// `len` must be at most `capacity()`, and the first `len` items must be initialized.
+    unsafe fn set_len(&mut self, len: usize);
+}
+
+/// A [`Slab`] made up of the spare capacity of a container such as a `Vec`, which takes care of
+/// extending the container's length to cover the data copied into it.
+///
+/// Offsets into the slab are relative to the start of the spare capacity, i.e. offset 0 is
+/// directly after the last item already in the container. After copying data into the slab, report
+/// the returned [`CopyRecord`]s with [`SpareCapacitySlab::mark_initialized`]. When the slab is
+/// committed with [`SpareCapacitySlab::commit`] or dropped, the length of the container is extended
+/// by the number of whole items in the initialized prefix of the slab.
+///
+/// This is a safer alternative to [`maybe_uninit_slice_from_vec`], which leaves updating the
+/// length of the `Vec` entirely to you.
+pub struct SpareCapacitySlab<'a, C: SpareCapacity + ?Sized> {
+    // a pointer rather than a reference, since moving a `&mut C` around would invalidate
+    // `base_ptr` for containers with inline storage
+    container: NonNull<C>,
+    base_ptr: *mut u8,
+    initialized_len: usize,
+    phantom: PhantomData<&'a mut C>,
+}
+
+/// A [`SpareCapacitySlab`] over the spare capacity of a `Vec<T>`.
+#[cfg(feature = "std")]
+pub type VecSlab<'a, T> = SpareCapacitySlab<'a, Vec<T>>;
+
+impl<'a, C: SpareCapacity + ?Sized> SpareCapacitySlab<'a, C> {
+    /// Make a slab out of the spare capacity of `container`.
+    ///
+    /// You may want to reserve enough capacity beforehand.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the item type is zero-sized.
+    pub fn new(container: &'a mut C) -> Self {
+        assert!(
+            core::mem::size_of::<C::Item>() != 0,
+            "cannot make a SpareCapacitySlab for a zero-sized type"
+        );
+        let mut container = NonNull::from(container);
+        // SAFETY: `container` was just made from a unique reference.
+        let base_ptr = unsafe { container.as_mut() }.spare_capacity_ptr().cast();
+        Self {
+            container,
+            base_ptr,
+            initialized_len: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn container(&self) -> &C {
+        // SAFETY: we borrow the container mutably for `'a`, and only ever access it through this
+        // pointer and `base_ptr`.
+        unsafe { self.container.as_ref() }
+    }
+
+    /// Report that the data described by `record` has been copied into the slab.
+    ///
+    /// Only the initialized *prefix* of the slab is tracked: if `record` starts after the end of
+    /// the data reported so far, it is ignored. Report records in the order the copies were made,
+    /// which is the natural order when copying data one after another.
+    ///
+    /// # Safety
+    ///
+    /// The whole range of bytes from the start of the slab up to the end of `record` must have
+    /// been initialized, with valid items at each multiple of the item size. This is the case
+    /// when copying items (or slices of them) one after another, each starting where the last
+    /// one ended.
+    #[inline]
+    pub unsafe fn mark_initialized(&mut self, record: CopyRecord) {
+        if record.start_offset <= self.initialized_len {
+            self.initialized_len = self.initialized_len.max(record.end_offset.min(self.size()));
+        }
+    }
+
+    /// The number of whole items which will be added to the container when the slab is committed.
+    #[inline]
+    pub fn initialized_count(&self) -> usize {
+        self.initialized_len / core::mem::size_of::<C::Item>()
+    }
+
+    /// Extend the length of the container to cover the initialized prefix of the slab, returning
+    /// the number of items that were added.
+    ///
+    /// This is also done when the slab is dropped.
+    pub fn commit(mut self) -> usize {
+        let count = self.initialized_count();
+        self.set_container_len();
+        count
+    }
+
+    fn set_container_len(&mut self) {
+        let count = self.initialized_count();
+        self.initialized_len = 0;
+        let new_len = self.container().len() + count;
+        // SAFETY:
+        // - `count` is at most the spare capacity of the container, since we clamp the initialized
+        // length to the size of the slab
+        // - if the safety requirements of `mark_initialized` were met, the first `count` items
+        // of the spare capacity are initialized
+        // - `base_ptr` isn't used after this, since we are being committed or dropped
+        unsafe { self.container.as_mut().set_len(new_len) }
+    }
+}
+
+impl<'a, C: SpareCapacity + ?Sized> Drop for SpareCapacitySlab<'a, C> {
+    fn drop(&mut self) {
+        self.set_container_len();
+    }
+}
+
+// SAFETY: `base_ptr` is valid for the whole spare capacity of the container as guaranteed by
+// `SpareCapacity`, and we have exclusive access to it for as long as we are borrowed. The length
+// of the container only changes when we are committed or dropped, so `base_ptr` stays valid and
+// consistent with `size` while we can be used as a slab.
+unsafe impl<'a, C: SpareCapacity + ?Sized> Slab for SpareCapacitySlab<'a, C> {
+    #[inline]
+    fn base_ptr(&self) -> *const u8 {
+        self.base_ptr
+    }
+
+    #[inline]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.base_ptr
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        let container = self.container();
+        (container.capacity() - container.len()) * core::mem::size_of::<C::Item>()
+    }
+}
+
+// SAFETY: the slab only gives access to the spare capacity of the container it borrows mutably, so
+// it may be sent between threads just like a `&mut C`.
+unsafe impl<'a, C: SpareCapacity + Send + ?Sized> Send for SpareCapacitySlab<'a, C> {}
+// SAFETY: the spare capacity is only accessed through `&self` by reading, like through a `&C`.
+unsafe impl<'a, C: SpareCapacity + Sync + ?Sized> Sync for SpareCapacitySlab<'a, C> {}
+
+// SAFETY: `Vec` upholds all of these guarantees for its inherent methods of the same names, and
+// `as_mut_ptr` points to its whole storage.
+#[cfg(feature = "std")]
+unsafe impl<T> SpareCapacity for Vec<T> {
+    type Item = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        Vec::capacity(self)
+    }
+
+    #[inline]
+    fn spare_capacity_ptr(&mut self) -> *mut T {
+        let len = Vec::len(self);
+        // SAFETY: one past the last item is still within (or one past the end of) the storage.
+        unsafe { Vec::as_mut_ptr(self).add(len) }
+    }
+
+    #[inline]
+    unsafe fn set_len(&mut self, len: usize) {
+        // SAFETY: same safety requirements
+        unsafe { Vec::set_len(self, len) }
+    }
+}
+
+/// A [`SpareCapacitySlab`] over the spare capacity of a [`bytes::BytesMut`].
+///
+/// Requires the `bytes` feature.
+#[cfg(feature = "bytes")]
+pub type BytesMutSlab<'a> = SpareCapacitySlab<'a, bytes::BytesMut>;
+
+// SAFETY: `BytesMut` upholds all of these guarantees for its inherent methods of the same names,
+// and `spare_capacity_mut` borrows exactly its spare capacity. Its dereferenced slice only covers
+// the initialized items, so it can't be used to access the spare capacity.
+#[cfg(feature = "bytes")]
+unsafe impl SpareCapacity for bytes::BytesMut {
+    type Item = u8;
+
+    #[inline]
+    fn len(&self) -> usize {
+        bytes::BytesMut::len(self)
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        bytes::BytesMut::capacity(self)
+    }
+
+    #[inline]
+    fn spare_capacity_ptr(&mut self) -> *mut u8 {
+        bytes::BytesMut::spare_capacity_mut(self)
+            .as_mut_ptr()
+            .cast()
+    }
+
+    #[inline]
+    unsafe fn set_len(&mut self, len: usize) {
+        // SAFETY: same safety requirements
+        unsafe { bytes::BytesMut::set_len(self, len) }
+    }
+}
+
+/// A [`SpareCapacitySlab`] over the spare capacity of an [`arrayvec::ArrayVec`].
+///
+/// Requires the `arrayvec` feature.
+#[cfg(feature = "arrayvec")]
+pub type ArrayVecSlab<'a, T, const CAP: usize> = SpareCapacitySlab<'a, arrayvec::ArrayVec<T, CAP>>;
+
+// SAFETY: `ArrayVec` upholds all of these guarantees for its inherent methods of the same names,
+// and `as_mut_ptr` points to its whole storage.
+#[cfg(feature = "arrayvec")]
+unsafe impl<T, const CAP: usize> SpareCapacity for arrayvec::ArrayVec<T, CAP> {
+    type Item = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        arrayvec::ArrayVec::len(self)
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        arrayvec::ArrayVec::capacity(self)
+    }
+
+    #[inline]
+    fn spare_capacity_ptr(&mut self) -> *mut T {
+        let len = arrayvec::ArrayVec::len(self);
+        // SAFETY: one past the last item is still within (or one past the end of) the storage.
+        unsafe { arrayvec::ArrayVec::as_mut_ptr(self).add(len) }
+    }
+
+    #[inline]
+    unsafe fn set_len(&mut self, len: usize) {
+        // SAFETY: same safety requirements
+        unsafe { arrayvec::ArrayVec::set_len(self, len) }
+    }
+}
+
+/// A [`SpareCapacitySlab`] over the spare capacity of a [`smallvec::SmallVec`].
+///
+/// Note that the storage of a `SmallVec` moves from inline to the heap when it grows, so reserve
+/// enough capacity before making the slab.
+///
+/// Requires the `smallvec` feature.
+#[cfg(feature = "smallvec")]
+pub type SmallVecSlab<'a, A> = SpareCapacitySlab<'a, smallvec::SmallVec<A>>;
+
+// SAFETY: `SmallVec` upholds all of these guarantees for its inherent methods of the same names,
+// and `as_mut_ptr` points to its whole storage.
+#[cfg(feature = "smallvec")]
+unsafe impl<A: smallvec::Array> SpareCapacity for smallvec::SmallVec<A> {
+    type Item = A::Item;
+
+    #[inline]
+    fn len(&self) -> usize {
+        smallvec::SmallVec::len(self)
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        smallvec::SmallVec::capacity(self)
+    }
+
+    #[inline]
+    fn spare_capacity_ptr(&mut self) -> *mut A::Item {
+        let len = smallvec::SmallVec::len(self);
+        // SAFETY: one past the last item is still within (or one past the end of) the storage.
+        unsafe { smallvec::SmallVec::as_mut_ptr(self).add(len) }
+    }
+
+    #[inline]
+    unsafe fn set_len(&mut self, len: usize) {
+        // SAFETY: same safety requirements
+        unsafe { smallvec::SmallVec::set_len(self, len) }
+    }
+}
@@ -0,0 +1,61 @@
+use super::*;
+
+use core::cell::UnsafeCell;
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::Ordering;
+
+/// An `N`-byte buffer meant to be placed in a `static`, which can be taken exactly once to get
+/// exclusive access to it as a [`Slab`].
+///
+/// This is useful on bare-metal targets, for example for DMA buffers in `.bss`, without having to
+/// write an unsafe singleton wrapper around a [`RawAllocation`].
+///
+/// ```
+/// # use presser::*;
+/// static BUFFER: StaticSlab<64> = StaticSlab::new();
+///
+/// let slab = BUFFER.take().unwrap();
+/// copy_to_offset(&42u32, slab, 0).unwrap();
+/// assert!(BUFFER.take().is_none());
+/// ```
+///
+/// Requires atomic compare-and-swap support on the target.
+pub struct StaticSlab<const N: usize> {
+    taken: AtomicBool,
+    bytes: UnsafeCell<[MaybeUninit<u8>; N]>,
+}
+
+// SAFETY: The only access to `bytes` is through `take`, which hands out a single exclusive
+// reference, guarded by `taken`.
+unsafe impl<const N: usize> Sync for StaticSlab<N> {}
+
+impl<const N: usize> StaticSlab<N> {
+    /// Make a new, uninitialized buffer.
+    pub const fn new() -> Self {
+        Self {
+            taken: AtomicBool::new(false),
+            bytes: UnsafeCell::new([MaybeUninit::uninit(); N]),
+        }
+    }
+
+    /// Take exclusive access to the buffer, which implements [`Slab`].
+    ///
+    /// Returns `None` if the buffer has already been taken.
+    // the returned reference is unique, guarded by `taken`.
+    #[allow(clippy::mut_from_ref)]
+    pub fn take(&'static self) -> Option<&'static mut [MaybeUninit<u8>; N]> {
+        if self.taken.swap(true, Ordering::AcqRel) {
+            return None;
+        }
+        // SAFETY: we just checked that no one has taken the buffer before, and made sure no one
+        // can in the future, so this is the only reference to it. It lives in a `static`, so it is
+        // valid for `'static`.
+        Some(unsafe { &mut *self.bytes.get() })
+    }
+}
+
+impl<const N: usize> Default for StaticSlab<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,156 @@
+use super::*;
+
+/// Wraps a [`Slab`] and keeps statistics about the copies made into it, to help answer how
+/// large a buffer actually needs to be.
+///
+/// Copies are counted when they're made through [`track`][StatsSlab::track] (or reported with
+/// [`record`][StatsSlab::record]), for example:
+///
+/// ```
+/// # use presser::*;
+/// let mut slab = StatsSlab::new(make_stack_slab::<u32, 4>());
+/// slab.track(|slab| copy_from_slice_to_offset(&[1u32, 2], slab, 0))?;
+/// let _ = slab.track(|slab| copy_from_slice_to_offset(&[3u32, 4, 5], slab, 8));
+///
+/// let stats = slab.report();
+/// assert_eq!(stats.copies, 1);
+/// assert_eq!(stats.failed_copies, 1);
+/// assert_eq!(stats.high_water_mark, 8);
+/// assert_eq!(stats.required_size, 20);
+/// # Ok::<(), presser::Error>(())
+/// ```
+///
+/// [`StatsSlab`] is itself a [`Slab`] which passes through to the wrapped one, but copies made
+/// into it directly aren't counted.
+pub struct StatsSlab<S: Slab> {
+    inner: S,
+    stats: SlabStats,
+}
+
+/// Statistics about the copies made into a [`StatsSlab`], returned by
+/// [`StatsSlab::report`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SlabStats {
+    /// The number of successful copies.
+    pub copies: usize,
+
+    /// The number of copies which failed with an error.
+    pub failed_copies: usize,
+
+    /// The total number of bytes written by successful copies, not counting padding.
+    pub bytes_written: usize,
+
+    /// The largest [`end_offset`][CopyRecord::end_offset] of any successful copy.
+    pub high_water_mark: usize,
+
+    /// The size the slab would have needed for all copies to succeed, as far as known: the
+    /// largest padded end offset of any successful copy, or the
+    /// [`required_bytes`][BoundsDetails::required_bytes] of any copy which failed by going out
+    /// of bounds.
+    pub required_size: usize,
+}
+
+impl core::fmt::Display for SlabStats {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} copies ({} failed), {} bytes written, high-water mark at {} bytes, {} bytes required",
+            self.copies, self.failed_copies, self.bytes_written, self.high_water_mark, self.required_size,
+        )
+    }
+}
+
+impl<S: Slab> StatsSlab<S> {
+    /// Wrap `inner`, starting with empty statistics.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            stats: SlabStats::default(),
+        }
+    }
+
+    /// Perform a copy into the wrapped slab with `copy`, and count it.
+    ///
+    /// The result of `copy` is passed through.
+    #[inline]
+    pub fn track<F>(&mut self, copy: F) -> Result<CopyRecord, Error>
+    where
+        F: FnOnce(&mut S) -> Result<CopyRecord, Error>,
+    {
+        let result = copy(&mut self.inner);
+        match &result {
+            Ok(record) => self.record(record),
+            Err(err) => self.record_error(err),
+        }
+        result
+    }
+
+    /// Count a successful copy made into the wrapped slab without going through
+    /// [`track`][StatsSlab::track].
+    #[inline]
+    pub fn record(&mut self, record: &CopyRecord) {
+        self.stats.copies += 1;
+        self.stats.bytes_written += record.len();
+        self.stats.high_water_mark = self.stats.high_water_mark.max(record.end_offset);
+        self.stats.required_size = self.stats.required_size.max(record.end_offset_padded);
+    }
+
+    /// Count a failed copy into the wrapped slab without going through
+    /// [`track`][StatsSlab::track].
+    #[inline]
+    pub fn record_error(&mut self, err: &Error) {
+        self.stats.failed_copies += 1;
+        if let Some(bounds) = err.bounds() {
+            self.stats.required_size = self.stats.required_size.max(bounds.required_bytes);
+        }
+    }
+
+    /// The statistics collected so far.
+    #[inline]
+    pub fn report(&self) -> SlabStats {
+        self.stats
+    }
+
+    /// Reset all statistics, for example when the slab is reused for the next frame.
+    #[inline]
+    pub fn reset_stats(&mut self) {
+        self.stats = SlabStats::default();
+    }
+
+    /// The wrapped slab.
+    #[inline]
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// The wrapped slab. Copies made into it through this reference aren't counted.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+
+    /// Unwrap the wrapped slab, discarding the statistics.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+// SAFETY: This is just a basic pass-thru to `inner`, which is a `Slab` itself.
+unsafe impl<S: Slab> Slab for StatsSlab<S> {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.inner.base_ptr()
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.inner.base_ptr_mut()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+}
@@ -0,0 +1,339 @@
+use super::*;
+
+/// Copies `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, using non-temporal (streaming) stores
+/// where the target supports them.
+///
+/// This is intended for destinations which are write-combined memory, like GPU upload heaps,
+/// where a regular copy would needlessly pull the destination into the cache. On `x86`/`x86_64`
+/// with SSE2 this uses `_mm_stream_si128` followed by an `sfence`, and on `aarch64` it uses
+/// `STNP`. On other targets, or for the unaligned head and tail of the copy, it falls back to a
+/// regular copy.
+///
+/// Streaming stores bypass the cache, so they will be *slower* than [`copy_to_offset`] if you
+/// read the copied data back soon after on the CPU.
+///
+/// See [`copy_to_offset`] for more.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_to_offset_streaming<T: Copy, S: Slab + ?Sized>(
+    src: &T,
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    let layout = Layout::new::<T>();
+    let offsets = compute_and_validate_offsets(&*dst, start_offset, layout, 1, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) };
+
+    // SAFETY:
+    // - src is valid as we have a reference to it
+    // - dst is valid so long as requirements for `slab` were met, i.e.
+    // we have unique access to the region described and that it is valid for the duration
+    // of 'a.
+    // - checked that copy stays within bounds of our allocation
+    // - the two regions can't overlap as we have unique access to `dst`
+    unsafe {
+        stream_copy_bytes((src as *const T).cast::<u8>(), dst_ptr, layout.size());
+    }
+
+    Ok(offsets.into())
+}
+
+/// Copies from `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, using non-temporal (streaming) stores
+/// where the target supports them.
+///
+/// See [`copy_to_offset_streaming`] and [`copy_from_slice_to_offset`] for more.
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_from_slice_to_offset_streaming<T: Copy, S: Slab + ?Sized>(
+    src: &[T],
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    let layout = Layout::for_value(src);
+    let offsets = compute_and_validate_offsets(&*dst, start_offset, layout, 1, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) };
+
+    // SAFETY:
+    // - src is valid as we have a reference to it
+    // - dst is valid so long as requirements for `slab` were met, i.e.
+    // we have unique access to the region described and that it is valid for the duration
+    // of 'a.
+    // - checked that copy stays within bounds of our allocation
+    // - the two regions can't overlap as we have unique access to `dst`
+    unsafe {
+        stream_copy_bytes(src.as_ptr().cast::<u8>(), dst_ptr, layout.size());
+    }
+
+    Ok(offsets.into())
+}
+
+/// A hint about the kind of copy being performed, used to select the copy strategy in
+/// [`copy_from_slice_to_offset_with_hint`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CopyHint {
+    /// A regular copy, the same as [`copy_from_slice_to_offset`].
+    #[default]
+    Default,
+
+    /// A large (multi-megabyte) copy. The source is copied in cache-sized chunks, prefetching
+    /// each chunk of the source while the previous one is being copied.
+    Large,
+
+    /// A copy into write-combined memory which will not be read back by the CPU soon. See
+    /// [`copy_from_slice_to_offset_streaming`].
+    Streaming,
+}
+
+/// Copies from `src` into the memory represented by `dst` starting at a minimum location
+/// of `start_offset` bytes past the start of `dst`, using the copy strategy selected by `hint`.
+///
+/// The hint only affects how the bytes are copied, the placement of the copy and the returned
+/// [`CopyRecord`] are the same as for [`copy_from_slice_to_offset`].
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn copy_from_slice_to_offset_with_hint<T: Copy, S: Slab + ?Sized>(
+    src: &[T],
+    dst: &mut S,
+    start_offset: usize,
+    hint: CopyHint,
+) -> Result<CopyRecord, Error> {
+    let layout = Layout::for_value(src);
+    let offsets = compute_and_validate_offsets(&*dst, start_offset, layout, 1, false)?;
+    poison_alignment_gap(dst, start_offset, &offsets);
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) };
+    let src_ptr = src.as_ptr().cast::<u8>();
+
+    // SAFETY:
+    // - src is valid as we have a reference to it
+    // - dst is valid so long as requirements for `slab` were met, i.e.
+    // we have unique access to the region described and that it is valid for the duration
+    // of 'a.
+    // - checked that copy stays within bounds of our allocation
+    // - the two regions can't overlap as we have unique access to `dst`
+    unsafe { copy_bytes_with_hint(src_ptr, dst_ptr, layout.size(), hint) };
+
+    Ok(offsets.into())
+}
+
+/// Copies `len` bytes from `src` to `dst` using the copy strategy selected by `hint`.
+///
+/// # Safety
+///
+/// Same requirements as [`core::ptr::copy_nonoverlapping`] for `u8`s.
+#[inline]
+pub(crate) unsafe fn copy_bytes_with_hint(
+    src: *const u8,
+    dst: *mut u8,
+    len: usize,
+    hint: CopyHint,
+) {
+    // SAFETY: same safety requirements
+    unsafe {
+        match hint {
+            CopyHint::Default => core::ptr::copy_nonoverlapping(src, dst, len),
+            CopyHint::Large => prefetch_copy_bytes(src, dst, len),
+            CopyHint::Streaming => stream_copy_bytes(src, dst, len),
+        }
+    }
+}
+
+/// The size of the chunks a [`CopyHint::Large`] copy is split into.
+const PREFETCH_CHUNK: usize = 4096;
+
+/// The distance between prefetch instructions, i.e. the size of a cache line on the targets we
+/// prefetch on.
+const CACHE_LINE: usize = 64;
+
+/// Copies `len` bytes from `src` to `dst` in chunks of [`PREFETCH_CHUNK`] bytes, prefetching
+/// the next chunk of `src` before copying each one.
+///
+/// # Safety
+///
+/// Same as [`core::ptr::copy_nonoverlapping`], except that neither pointer needs to be aligned.
+#[inline]
+unsafe fn prefetch_copy_bytes(src: *const u8, dst: *mut u8, len: usize) {
+    let mut offset = 0;
+    while offset < len {
+        let chunk = PREFETCH_CHUNK.min(len - offset);
+        let next = offset + chunk;
+
+        // SAFETY: `next + next_chunk <= len`, so every prefetched address is in bounds of `src`,
+        // and `offset + chunk <= len`, so the copy stays in bounds of both `src` and `dst`.
+        unsafe {
+            let next_chunk = PREFETCH_CHUNK.min(len - next);
+            for line in (0..next_chunk).step_by(CACHE_LINE) {
+                imp::prefetch(src.add(next + line));
+            }
+            core::ptr::copy_nonoverlapping(src.add(offset), dst.add(offset), chunk);
+        }
+
+        offset = next;
+    }
+}
+
+/// The size of a single streaming store.
+const CHUNK: usize = 16;
+
+/// Copies `len` bytes from `src` to `dst`, using streaming stores for the 16-byte aligned
+/// middle section of `dst`.
+///
+/// # Safety
+///
+/// Same as [`core::ptr::copy_nonoverlapping`], except that neither pointer needs to be aligned.
+#[inline]
+unsafe fn stream_copy_bytes(src: *const u8, dst: *mut u8, len: usize) {
+    let head = dst.align_offset(CHUNK).min(len);
+    let chunks = (len - head) / CHUNK;
+    if !imp::SUPPORTED || chunks == 0 {
+        // SAFETY: same safety requirements
+        unsafe { core::ptr::copy_nonoverlapping(src, dst, len) };
+        return;
+    }
+
+    let body = chunks * CHUNK;
+    let tail = head + body;
+
+    // SAFETY:
+    // - `head + body + (len - tail) == len`, so all three copies stay within the `len` bytes
+    // the caller guarantees to be valid
+    // - `dst + head` is 16-byte aligned by construction of `head`
+    unsafe {
+        core::ptr::copy_nonoverlapping(src, dst, head);
+        imp::stream_chunks(src.add(head), dst.add(head), chunks);
+        core::ptr::copy_nonoverlapping(src.add(tail), dst.add(tail), len - tail);
+    }
+}
+
+#[cfg(all(
+    not(miri),
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+mod imp {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86 as arch;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64 as arch;
+
+    pub(super) const SUPPORTED: bool = true;
+
+    /// # Safety
+    ///
+    /// `src` must be valid for reads and `dst` valid for writes of `chunks * 16` bytes, `dst`
+    /// must be 16-byte aligned, and the regions must not overlap.
+    #[inline]
+    pub(super) unsafe fn stream_chunks(src: *const u8, dst: *mut u8, chunks: usize) {
+        for i in 0..chunks {
+            // SAFETY: within the bounds given by the caller, `_mm_loadu_si128` has no alignment
+            // requirements, and `dst` is 16-byte aligned as `_mm_stream_si128` requires. SSE2 is
+            // statically enabled.
+            unsafe {
+                let value = arch::_mm_loadu_si128(src.add(i * super::CHUNK).cast());
+                arch::_mm_stream_si128(dst.add(i * super::CHUNK).cast(), value);
+            }
+        }
+        // Streaming stores are weakly ordered, make sure they're visible before any later
+        // stores (for example, one that signals the data is ready).
+        // SAFETY: SSE2 is statically enabled.
+        unsafe { arch::_mm_sfence() };
+    }
+
+    /// Hint that the cache line containing `ptr` will be read soon.
+    #[inline]
+    pub(super) fn prefetch(ptr: *const u8) {
+        // SAFETY: prefetches never fault, and SSE is statically enabled as part of SSE2.
+        unsafe { arch::_mm_prefetch::<{ arch::_MM_HINT_T0 }>(ptr.cast()) };
+    }
+}
+
+#[cfg(all(not(miri), target_arch = "aarch64"))]
+mod imp {
+    pub(super) const SUPPORTED: bool = true;
+
+    /// # Safety
+    ///
+    /// `src` must be valid for reads and `dst` valid for writes of `chunks * 16` bytes, `dst`
+    /// must be 16-byte aligned, and the regions must not overlap.
+    #[inline]
+    pub(super) unsafe fn stream_chunks(src: *const u8, dst: *mut u8, chunks: usize) {
+        for i in 0..chunks {
+            // SAFETY: within the bounds given by the caller, and `STNP` of two 64-bit registers
+            // writes exactly 16 bytes to the 16-byte aligned `dst`.
+            unsafe {
+                let src = src.add(i * super::CHUNK).cast::<u64>();
+                let lo = src.read_unaligned();
+                let hi = src.add(1).read_unaligned();
+                core::arch::asm!(
+                    "stnp {lo}, {hi}, [{dst}]",
+                    dst = in(reg) dst.add(i * super::CHUNK),
+                    lo = in(reg) lo,
+                    hi = in(reg) hi,
+                    options(nostack, preserves_flags),
+                );
+            }
+        }
+    }
+
+    /// Hint that the cache line containing `ptr` will be read soon.
+    #[inline]
+    pub(super) fn prefetch(ptr: *const u8) {
+        // SAFETY: `PRFM` never faults and has no other side effects.
+        unsafe {
+            core::arch::asm!(
+                "prfm pldl1keep, [{ptr}]",
+                ptr = in(reg) ptr,
+                options(nostack, readonly, preserves_flags),
+            );
+        }
+    }
+}
+
+#[cfg(not(any(
+    all(
+        not(miri),
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    all(not(miri), target_arch = "aarch64")
+)))]
+mod imp {
+    pub(super) const SUPPORTED: bool = false;
+
+    /// # Safety
+    ///
+    /// Never called, as streaming stores aren't supported on this target.
+    #[inline]
+    pub(super) unsafe fn stream_chunks(src: *const u8, dst: *mut u8, chunks: usize) {
+        // SAFETY: same safety requirements
+        unsafe { core::ptr::copy_nonoverlapping(src, dst, chunks * super::CHUNK) };
+    }
+
+    /// Prefetching isn't supported on this target, so this does nothing.
+    #[inline]
+    pub(super) fn prefetch(_ptr: *const u8) {}
+}
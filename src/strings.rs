@@ -0,0 +1,120 @@
+use super::*;
+
+use core::ffi::CStr;
+
+/// Copies the bytes of `src`, including its NUL terminator, into the memory represented by `dst`
+/// starting at *exactly* `start_offset` bytes past the start of `dst`.
+///
+/// The function will return an error if:
+/// - `start_offset` is out of bounds of `dst`
+/// - `start_offset` plus the length of `src` including its terminator is out of bounds of `dst`
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn write_cstr_at_offset<S: Slab + ?Sized>(
+    src: &CStr,
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    copy_from_slice_to_offset_exact(src.to_bytes_with_nul(), dst, start_offset)
+}
+
+/// Copies the bytes of `src` followed by a NUL terminator into the memory represented by `dst`
+/// starting at *exactly* `start_offset` bytes past the start of `dst`.
+///
+/// `src` is copied as-is, so if it contains a NUL byte itself, a C consumer of the string will
+/// see it end there. Use [`write_cstr_at_offset`] if that must be checked.
+///
+/// The function will return an error if:
+/// - `start_offset` is out of bounds of `dst`
+/// - `start_offset + src.len() + 1` is out of bounds of `dst`
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn write_str_nul_terminated_at_offset<S: Slab + ?Sized>(
+    src: &str,
+    dst: &mut S,
+    start_offset: usize,
+) -> Result<CopyRecord, Error> {
+    let len = src.len().checked_add(1).ok_or(Error::invalid_layout())?;
+    let layout = Layout::array::<u8>(len)?;
+    let offsets = compute_and_validate_offsets(&*dst, start_offset, layout, 1, true)?;
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) };
+
+    // SAFETY:
+    // - src is valid as we have a reference to it
+    // - dst is valid so long as requirements for `slab` were met, i.e.
+    // we have unique access to the region described and that it is valid for the duration
+    // of 'a.
+    // - areas not overlapping as long as safety requirements of creation of `self` were met,
+    // i.e. that we have exclusive access to the region of memory described.
+    // - checked that the string and its terminator stay within bounds of our allocation
+    // - `u8` has no alignment requirements
+    unsafe {
+        core::ptr::copy_nonoverlapping(src.as_ptr(), dst_ptr, src.len());
+        dst_ptr.add(src.len()).write(0);
+    }
+
+    Ok(offsets.into())
+}
+
+/// Encodes `src` as UTF-16 into the memory represented by `dst` starting at *exactly*
+/// `start_offset` bytes past the start of `dst`, optionally followed by a NUL terminator.
+///
+/// The code units are written in native byte order, i.e. as an inline array of `u16`s (`WCHAR`s
+/// on Windows). `src` is encoded on the fly, so no intermediate buffer is needed.
+///
+/// - `nul_terminate` controls whether a `0` code unit is written after the encoded string.
+///
+/// The function will return an error if:
+/// - `start_offset` within `dst` is not properly aligned for `u16`
+/// - `start_offset` is out of bounds of `dst`
+/// - `start_offset` plus the size of the encoded string (and terminator) is out of bounds of `dst`
+///
+/// # Safety
+///
+/// This function is safe on its own, however it is very possible to do unsafe
+/// things if you read the copied data in the wrong way. See the
+/// [crate-level Safety documentation][`crate#safety`] for more.
+#[inline]
+pub fn write_utf16_at_offset<S: Slab + ?Sized>(
+    src: &str,
+    dst: &mut S,
+    start_offset: usize,
+    nul_terminate: bool,
+) -> Result<CopyRecord, Error> {
+    let len = src.encode_utf16().count() + usize::from(nul_terminate);
+    let layout = Layout::array::<u16>(len)?;
+    let offsets = compute_and_validate_offsets(&*dst, start_offset, layout, 1, true)?;
+
+    // SAFETY: if compute_offsets succeeded, this has already been checked to be safe.
+    let dst_ptr = unsafe { dst.base_non_null().as_ptr().add(offsets.start) }.cast::<u16>();
+
+    let units = src
+        .encode_utf16()
+        .chain(core::iter::once(0).filter(|_| nul_terminate));
+    for (i, unit) in units.enumerate() {
+        // SAFETY:
+        // - dst is valid so long as requirements for `slab` were met, i.e.
+        // we have unique access to the region described and that it is valid for the duration
+        // of 'a.
+        // - dst aligned at least to align_of::<u16>(), so each element is as well
+        // - `encode_utf16` always yields the same units for the same string, so we write exactly
+        // `len` units, which was checked to stay within bounds of our allocation
+        unsafe {
+            dst_ptr.add(i).write(unit);
+        }
+    }
+
+    Ok(offsets.into())
+}
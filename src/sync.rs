@@ -0,0 +1,94 @@
+use std::sync::Mutex;
+use std::sync::TryLockError;
+
+/// A [`Slab`][crate::Slab] behind a lock, so that a few threads can share it and take turns
+/// copying into it.
+///
+/// This is meant for the occasional upload from multiple threads into one buffer. If many threads
+/// write into the same slab at once, see [`ConcurrentWriter`][crate::ConcurrentWriter] instead,
+/// which doesn't need to lock.
+///
+/// ```
+/// # use presser::*;
+/// let slab = SyncSlab::new(HeapSlab::new(std::alloc::Layout::new::<[u32; 4]>()));
+///
+/// std::thread::scope(|s| {
+///     for thread in 0..4u32 {
+///         let slab = &slab;
+///         s.spawn(move || {
+///             slab.with(|slab| copy_to_offset(&thread, slab, thread as usize * 4)).unwrap();
+///         });
+///     }
+/// });
+///
+/// let slab = slab.into_inner();
+/// // SAFETY: every thread copied a `u32` into its own offset.
+/// assert_eq!(unsafe { read_slice_at_offset::<u32, _>(&slab, 0, 4) }?, &[0, 1, 2, 3]);
+/// # Ok::<(), presser::Error>(())
+/// ```
+///
+/// A [`SyncSlab`] is [`Sync`] if the wrapped slab is [`Send`], as is the case for the slabs
+/// owning their memory, like [`HeapSlab`][crate::HeapSlab]. Slabs viewing memory through a raw
+/// pointer are not, since this crate can't know whether that memory may be accessed from other
+/// threads.
+#[derive(Debug, Default)]
+pub struct SyncSlab<S> {
+    inner: Mutex<S>,
+}
+
+impl<S> SyncSlab<S> {
+    /// Wrap `inner` behind a lock.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner: Mutex::new(inner),
+        }
+    }
+
+    /// Lock the slab, blocking until no other thread is using it, and call `f` with it.
+    ///
+    /// If another thread panicked while using the slab, it's handed out anyway, since a slab
+    /// doesn't hold any invariants which a panic could have broken. Its contents may of course
+    /// only have been partially written.
+    pub fn with<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut S) -> R,
+    {
+        let mut guard = self
+            .inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&mut guard)
+    }
+
+    /// Like [`with`][SyncSlab::with], but returns `None` without calling `f` if another thread is
+    /// currently using the slab.
+    pub fn try_with<R, F>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut S) -> R,
+    {
+        let mut guard = match self.inner.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => return None,
+        };
+        Some(f(&mut guard))
+    }
+
+    /// The wrapped slab, without locking, since the mutable borrow guarantees no other thread is
+    /// using it.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut S {
+        self.inner
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Unwrap the wrapped slab.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -0,0 +1,154 @@
+use super::*;
+
+/// Stages a set of copy operations into a [`Slab`] and only performs them once they have all been
+/// validated.
+///
+/// Each staged copy is placed after the previously staged one, starting at the offset the
+/// transaction was created with, as if appending to a log. Staging a copy validates it and
+/// reserves space for it, but doesn't write any data. Then, either:
+///
+/// - [`commit`][CopyTransaction::commit] performs all staged copies, or
+/// - [`rollback`][CopyTransaction::rollback] (or just dropping the transaction) releases all
+///   reserved space without writing anything to the slab.
+///
+/// If staging any copy fails, the transaction is poisoned and `commit` will return the first
+/// error encountered without writing anything.
+pub struct CopyTransaction<'a, 'src, S: Slab + ?Sized> {
+    slab: &'a mut S,
+    start_offset: usize,
+    cursor: usize,
+    staged: Vec<StagedCopy<'src>>,
+    error: Option<Error>,
+}
+
+struct StagedCopy<'src> {
+    src: &'src [MaybeUninit<u8>],
+    dst_offset: usize,
+}
+
+impl<'a, 'src, S: Slab + ?Sized> CopyTransaction<'a, 'src, S> {
+    /// Begin a new transaction into `slab`, before which no staged copy will be placed.
+    pub fn new(slab: &'a mut S, start_offset: usize) -> Self {
+        Self {
+            slab,
+            start_offset,
+            cursor: start_offset,
+            staged: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Stage a copy of `src`, aligned to at least `min_alignment`, after all previously staged copies.
+    ///
+    /// Returns the [`CopyRecord`] the copy will have once the transaction is committed.
+    pub fn stage<T: Copy>(
+        &mut self,
+        src: &'src T,
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error> {
+        // SAFETY: `src` is a valid reference, so it is valid for reads of `size_of::<T>()` bytes,
+        // any of which may be viewed as `MaybeUninit<u8>`.
+        let bytes = unsafe {
+            core::slice::from_raw_parts(
+                (src as *const T).cast::<MaybeUninit<u8>>(),
+                core::mem::size_of::<T>(),
+            )
+        };
+        self.stage_bytes(bytes, Layout::new::<T>(), min_alignment)
+    }
+
+    /// Stage a copy of `src`, laid out as a `[T]` and aligned to at least `min_alignment`, after all
+    /// previously staged copies.
+    ///
+    /// Returns the [`CopyRecord`] the copy will have once the transaction is committed.
+    pub fn stage_slice<T: Copy>(
+        &mut self,
+        src: &'src [T],
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error> {
+        // SAFETY: `src` is a valid reference, so it is valid for reads of `size_of_val(src)` bytes,
+        // any of which may be viewed as `MaybeUninit<u8>`.
+        let bytes = unsafe {
+            core::slice::from_raw_parts(
+                src.as_ptr().cast::<MaybeUninit<u8>>(),
+                core::mem::size_of_val(src),
+            )
+        };
+        self.stage_bytes(bytes, Layout::for_value(src), min_alignment)
+    }
+
+    fn stage_bytes(
+        &mut self,
+        src: &'src [MaybeUninit<u8>],
+        layout: Layout,
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error> {
+        let result =
+            compute_and_validate_offsets(&*self.slab, self.cursor, layout, min_alignment, false);
+
+        match result {
+            Ok(offsets) => {
+                self.cursor = offsets.end;
+                self.staged.push(StagedCopy {
+                    src,
+                    dst_offset: offsets.start,
+                });
+                Ok(offsets.into())
+            }
+            Err(err) => {
+                self.error.get_or_insert(err);
+                Err(err)
+            }
+        }
+    }
+
+    /// The offset directly after the end of the last staged copy. If the transaction is
+    /// committed, this is where the next data appended to the slab should go.
+    pub fn end_offset(&self) -> usize {
+        self.cursor
+    }
+
+    /// Perform all staged copies.
+    ///
+    /// Returns the offset directly after the end of the last copy, or, if staging any copy failed,
+    /// the first error encountered, in which case nothing is written.
+    pub fn commit(self) -> Result<usize, Error> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        for staged in self.staged {
+            // SAFETY: the destination range of each staged copy has been validated to be within
+            // the bounds of the slab when it was staged, and the slab has been uniquely borrowed
+            // since then, so it can't have changed.
+            let dst_ptr = unsafe { self.slab.base_non_null().as_ptr().add(staged.dst_offset) };
+
+            // SAFETY:
+            // - src is valid as we have a reference to it
+            // - dst is valid so long as requirements for `slab` were met, i.e.
+            // we have unique access to the region described and that it is valid for the duration
+            // of 'a.
+            // - areas not overlapping as long as safety requirements of creation of `slab` were met,
+            // i.e. that we have exclusive access to the region of memory described.
+            // - dst was aligned as required when staged
+            // - checked that copy stays within bounds of our allocation
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    staged.src.as_ptr().cast::<u8>(),
+                    dst_ptr,
+                    staged.src.len(),
+                );
+            }
+        }
+
+        Ok(self.cursor)
+    }
+
+    /// Discard all staged copies without writing anything, returning the offset the transaction
+    /// was started at, i.e. the end of the log before this transaction.
+    ///
+    /// This is equivalent to dropping the transaction.
+    pub fn rollback(self) -> usize {
+        self.start_offset
+    }
+}
@@ -0,0 +1,94 @@
+/// A `T` with an alignment of 1, to opt specific values, fields or elements out of alignment
+/// handling while keeping the rest of a copy or read validated as usual.
+///
+/// Since the copy and read helpers of this crate derive the alignment of their data from its
+/// type, an `Unalign<T>` is placed and read at exactly the requested offset, without any padding
+/// for the alignment of `T`:
+///
+/// ```
+/// # use presser::*;
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct PackedVertex {
+///     flags: u8,
+///     position: Unalign<[f32; 3]>,
+/// }
+///
+/// let vertex = PackedVertex { flags: 1, position: Unalign::new([1.0, 2.0, 3.0]) };
+/// assert_eq!(core::mem::size_of::<PackedVertex>(), 13);
+///
+/// let mut slab = make_stack_slab::<u8, 64>();
+/// let record = copy_to_offset(&vertex, &mut slab, 3)?;
+/// assert_eq!(record.start_offset, 3);
+///
+/// // SAFETY: a `PackedVertex` was copied to `record.start_offset` above.
+/// let copied = unsafe { read_at_offset::<PackedVertex, _>(&slab, record.start_offset) }?;
+/// assert_eq!(copied.position.get(), [1.0, 2.0, 3.0]);
+/// # Ok::<(), presser::Error>(())
+/// ```
+///
+/// Like with any `#[repr(packed)]` type, references to the inner `T` can't be taken directly.
+/// Use [`get`][Unalign::get] and [`set`][Unalign::set] to access it by value instead.
+#[derive(Default)]
+#[repr(C, packed)]
+pub struct Unalign<T>(T);
+
+impl<T> Unalign<T> {
+    /// Wrap `value`.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Unwrap the inner value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        let Self(value) = self;
+        value
+    }
+}
+
+impl<T: Copy> Unalign<T> {
+    /// A copy of the inner value.
+    #[inline]
+    pub fn get(&self) -> T {
+        self.0
+    }
+
+    /// Replace the inner value with `value`.
+    #[inline]
+    pub fn set(&mut self, value: T) {
+        self.0 = value;
+    }
+}
+
+impl<T: Copy> Clone for Unalign<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Copy> Copy for Unalign<T> {}
+
+impl<T: Copy + PartialEq> PartialEq for Unalign<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T: Copy + Eq> Eq for Unalign<T> {}
+
+impl<T: Copy + core::fmt::Debug> core::fmt::Debug for Unalign<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Unalign").field(&self.get()).finish()
+    }
+}
+
+impl<T> From<T> for Unalign<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
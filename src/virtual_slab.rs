@@ -0,0 +1,209 @@
+use super::*;
+
+/// A slab made up of a large range of reserved virtual address space, of which pages are only
+/// committed (backed by memory) on request. Implements [`Slab`] and gets unmapped on [`Drop`].
+///
+/// Since the whole range is reserved up front, the slab never moves as it grows, so pointers into
+/// it and [`CopyRecord`]s describing data previously copied into it stay valid. Memory usage is
+/// proportional to the committed size, not the reserved size.
+///
+/// [`VirtualSlab::copy`] and [`VirtualSlab::copy_slice`] commit memory on demand. Committing is
+/// explicit otherwise: through the [`Slab`] interface, only the committed part of the slab is
+/// visible, i.e. [`Slab::size`] returns [`VirtualSlab::committed_size`], so commit more memory
+/// with [`VirtualSlab::commit_for`] (or [`VirtualSlab::commit_to`]) before copying data with the
+/// free copy functions, or they will fail with [`ErrorKind::OutOfMemory`].
+///
+/// ```
+/// # use presser::*;
+/// let mut slab = VirtualSlab::new(1 << 30)?;
+/// let record = slab.copy_slice(&[1u32, 2, 3], 0, 1)?;
+/// assert!(slab.committed_size() >= record.end_offset);
+///
+/// // the free functions only see the committed memory
+/// let record = slab.commit_for(core::alloc::Layout::new::<u64>(), record.end_offset, 1)?;
+/// copy_to_offset(&4u64, &mut slab, record.start_offset)?;
+/// # Ok::<(), presser::Error>(())
+/// ```
+///
+/// Only supported on Unix-like platforms and Windows. On other platforms, creating one always
+/// fails.
+pub struct VirtualSlab {
+    base_ptr: NonNull<u8>,
+    reserved_size: usize,
+    committed_size: usize,
+}
+
+impl VirtualSlab {
+    /// Reserve `reserved_size` bytes of address space, rounded up to a multiple of the page size,
+    /// without committing any of it.
+    ///
+    /// The function will return an error if:
+    /// - `reserved_size` is 0 ([`ErrorKind::InvalidLayout`])
+    /// - the address space could not be reserved ([`ErrorKind::AllocationFailed`])
+    pub fn new(reserved_size: usize) -> Result<Self, Error> {
+        if reserved_size == 0 {
+            return Err(Error::invalid_layout());
+        }
+        let reserved_size =
+            round_up_to_multiple(reserved_size, page_size()).ok_or(Error::invalid_layout())?;
+        let base_ptr = reserve(reserved_size).ok_or(Error::allocation_failed())?;
+        Ok(Self {
+            base_ptr,
+            reserved_size,
+            committed_size: 0,
+        })
+    }
+
+    /// The number of bytes of address space reserved for the slab, i.e. the size it may grow to.
+    #[inline]
+    pub fn reserved_size(&self) -> usize {
+        self.reserved_size
+    }
+
+    /// The number of bytes at the start of the slab that are committed, which is always a
+    /// multiple of the page size. This is also the size of the slab as seen through the [`Slab`]
+    /// interface.
+    #[inline]
+    pub fn committed_size(&self) -> usize {
+        self.committed_size
+    }
+
+    /// Ensure at least the first `len` bytes of the slab are committed, rounded up to a multiple
+    /// of the page size.
+    ///
+    /// Newly committed memory begins uninitialized.
+    ///
+    /// The function will return an error if:
+    /// - `len` is greater than the reserved size ([`ErrorKind::OutOfMemory`])
+    /// - the memory could not be committed ([`ErrorKind::AllocationFailed`])
+    pub fn commit_to(&mut self, len: usize) -> Result<(), Error> {
+        if len <= self.committed_size {
+            return Ok(());
+        }
+        if len > self.reserved_size {
+            return Err(Error::out_of_memory(0, len, self.reserved_size));
+        }
+        // can't overflow or exceed the reserved size, as that's a multiple of the page size too.
+        let new_committed_size =
+            round_up_to_multiple(len, page_size()).ok_or(Error::invalid_layout())?;
+
+        // SAFETY:
+        // - the committed size is always a multiple of the page size, so this is page-aligned
+        // - the range is within the reservation, which we release only on drop
+        let committed = unsafe {
+            commit(
+                NonNull::new_unchecked(self.base_ptr.as_ptr().add(self.committed_size)),
+                new_committed_size - self.committed_size,
+            )
+        };
+        if !committed {
+            return Err(Error::allocation_failed());
+        }
+
+        self.committed_size = new_committed_size;
+        Ok(())
+    }
+
+    /// Ensure enough of the slab is committed for a copy of data with layout `layout` starting
+    /// at a minimum location of `start_offset` bytes past the start of the slab and with minimum
+    /// alignment `min_alignment`, as [`copy_to_offset_with_align`] and friends would perform it.
+    ///
+    /// Returns a [`CopyRecord`] describing where such a copy will be placed. See
+    /// [`plan_copy_for_layout`] for more.
+    pub fn commit_for(
+        &mut self,
+        layout: Layout,
+        start_offset: usize,
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error> {
+        let offsets = compute_and_validate_offsets_for_raw_parts(
+            addr(self.base_ptr.as_ptr()),
+            self.reserved_size,
+            start_offset,
+            layout,
+            min_alignment,
+            false,
+        )?;
+        self.commit_to(offsets.end_padded)?;
+        Ok(offsets.into())
+    }
+
+    /// Copy `src` into the slab, committing memory as needed, starting at a minimum location of
+    /// `start_offset` bytes past the start of the slab and with minimum alignment `min_alignment`.
+    ///
+    /// Apart from committing memory, this behaves like [`copy_to_offset_with_align`], so see its
+    /// documentation for more. If the copy would end past the reserved size, an error of kind
+    /// [`ErrorKind::OutOfMemory`] is returned, and if the memory could not be committed, one of
+    /// kind [`ErrorKind::AllocationFailed`].
+    ///
+    /// # Safety
+    ///
+    /// This function is safe on its own, however it is very possible to do unsafe
+    /// things if you read the copied data in the wrong way. See the
+    /// [crate-level Safety documentation][`crate#safety`] for more.
+    pub fn copy<T: Copy>(
+        &mut self,
+        src: &T,
+        start_offset: usize,
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error> {
+        let record = self.commit_for(Layout::new::<T>(), start_offset, min_alignment)?;
+        copy_to_offset_with_align_exact(src, self, record.start_offset, min_alignment)
+    }
+
+    /// Copy all elements of `src` into the slab, committing memory as needed, starting at a
+    /// minimum location of `start_offset` bytes past the start of the slab and with minimum
+    /// alignment `min_alignment`.
+    ///
+    /// Apart from committing memory, this behaves like [`copy_from_slice_to_offset_with_align`],
+    /// so see its documentation for more. See [`VirtualSlab::copy`] for the errors from committing.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe on its own, however it is very possible to do unsafe
+    /// things if you read the copied data in the wrong way. See the
+    /// [crate-level Safety documentation][`crate#safety`] for more.
+    pub fn copy_slice<T: Copy>(
+        &mut self,
+        src: &[T],
+        start_offset: usize,
+        min_alignment: usize,
+    ) -> Result<CopyRecord, Error> {
+        let record = self.commit_for(Layout::for_value(src), start_offset, min_alignment)?;
+        copy_from_slice_to_offset_with_align_exact(src, self, record.start_offset, min_alignment)
+    }
+}
+
+impl Drop for VirtualSlab {
+    fn drop(&mut self) {
+        // SAFETY: the address space was reserved in `new`, and unless the user did something
+        // unsafely wrong, this memory won't be used after drop.
+        unsafe { release(self.base_ptr, self.reserved_size) }
+    }
+}
+
+// SAFETY: a `VirtualSlab` owns its mapping exclusively, just like a `Box<[MaybeUninit<u8>]>`, so it
+// may be sent to and shared with other threads.
+unsafe impl Send for VirtualSlab {}
+// SAFETY: see above
+unsafe impl Sync for VirtualSlab {}
+
+// SAFETY: We point to a single reservation of address space, of which the first `committed_size`
+// bytes are committed and therefore valid to access. It stays valid until we are dropped, so our
+// `base_ptr` access is as required.
+unsafe impl Slab for VirtualSlab {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.base_ptr.as_ptr().cast_const()
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.base_ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.committed_size
+    }
+}
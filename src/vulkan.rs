@@ -0,0 +1,167 @@
+use super::*;
+
+use ash::vk;
+
+impl From<&vk::PhysicalDeviceLimits> for DeviceAlignments {
+    fn from(limits: &vk::PhysicalDeviceLimits) -> Self {
+        // Limits too large for a `usize` can't be satisfied anyway, so saturating them makes any
+        // copy relying on them fail rather than silently misalign.
+        let to_usize = |limit: vk::DeviceSize| usize::try_from(limit).unwrap_or(usize::MAX);
+        Self {
+            min_uniform_offset: to_usize(limits.min_uniform_buffer_offset_alignment),
+            min_storage_offset: to_usize(limits.min_storage_buffer_offset_alignment),
+            non_coherent_atom_size: to_usize(limits.non_coherent_atom_size),
+        }
+    }
+}
+
+/// A [`Slab`] over a range of Vulkan device memory mapped with `vkMapMemory`, which knows the
+/// offset alignment limits of the device it belongs to.
+///
+/// Besides being usable with all the regular copy functions, this provides shorthands for
+/// copying uniform and storage buffer data at valid dynamic offsets, see for example
+/// [`copy_uniform`][VkMappedSlab::copy_uniform].
+pub struct VkMappedSlab {
+    base_ptr: NonNull<u8>,
+    size: usize,
+    alignments: DeviceAlignments,
+}
+
+impl VkMappedSlab {
+    /// Create a new [`VkMappedSlab`] from the pointer returned by `vkMapMemory` and the size of
+    /// the mapped range, along with the limits of the physical device the memory was allocated
+    /// from.
+    ///
+    /// Returns [`ErrorKind::InvalidLayout`] if `ptr` is null or `size` is larger than `isize::MAX`.
+    ///
+    /// # Safety
+    ///
+    /// For as long as the returned value is alive, `ptr` and `size` must uphold all the
+    /// requirements of the [`Slab`] trait, i.e. the memory must stay mapped and must not be
+    /// accessed other than through the returned value. In particular, the range must not be
+    /// unmapped or freed before the returned value is dropped.
+    ///
+    /// Also see the [top-level safety documentation][`crate#safety`].
+    pub unsafe fn new(
+        ptr: *mut c_void,
+        size: vk::DeviceSize,
+        limits: &vk::PhysicalDeviceLimits,
+    ) -> Result<Self, Error> {
+        let base_ptr = NonNull::new(ptr.cast::<u8>()).ok_or(Error::invalid_layout())?;
+        let size = usize::try_from(size).map_err(|_| Error::invalid_layout())?;
+        if size > isize::MAX as usize {
+            return Err(Error::invalid_layout());
+        }
+
+        Ok(Self {
+            base_ptr,
+            size,
+            alignments: limits.into(),
+        })
+    }
+
+    /// The offset alignment limits of the device this memory belongs to.
+    #[inline]
+    pub fn alignments(&self) -> &DeviceAlignments {
+        &self.alignments
+    }
+
+    /// Copies `src` into `self` at the first valid dynamic uniform buffer offset at or after
+    /// `start_offset`.
+    ///
+    /// See [`copy_for_dynamic_offset`] for more.
+    #[inline]
+    pub fn copy_uniform<T: Copy>(
+        &mut self,
+        src: &T,
+        start_offset: usize,
+    ) -> Result<DynamicOffsetRecord, Error> {
+        let alignments = self.alignments;
+        copy_for_dynamic_offset(
+            src,
+            self,
+            start_offset,
+            &alignments,
+            DynamicBufferKind::Uniform,
+        )
+    }
+
+    /// Copies from `src` into `self` at the first valid dynamic uniform buffer offset at or after
+    /// `start_offset`.
+    ///
+    /// See [`copy_from_slice_for_dynamic_offset`] for more.
+    #[inline]
+    pub fn copy_uniform_slice<T: Copy>(
+        &mut self,
+        src: &[T],
+        start_offset: usize,
+    ) -> Result<DynamicOffsetRecord, Error> {
+        let alignments = self.alignments;
+        copy_from_slice_for_dynamic_offset(
+            src,
+            self,
+            start_offset,
+            &alignments,
+            DynamicBufferKind::Uniform,
+        )
+    }
+
+    /// Copies `src` into `self` at the first valid dynamic storage buffer offset at or after
+    /// `start_offset`.
+    ///
+    /// See [`copy_for_dynamic_offset`] for more.
+    #[inline]
+    pub fn copy_storage<T: Copy>(
+        &mut self,
+        src: &T,
+        start_offset: usize,
+    ) -> Result<DynamicOffsetRecord, Error> {
+        let alignments = self.alignments;
+        copy_for_dynamic_offset(
+            src,
+            self,
+            start_offset,
+            &alignments,
+            DynamicBufferKind::Storage,
+        )
+    }
+
+    /// Copies from `src` into `self` at the first valid dynamic storage buffer offset at or after
+    /// `start_offset`.
+    ///
+    /// See [`copy_from_slice_for_dynamic_offset`] for more.
+    #[inline]
+    pub fn copy_storage_slice<T: Copy>(
+        &mut self,
+        src: &[T],
+        start_offset: usize,
+    ) -> Result<DynamicOffsetRecord, Error> {
+        let alignments = self.alignments;
+        copy_from_slice_for_dynamic_offset(
+            src,
+            self,
+            start_offset,
+            &alignments,
+            DynamicBufferKind::Storage,
+        )
+    }
+}
+
+// SAFETY: So long as the safety requirements of `VkMappedSlab::new` are met, this is also safe
+// since it's just a basic pass-thru of info.
+unsafe impl Slab for VkMappedSlab {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.base_ptr.as_ptr() as *const u8
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.base_ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.size
+    }
+}
@@ -0,0 +1,93 @@
+use super::*;
+
+use js_sys::Uint8Array;
+use js_sys::WebAssembly;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+
+/// A [`Slab`] over a `Uint8Array` view into the WebAssembly module's own linear memory, i.e. into
+/// the `ArrayBuffer` (or `SharedArrayBuffer`, when using threads) backing `wasm_bindgen::memory()`.
+///
+/// This lets upload code compiled to WASM write through presser like it does natively, and then
+/// hand the written bytes to JS, for example to `GPUQueue.writeBuffer`, with
+/// [`record_view`][ArrayBufferSlab::record_view]. Typed arrays that don't view the module's linear
+/// memory can't be accessed through pointers at all, so they can't be used as a [`Slab`].
+pub struct ArrayBufferSlab {
+    base_ptr: NonNull<u8>,
+    size: usize,
+}
+
+impl ArrayBufferSlab {
+    /// Create a new [`ArrayBufferSlab`] over the memory viewed by `view`.
+    ///
+    /// Returns [`ErrorKind::InvalidLayout`] if `view` isn't a view into the module's linear memory,
+    /// or if it's empty and starts at address 0.
+    ///
+    /// # Safety
+    ///
+    /// For as long as the returned value is alive, the memory viewed by `view` must uphold all the
+    /// requirements of the [`Slab`] trait. In particular, it must be memory you own (for example
+    /// the spare capacity of a `Vec<u8>` you created `view` over), and it must not be accessed
+    /// other than through the returned value, neither from Rust nor from JS.
+    ///
+    /// Also see the [top-level safety documentation][`crate#safety`].
+    pub unsafe fn from_memory_view(view: &Uint8Array) -> Result<Self, Error> {
+        let memory = wasm_bindgen::memory().unchecked_into::<WebAssembly::Memory>();
+        if JsValue::from(view.buffer()) != memory.buffer() {
+            return Err(Error::invalid_layout());
+        }
+
+        // Linear memory addresses *are* byte offsets into its buffer.
+        let base_ptr =
+            NonNull::new(view.byte_offset() as usize as *mut u8).ok_or(Error::invalid_layout())?;
+        let size = view.byte_length() as usize;
+
+        Ok(Self { base_ptr, size })
+    }
+
+    /// A `Uint8Array` viewing all of `self`.
+    ///
+    /// # Safety
+    ///
+    /// The returned array must not be written to, and must not be used after `self` is dropped
+    /// or mutably borrowed. Note that growing the module's memory (which may happen on any
+    /// allocation) detaches all views into it, after which they're empty.
+    #[inline]
+    pub unsafe fn view(&self) -> Uint8Array {
+        // SAFETY: same safety requirements
+        unsafe { Uint8Array::view_mut_raw(self.base_ptr.as_ptr(), self.size) }
+    }
+
+    /// A `Uint8Array` viewing the bytes written by the copy described by `record`, ready to be
+    /// passed to JS.
+    ///
+    /// # Safety
+    ///
+    /// See [`view`][ArrayBufferSlab::view].
+    #[inline]
+    pub unsafe fn record_view(&self, record: &CopyRecord) -> Uint8Array {
+        // SAFETY: same safety requirements
+        let view = unsafe { self.view() };
+        // Records of copies into `self` always lie within `self.size`, which fits in a `u32`.
+        view.subarray(record.start_offset as u32, record.end_offset as u32)
+    }
+}
+
+// SAFETY: So long as the safety requirements of `ArrayBufferSlab::from_memory_view` are met, this
+// is also safe since it's just a basic pass-thru of info.
+unsafe impl Slab for ArrayBufferSlab {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const u8 {
+        self.base_ptr.as_ptr() as *const u8
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut u8 {
+        self.base_ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    fn size(&self) -> usize {
+        self.size
+    }
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+use wgpu::BufferViewMut;
+use wgpu::QueueWriteBufferView;
+use wgpu::WriteOnly;
+
+/// View a [`wgpu::WriteOnly`] byte slice, such as one obtained from [`BufferViewMut::slice`] or
+/// [`QueueWriteBufferView::slice`], as a [`Slab`].
+///
+/// [`wgpu::WriteOnly`]: WriteOnly
+///
+/// # Safety
+///
+/// `wgpu` may hand out write-only views of memory which isn't readable or initialized, and
+/// requires that writes through them never make the memory less initialized than it was. While
+/// using the returned slab, you must therefore make sure that:
+///
+/// - You never read from the slab, for example through [`read_at_offset`] or
+///   [`Slab::as_maybe_uninit_bytes`].
+/// - Any padding bytes of the types you copy into the slab are overwritten with initialized
+///   bytes before the view is dropped. The simplest way to ensure this is to only copy types
+///   which have no padding bytes.
+///
+/// Also see the [top-level safety documentation][`crate#safety`].
+#[inline]
+pub unsafe fn write_only_as_slab(mut view: WriteOnly<'_, [u8]>) -> BorrowedRawAllocation<'_> {
+    let size = view.len();
+    BorrowedRawAllocation {
+        base_ptr: view.as_raw_element_ptr(),
+        size,
+        phantom: PhantomData,
+    }
+}
+
+/// View all of a mapped [`BufferViewMut`] as a [`Slab`].
+///
+/// # Safety
+///
+/// See [`write_only_as_slab`].
+#[inline]
+pub unsafe fn buffer_view_as_slab(view: &mut BufferViewMut) -> BorrowedRawAllocation<'_> {
+    // SAFETY: same safety requirements
+    unsafe { write_only_as_slab(view.slice(..)) }
+}
+
+/// View all of a [`QueueWriteBufferView`] staging buffer as a [`Slab`].
+///
+/// # Safety
+///
+/// See [`write_only_as_slab`].
+#[inline]
+pub unsafe fn queue_write_view_as_slab(
+    view: &mut QueueWriteBufferView,
+) -> BorrowedRawAllocation<'_> {
+    // SAFETY: same safety requirements
+    unsafe { write_only_as_slab(view.slice(..)) }
+}